@@ -0,0 +1,5 @@
+import Foundation
+
+func swift_reflect_array_i32(arg: RustArray<Int32>) -> RustArray<Int32> {
+    arg
+}
@@ -46,6 +46,27 @@ func swift_calls_rust_fnonce_callback_twice(arg: () -> ()) {
     arg()
 }
 
+func swift_calls_rust_fn_callback_repeatedly(arg: (UInt8) -> UInt8) -> UInt8 {
+    arg(1) + arg(2) + arg(3)
+}
+
+func swift_calls_rust_fn_mut_callback_repeatedly(arg: () -> UInt32) -> UInt32 {
+    let _ = arg()
+    let _ = arg()
+    return arg()
+}
+
+func swift_calls_rust_fn_mut_callback_no_args_no_return(arg: () -> ()) {
+    arg()
+    arg()
+}
+
+func swift_calls_rust_fn_callback_opaque_rust(
+    arg: (CallbackTestOpaqueRustType) -> UInt32
+) -> UInt32 {
+    arg(CallbackTestOpaqueRustType(10)) + arg(CallbackTestOpaqueRustType(20))
+}
+
 class SwiftMethodCallbackTester {
     func method_with_fnonce_callback(callback: () -> ()) {
         callback()
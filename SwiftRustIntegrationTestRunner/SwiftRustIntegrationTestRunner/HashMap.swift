@@ -0,0 +1,14 @@
+import Foundation
+
+func swift_arg_hash_map_string_u32(map: RustHashMap<RustString, UInt32>) {
+    assert(map.len() == 2)
+    assert(map.get("three") == 3)
+    assert(map.get("four") == 4)
+}
+
+func swift_return_hash_map_string_u32() -> RustHashMap<RustString, UInt32> {
+    let map = RustHashMap<RustString, UInt32>()
+    map.insert("one", 1)
+    map.insert("two", 2)
+    return map
+}
@@ -51,18 +51,33 @@ func swift_reflect_option_bool(arg: Optional<Bool>) -> Optional<Bool> {
 func swift_reflect_option_string(arg: Optional<RustString>) -> Optional<RustString> {
     arg
 }
-// TODO: Change `swift_arg_option_str` `swift_reflect_option_str` once we support Swift returning `-> &str` via RustStr
-// For now we return true if the arg is Some and false if arg is None
-//func swift_reflect_option_str(arg: Optional<RustStr>) -> Optional<RustStr> {
-//    arg
-//}
-func swift_arg_option_str(arg: Optional<RustStr>) -> Bool {
-    if let val = arg {
-        assert(val.toString() == "this is an option str")
-        return true
-    } else {
-        return false
+func swift_reflect_option_str(arg: Optional<RustStr>) -> Optional<RustStr> {
+    arg
+}
+
+func swift_reflect_option_enum_with_no_data(arg: Optional<OptionEnumWithNoData>) -> Optional<OptionEnumWithNoData> {
+    arg
+}
+func swift_reflect_option_opaque_rust_type(arg: Optional<OptTestOpaqueRustType>) -> Optional<OptTestOpaqueRustType> {
+    arg
+}
+
+public class OptTestOpaqueSwiftType {
+    let val: UInt8
+
+    init(val: UInt8) {
+        self.val = val
+    }
+
+    func field() -> UInt8 {
+        val
     }
-    
+}
+
+func swift_create_option_opaque_swift_type(field: UInt8) -> Optional<OptTestOpaqueSwiftType> {
+    OptTestOpaqueSwiftType(val: field)
+}
+func swift_reflect_option_opaque_swift_type(arg: Optional<OptTestOpaqueSwiftType>) -> Optional<OptTestOpaqueSwiftType> {
+    arg
 }
 
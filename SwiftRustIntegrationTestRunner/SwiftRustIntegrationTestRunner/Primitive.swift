@@ -52,6 +52,18 @@ func swift_negate_bool(arg: Bool) -> Bool {
     !arg
 }
 
+func swift_reflect_u128(arg: RustU128) -> RustU128 {
+    arg
+}
+
+func swift_reflect_i128(arg: RustI128) -> RustI128 {
+    arg
+}
+
+func swift_uppercase_char(arg: Unicode.Scalar) -> Unicode.Scalar {
+    String(arg).uppercased().unicodeScalars.first!
+}
+
 func swift_reflect_null(arg: ()) -> () {
     arg
 }
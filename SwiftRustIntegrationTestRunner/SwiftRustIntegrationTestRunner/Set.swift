@@ -0,0 +1,11 @@
+import Foundation
+
+func swift_return_hash_set_u32() -> RustSet<UInt32> {
+    [1, 2, 3]
+}
+
+func swift_arg_btree_set_string(set: RustSet<RustString>) {
+    assert(set.len() == 2)
+    assert(set.contains("a"))
+    assert(set.contains("b"))
+}
@@ -0,0 +1,18 @@
+//
+//  SharedEnum.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_enum_with_collections(arg: EnumWithCollections) -> EnumWithCollections {
+    arg
+}
+
+func swift_reflect_enum_with_unnamed_data(arg: EnumWithUnnamedData) -> EnumWithUnnamedData {
+    arg
+}
+
+func swift_reflect_enum_with_named_data(arg: EnumWithNamedData) -> EnumWithNamedData {
+    arg
+}
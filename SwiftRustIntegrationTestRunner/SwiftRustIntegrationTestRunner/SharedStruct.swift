@@ -18,3 +18,7 @@ func rust_calls_swift_struct_repr_struct_one_primitive_field(arg: StructReprStru
 func rust_calls_swift_struct_repr_struct_one_string_field(arg: StructReprStructWithOneStringField) -> StructReprStructWithOneStringField {
     arg
 }
+
+func rust_calls_swift_generic_struct(arg: GenericPoint<Double>) -> GenericPoint<Float> {
+    GenericPoint(x: Float(arg.x), y: Float(arg.y))
+}
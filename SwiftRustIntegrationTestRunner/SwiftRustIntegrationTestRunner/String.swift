@@ -14,3 +14,19 @@ func create_swift_string() -> String {
 func reflect_rust_string(arg: RustString) -> RustString {
     arg
 }
+
+func swift_reflect_str(arg: RustStr) -> RustStr {
+    arg
+}
+
+func swift_trim_str(arg: RustStr) -> RustStr {
+    trim_str(arg)
+}
+
+func swift_make_str() -> String {
+    "made in swift"
+}
+
+func swift_make_option_str(some: Bool) -> Optional<String> {
+    some ? "made in swift" : nil
+}
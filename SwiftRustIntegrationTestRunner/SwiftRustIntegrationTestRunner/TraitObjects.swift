@@ -0,0 +1,35 @@
+//
+//  TraitObjects.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+class ScaledRenderer: SwiftRenderer {
+    var currentScale: Float
+    
+    init(scale: Float) {
+        self.currentScale = scale
+    }
+    
+    func render(width: UInt32) -> String {
+        "width: \(Int(Float(width) * currentScale))"
+    }
+    
+    func scale() -> Float {
+        currentScale
+    }
+    
+    func set_scale(scale: Float) {
+        currentScale = scale
+    }
+}
+
+func swift_makes_renderer(scale: Float) -> SwiftRenderer {
+    ScaledRenderer(scale: scale)
+}
+
+func swift_increments_rust_counter(counter: RustCounter) -> RustCounter {
+    counter.increment()
+    return counter
+}
@@ -15,3 +15,11 @@ func swift_return_vec_u8() -> RustVec<UInt8> {
     }
     return vec
 }
+
+class ASwiftTypeInsideVecT {
+    let text: String
+
+    init(text: String) {
+        self.text = text
+    }
+}
@@ -0,0 +1,44 @@
+//
+//  ArrayTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class ArrayTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    func testRustArray() throws {
+        var array: RustArray<UInt8> = [1, 2, 3]
+        XCTAssertEqual(array.count, 3)
+
+        array[1] = 5
+        XCTAssertEqual(array.toArray(), [1, 5, 3])
+        XCTAssertEqual(array.reduce(0, +), 9)
+    }
+
+    /// Verify that fixed size arrays can be used as arguments and return types for extern "Rust"
+    /// functions.
+    func testReflectArrays() throws {
+        XCTAssertEqual(rust_reflect_array_u8([1, 2, 3, 4]), [1, 2, 3, 4])
+        XCTAssertEqual(rust_reflect_array_f64([0.5, 1.5, 2.5]).toArray(), [0.5, 1.5, 2.5])
+
+        let structs = rust_reflect_array_transparent_struct([
+            ArrayTestStruct(field: 1),
+            ArrayTestStruct(field: 2),
+        ])
+        XCTAssertEqual(structs.map { $0.field }, [1, 2])
+    }
+
+    /// Verify that Rust can pass fixed size arrays to and receive them from Swift.
+    func testRustCallsSwiftArrayFunctions() {
+        run_array_tests()
+    }
+}
@@ -14,4 +14,47 @@ class CallbackTests: XCTestCase {
     func testRustCallsSwift() throws {
         test_callbacks_rust_calls_swift()
     }
+    
+    /// Verify that Rust can store a Swift closure and call it many times.
+    func testRustStoresSwiftClosure() throws {
+        rust_stores_swift_closure({ val in val > 5 })
+        
+        XCTAssertTrue(rust_calls_stored_swift_closure(10))
+        XCTAssertFalse(rust_calls_stored_swift_closure(1))
+        
+        rust_drops_stored_swift_closure()
+    }
+    
+    /// Verify that the Swift closure is released when Rust drops it.
+    func testRustReleasesSwiftClosure() throws {
+        class Captured {}
+        
+        weak var weakCaptured: Captured?
+        do {
+            let captured = Captured()
+            weakCaptured = captured
+            
+            rust_stores_swift_closure({ _ in
+                let _ = captured
+                return true
+            })
+        }
+        XCTAssertNotNil(weakCaptured)
+        
+        rust_drops_stored_swift_closure()
+        XCTAssertNil(weakCaptured)
+    }
+    
+    /// Verify that Rust can call a Swift closure from another thread.
+    func testRustCallsSwiftClosureOnAnotherThread() throws {
+        XCTAssertTrue(rust_calls_swift_closure_on_another_thread({ val in val == 10 }))
+    }
+    
+    /// Verify that Rust can call a Swift closure that it receives as a `Box<dyn FnMut()>`.
+    func testRustCallsSwiftFnMutClosure() throws {
+        var callCount = 0
+        rust_calls_swift_fn_mut_closure_twice({ callCount += 1 })
+        
+        XCTAssertEqual(callCount, 2)
+    }
 }
@@ -24,4 +24,52 @@ class FunctionAttributeTests: XCTestCase {
     func testSwiftNameAttribute() throws {
         XCTAssertEqual(testCallRustFromSwiftByNameAttribute().toString(), "StringFromRust")
     }
+
+    /// Verify that the `swift_bridge(monomorphize = (...))` attribute generates a function
+    /// for each of the listed types.
+    func testMonomorphizeAttribute() throws {
+        let int: Optional<UInt32> = parse_number("123")
+        let double: Optional<Double> = parse_number("1.5")
+
+        XCTAssertEqual(int, 123)
+        XCTAssertEqual(double, 1.5)
+        XCTAssertNil(parse_number("abc") as Optional<UInt32>)
+    }
+
+    /// Verify that the `swift_bridge(operator = "...")` attribute generates Swift operators and
+    /// subscripts.
+    func testOperatorAttribute() throws {
+        let a = OperatorVector(1.0, 2.0)
+        let b = OperatorVector(3.0, 4.0)
+
+        let sum = a + b
+        XCTAssertEqual(sum.x(), 4.0)
+        XCTAssertEqual(sum.y(), 6.0)
+
+        let negated = -a
+        XCTAssertEqual(negated.x(), -1.0)
+        XCTAssertEqual(negated.y(), -2.0)
+
+        let scaled = b * 2.0
+        XCTAssertEqual(scaled[0], 6.0)
+        XCTAssertEqual(scaled[1], 8.0)
+
+        scaled[1] = 10.0
+        XCTAssertEqual(scaled[1], 10.0)
+
+        let money = OperatorMoney(cents: 150) + OperatorMoney(cents: 50)
+        XCTAssertEqual(money.cents, 200)
+        XCTAssertEqual((3 * money).cents, 600)
+    }
+
+    /// Verify that the `swift_bridge(property = "...")` and `swift_bridge(setter = "...")`
+    /// attributes generate Swift computed properties.
+    func testPropertyAttribute() throws {
+        let person = PropertyPerson("Alice", 30)
+        XCTAssertEqual(person.name.toString(), "Alice")
+        XCTAssertEqual(person.age, 30)
+
+        person.name = "Bob".intoRustString()
+        XCTAssertEqual(person.name.toString(), "Bob")
+    }
 }
@@ -23,11 +23,29 @@ class GenericTests: XCTestCase {
         let _: SomeGenericType<UInt32> = reflect_generic_u32(val)
     }
     
+    func testReflectVecOfGenericOpaqueRustType() {
+        let vec: RustVec<SomeGenericType<UInt32>> = RustVec()
+        vec.push(value: new_some_generic_type_u32())
+        
+        let reflected = reflect_vec_generic_u32(vec)
+        XCTAssertEqual(reflected.len(), 1)
+        let _: SomeGenericType<UInt32> = reflected.pop()!
+    }
+    
     func testReflectGenericOpaqueCopyRustType() {
         let val = new_some_generic_copy_type_u32()
         let _: SomeGenericCopyType<UInt32> = reflect_generic_copy_u32(val)
     }
     
+    func testReflectVecOfGenericOpaqueCopyRustType() {
+        let vec: RustVec<SomeGenericCopyType<UInt32>> = RustVec()
+        vec.push(value: new_some_generic_copy_type_u32())
+        
+        let reflected = reflect_vec_generic_copy_u32(vec)
+        XCTAssertEqual(reflected.len(), 1)
+        let _: SomeGenericCopyType<UInt32> = reflected.get(index: 0)!
+    }
+    
     func testReflectGenericWithInnerOpaqueRustType() {
         let val = new_generic_with_inner_opaque_type()
         let _: GenericWithOpaqueRustInnerTy<InnerTy> = reflect_generic_with_inner_opaque_type(val)
@@ -0,0 +1,111 @@
+//
+//  HashMapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class HashMapTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    func testRustHashMapInsertGetRemove() throws {
+        let map = RustHashMap<RustString, UInt32>()
+        XCTAssertEqual(map.len(), 0)
+
+        XCTAssertNil(map.insert("one", 1))
+        XCTAssertEqual(map.insert("one", 11), 1)
+        XCTAssertEqual(map.len(), 1)
+
+        XCTAssertEqual(map.get("one"), 11)
+        XCTAssertNil(map.get("two"))
+
+        XCTAssertEqual(map.remove("one"), 11)
+        XCTAssertNil(map.remove("one"))
+        XCTAssertEqual(map.len(), 0)
+    }
+
+    func testRustHashMapIterator() throws {
+        let map = RustHashMap<UInt8, RustString>()
+        map.insert(1, RustString("one"))
+        map.insert(2, RustString("two"))
+
+        var iterations = 0
+        for (key, value) in map {
+            XCTAssertEqual(map.get(key)!.toString(), value.toString())
+            iterations += 1
+        }
+        XCTAssertEqual(iterations, 2)
+        XCTAssertEqual(map.count, 2)
+    }
+
+    /// Verify that we can walk a `RustHashMap` using its `Collection` indices.
+    func testRustHashMapIndices() throws {
+        let map = RustHashMap<UInt8, RustString>()
+        map.insert(1, RustString("one"))
+        map.insert(2, RustString("two"))
+        map.insert(3, RustString("three"))
+
+        var keys: [UInt8] = []
+        var index = map.startIndex
+        while index != map.endIndex {
+            keys.append(map[index].key)
+            index = map.index(after: index)
+        }
+        XCTAssertEqual(keys.sorted(), [1, 2, 3])
+
+        // Moving forward from the same index more than once gives the same entry each time,
+        // and reading an index more than once gives the same entry each time.
+        let start = map.startIndex
+        XCTAssertEqual(map[map.index(after: start)].key, map[map.index(after: start)].key)
+        XCTAssertEqual(map[start].key, map[start].key)
+
+        XCTAssertEqual(map.map { $0.key }.sorted(), [1, 2, 3])
+    }
+
+    func testRustHashMapToDictionary() throws {
+        let map = RustHashMap<RustString, UInt32>()
+        map.insert("one", 1)
+        map.insert("two", 2)
+
+        XCTAssertEqual(map.toDictionary(), ["one": 1, "two": 2])
+    }
+
+    /// Verify that a HashMap<K, V> can be used as an argument and return type for
+    /// extern "Rust" functions.
+    func testReflectHashMap() throws {
+        let map = RustHashMap<RustString, UInt32>()
+        map.insert("hello", 5)
+
+        let reflected = rust_reflect_hash_map_string_u32(map)
+        XCTAssertEqual(reflected.len(), 1)
+        XCTAssertEqual(reflected.get("hello"), 5)
+
+        let map2 = RustHashMap<UInt8, RustString>()
+        map2.insert(10, RustString("ten"))
+
+        let reflected2 = rust_reflect_hash_map_u8_string(map2)
+        XCTAssertEqual(reflected2.get(10)!.toString(), "ten")
+        XCTAssertEqual(reflected2.toDictionary()[10]!.toString(), "ten")
+    }
+
+    /// Verify that more than one bridge module in a crate can use the same HashMap<K, V>.
+    func testHashMapUsedByTwoBridgeModules() throws {
+        let map = rust_reflect_hash_map_string_u32(RustHashMap<RustString, UInt32>())
+        map.insert("one", 1)
+        map.insert("two", 2)
+
+        XCTAssertEqual(rust_hash_map_string_u32_sum(map), 3)
+    }
+
+    /// Verify that Rust can pass `RustHashMap`s to and receive `RustHashMap`s from Swift.
+    func testRustCallsSwiftRustHashMapFunctions() {
+        run_hash_map_tests()
+    }
+}
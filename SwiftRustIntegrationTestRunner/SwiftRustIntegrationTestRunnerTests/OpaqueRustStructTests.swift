@@ -130,5 +130,28 @@ class OpaqueRustStructTests: XCTestCase {
             }
         }
     }
+
+    func testOpaqueRustTypeImplClone() throws {
+        let original = RustCloneType(5)
+        let copy = original.clone()
+
+        copy.set_value(10)
+        XCTAssertEqual(original.value(), 5)
+        XCTAssertEqual(copy.value(), 10)
+
+        let refCopy: RustCloneType = (original as RustCloneTypeRef).clone()
+        XCTAssertEqual(refCopy.value(), 5)
+    }
+
+    func testOpaqueRustTypeImplComparable() throws {
+        let values = [RustComparableType(3), RustComparableType(1), RustComparableType(2)]
+
+        XCTAssertLessThan(values[1], values[2])
+        XCTAssertFalse(values[0] < values[2])
+        XCTAssertEqual(values[1], RustComparableType(1))
+
+        XCTAssertEqual(values.sorted().map { $0.value() }, [1, 2, 3])
+        XCTAssertEqual(values.max()?.value(), 3)
+    }
 }
 
@@ -42,6 +42,20 @@ class OpaqueTypeAttributeTests: XCTestCase {
         XCTAssert(AlreadyDeclaredCopyTypeTest.an_associated_function())
     }
 
+    /// Verify that the `Display` and `Debug` attributes use the Rust implementations for the owned, `Ref` and `RefMut`
+    /// classes.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/display_and_debug.rs
+    func testExternRustDisplayAndDebugOpaqueRustType() throws {
+        let val = RustPrintableType("hello")
+
+        XCTAssertEqual(String(describing: val), "Printable hello")
+        XCTAssertEqual(String(reflecting: val), #"RustPrintableType { name: "hello" }"#)
+
+        XCTAssertEqual(String(describing: val.as_ref()), "Printable hello")
+        XCTAssertEqual(String(describing: val.as_mut()), "Printable hello")
+        XCTAssertEqual("\(val.as_mut())", "Printable hello")
+    }
+
     
     func testPerformanceExample() throws {
         // This is an example of a performance test case.
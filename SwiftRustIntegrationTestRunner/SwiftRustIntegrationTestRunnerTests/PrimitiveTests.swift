@@ -29,6 +29,38 @@ class PrimitiveTests: XCTestCase {
         XCTAssertEqual(rust_double_f64(10.0), 20.0);
         XCTAssertEqual(rust_negate_bool(true), false);
         XCTAssertEqual(rust_negate_bool(false), true);
+        XCTAssertEqual(rust_double_u128(RustU128(low: UInt64.max, high: 0)), RustU128(low: UInt64.max - 1, high: 1));
+        XCTAssertEqual(rust_double_i128(-10), -20);
+        XCTAssertEqual(rust_uppercase_char("a"), "A");
+    }
+
+    /// Verify that 128 bit integers and `char`s can be used inside of `Option`, `Vec`, tuples and
+    /// shared structs.
+    func testCompositeTypesOfInt128AndChar() throws {
+        XCTAssertEqual(rust_reflect_option_u128(5), 5)
+        XCTAssertNil(rust_reflect_option_u128(nil))
+        XCTAssertEqual(rust_reflect_option_char("z"), "z")
+        XCTAssertNil(rust_reflect_option_char(nil))
+
+        let ints = RustVec<RustI128>()
+        ints.push(value: -1)
+        ints.push(value: 2)
+        XCTAssertEqual(Array(rust_reflect_vec_i128(ints)), [-1, 2])
+
+        let chars = RustVec<Unicode.Scalar>()
+        chars.push(value: "🦀")
+        XCTAssertEqual(Array(rust_reflect_vec_char(chars)), ["🦀"])
+
+        let tuple = rust_reflect_tuple_u128_char((7, "x"))
+        XCTAssertEqual(tuple.0, 7)
+        XCTAssertEqual(tuple.1, "x")
+
+        let value = rust_reflect_primitive_test_struct(
+            PrimitiveTestStruct(id: RustU128(low: 1, high: 2), offset: -3, letter: "q")
+        )
+        XCTAssertEqual(value.id, RustU128(low: 1, high: 2))
+        XCTAssertEqual(value.offset, -3)
+        XCTAssertEqual(value.letter, "q")
     }
 }
 
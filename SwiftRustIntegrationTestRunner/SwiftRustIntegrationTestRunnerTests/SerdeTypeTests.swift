@@ -0,0 +1,76 @@
+//
+//  SerdeTypeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `#[swift_bridge(serde)]` types.
+/// See crates/swift-integration-tests/src/serde_types.rs
+class SerdeTypeTests: XCTestCase {
+
+    /// Verify that we can receive a serde type from Rust.
+    func testRustReturnsSerdeType() throws {
+        let config = try rust_make_serde_config()
+
+        XCTAssertEqual(config.name, "parent")
+        XCTAssertEqual(config.tags, ["a", "b"])
+        XCTAssertEqual(config.limits, ["cpu": 4])
+        XCTAssertEqual(config.children.count, 1)
+        XCTAssertEqual(config.children[0].name, "child")
+
+        switch config.shape {
+        case .Circle(let radius):
+            XCTAssertEqual(radius, 1.5)
+        default:
+            XCTFail()
+        }
+        switch config.children[0].shape {
+        case .Polygon(let points):
+            XCTAssertEqual(points, [1, 2])
+        default:
+            XCTFail()
+        }
+    }
+
+    /// Verify that we can pass a serde type to Rust and get it back.
+    func testSerdeTypeRoundTrip() throws {
+        let config = SerdeConfig(
+            name: "config",
+            tags: ["tag"],
+            limits: [:],
+            shape: nil,
+            children: []
+        )
+
+        let reflected = try rust_reflect_serde_config(config)
+        XCTAssertEqual(reflected.name, "config")
+        XCTAssertEqual(reflected.tags, ["tag"])
+        XCTAssertEqual(reflected.limits, [:])
+        XCTAssertNil(reflected.shape)
+        XCTAssertEqual(reflected.children.count, 0)
+
+        switch try rust_reflect_serde_shape(SerdeShape.Empty) {
+        case .Empty:
+            break
+        default:
+            XCTFail()
+        }
+
+        XCTAssertNil(try rust_reflect_optional_serde_shape(nil))
+        switch try rust_reflect_optional_serde_shape(SerdeShape.Circle(radius: 2)) {
+        case .Circle(let radius):
+            XCTAssertEqual(radius, 2)
+        default:
+            XCTFail()
+        }
+    }
+
+    /// Verify that we throw an error instead of crashing when a serde type can not be encoded or
+    /// decoded.
+    func testSerdeTypeConversionErrorsAreThrown() throws {
+        XCTAssertThrowsError(try rust_make_unencodable_serde_shape())
+        XCTAssertThrowsError(try rust_reflect_serde_shape(SerdeShape.Polygon([Double.nan])))
+    }
+}
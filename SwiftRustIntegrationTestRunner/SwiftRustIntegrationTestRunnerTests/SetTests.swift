@@ -0,0 +1,92 @@
+//
+//  SetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class SetTests: XCTestCase {
+    override func setUpWithError() throws {
+        // Put setup code here. This method is called before the invocation of each test method in the class.
+    }
+
+    override func tearDownWithError() throws {
+        // Put teardown code here. This method is called after the invocation of each test method in the class.
+    }
+
+    func testRustSetInsertContainsRemove() throws {
+        let set = RustSet<RustString>()
+        XCTAssertTrue(set.isEmpty)
+
+        XCTAssertTrue(set.insert("one").inserted)
+        XCTAssertFalse(set.insert("one").inserted)
+        XCTAssertEqual(set.len(), 1)
+
+        XCTAssertTrue(set.contains("one"))
+        XCTAssertFalse(set.contains("two"))
+
+        XCTAssertEqual(set.remove("one")?.toString(), "one")
+        XCTAssertNil(set.remove("one"))
+        XCTAssertTrue(set.isEmpty)
+    }
+
+    func testRustSetIterator() throws {
+        let set: RustSet<UInt16> = [1, 2, 3]
+
+        var sum: UInt16 = 0
+        for member in set {
+            sum += member
+        }
+        XCTAssertEqual(sum, 6)
+        XCTAssertEqual(set.toSet(), [1, 2, 3])
+    }
+
+    /// Verify that a BTreeSet is iterated over in order.
+    func testRustBTreeSetIteratesInOrder() throws {
+        let set = rust_make_btree_set_of_strings()
+        XCTAssertEqual(set.map { $0.toString() }, ["a", "b", "c"])
+        XCTAssertEqual(set.toSet(), ["a", "b", "c"])
+    }
+
+    func testRustSetAlgebra() throws {
+        let a: RustSet<Int32> = [1, 2, 3]
+        let b: RustSet<Int32> = [2, 3, 4]
+
+        XCTAssertEqual(a.union(b).toSet(), [1, 2, 3, 4])
+        XCTAssertEqual(a.intersection(b).toSet(), [2, 3])
+        XCTAssertEqual(a.symmetricDifference(b).toSet(), [1, 4])
+        XCTAssertEqual(a.subtracting(b).toSet(), [1])
+        XCTAssertTrue(a.intersection(b).isSubset(of: a))
+        XCTAssertEqual(a, [3, 2, 1])
+    }
+
+    /// Verify that `copy()` gives us a set that can be mutated without changing the original.
+    func testRustSetCopy() throws {
+        let a: RustSet<UInt8> = [1, 2]
+        let b = a.copy()
+        b.insert(3)
+
+        XCTAssertEqual(a.toSet(), [1, 2])
+        XCTAssertEqual(b.toSet(), [1, 2, 3])
+    }
+
+    /// Verify that sets can be used as arguments and return types for extern "Rust" functions.
+    func testReflectSets() throws {
+        let strings = RustSet<RustString>()
+        strings.insert("hello")
+        XCTAssertEqual(rust_reflect_hash_set_string(strings).toSet(), ["hello"])
+
+        XCTAssertEqual(rust_reflect_hash_set_u32([5, 6]).toSet(), [5, 6])
+
+        let btreeSet = RustSet<Int64>(kind: .btreeSet)
+        btreeSet.insert(-1)
+        btreeSet.insert(-2)
+        XCTAssertEqual(Array(rust_reflect_btree_set_i64(btreeSet)), [-2, -1])
+    }
+
+    /// Verify that Rust can pass sets to and receive sets from Swift.
+    func testRustCallsSwiftSetFunctions() {
+        run_set_tests()
+    }
+}
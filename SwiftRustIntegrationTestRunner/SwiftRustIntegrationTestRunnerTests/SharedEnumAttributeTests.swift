@@ -42,5 +42,35 @@ class SharedEnumAttributeTests: XCTestCase {
         XCTAssertEqual(debugString, "Variant")
     }
 
-}
+    /// Verify that an enum that derives `PartialEq`, `Hash` and `PartialOrd` is `Equatable`,
+    /// `Hashable` and `Comparable` in Swift, and is ordered the same way as in Rust.
+    func testSharedEnumDeriveComparisonTraits() throws {
+        XCTAssertEqual(DeriveComparisonEnum.Medium(1), DeriveComparisonEnum.Medium(1))
+        XCTAssertNotEqual(DeriveComparisonEnum.Medium(1), DeriveComparisonEnum.Medium(2))
+
+        let set: Set<DeriveComparisonEnum> = [.Low, .Low, .High(level: 1)]
+        XCTAssertEqual(set.count, 2)
+
+        XCTAssertLessThan(DeriveComparisonEnum.Low, DeriveComparisonEnum.Medium(0))
+        XCTAssertLessThan(DeriveComparisonEnum.Medium(1), DeriveComparisonEnum.Medium(2))
+        XCTAssertLessThan(DeriveComparisonEnum.Medium(200), DeriveComparisonEnum.High(level: 0))
+    }
 
+    /// Verify that a `#[swift_bridge(Codable)]` enum is encoded the same way that serde serializes it.
+    func testSharedEnumCodable() throws {
+        let encoder = JSONEncoder()
+
+        for (value, expectedJson) in [
+            (CodableEnum.Unit, #""Unit""#),
+            (CodableEnum.NewType(1), #"{"NewType":1}"#),
+            (CodableEnum.Tuple(1, 2), #"{"Tuple":[1,2]}"#),
+            (CodableEnum.Named(id: 3), #"{"Named":{"id":3}}"#),
+        ] {
+            let json = try encoder.encode(value)
+            XCTAssertEqual(String(data: json, encoding: .utf8), expectedJson)
+
+            let decoded = try JSONDecoder().decode(CodableEnum.self, from: json)
+            XCTAssertEqual(try encoder.encode(decoded), json)
+        }
+    }
+}
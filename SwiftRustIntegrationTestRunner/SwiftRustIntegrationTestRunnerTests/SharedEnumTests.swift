@@ -128,4 +128,37 @@ class SharedEnumTests: XCTestCase {
         }
     }
 
+    func testEnumWithCollections() {
+        let named = EnumWithCollections.Named(bytes: RustVec<UInt8>(), maybe_string: create_string("hello"))
+        switch reflect_enum_with_collections(named) {
+        case .Named(let bytes, let maybeString):
+            XCTAssertEqual(bytes.len(), 0)
+            XCTAssertEqual(maybeString!.toString(), "hello")
+        default:
+            XCTFail()
+        }
+
+        let strings = RustVec<RustString>()
+        strings.push(value: RustString("world"))
+        let unnamed = EnumWithCollections.Unnamed(123, strings)
+        switch reflect_enum_with_collections(unnamed) {
+        case .Unnamed(let maybeU32, let strings):
+            XCTAssertEqual(maybeU32, 123)
+            XCTAssertEqual(strings.get(index: 0)!.toString(), "world")
+        default:
+            XCTFail()
+        }
+
+        switch reflect_enum_with_collections(EnumWithCollections.MaybeOpaque(nil)) {
+        case .MaybeOpaque(let value):
+            XCTAssertNil(value)
+        default:
+            XCTFail()
+        }
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return enums with associated values.
+    func testRustCallsSwiftDataCarryingEnums() {
+        test_rust_calls_swift_data_carrying_enums()
+    }
 }
@@ -34,5 +34,48 @@ class SharedStructAttributeTests: XCTestCase {
     func testSharedStructAlreadyDeclared() throws {
         test_rust_calls_swift_already_declared_struct()
     }
-}
 
+    /// Verify that a `#[swift_bridge(Codable)]` struct uses the Rust field names as its coding keys.
+    func testSharedStructCodable() throws {
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = .sortedKeys
+
+        let val = CodableStruct(id: 123, display_name: "hello".intoRustString())
+        let json = try encoder.encode(val)
+        XCTAssertEqual(
+            String(data: json, encoding: .utf8),
+            #"{"display_name":"hello","id":123}"#
+        )
+
+        let decoded = try JSONDecoder().decode(CodableStruct.self, from: json)
+        XCTAssertEqual(decoded.id, 123)
+        XCTAssertEqual(decoded.display_name.toString(), "hello")
+    }
+
+    /// Verify that a `#[swift_bridge(Codable)]` tuple struct is encoded as an array.
+    func testSharedTupleStructCodable() throws {
+        let json = try JSONEncoder().encode(CodableTupleStruct(_0: 1, _1: 2))
+        XCTAssertEqual(String(data: json, encoding: .utf8), "[1,2]")
+
+        let decoded = try JSONDecoder().decode(CodableTupleStruct.self, from: json)
+        XCTAssertEqual(decoded._0, 1)
+        XCTAssertEqual(decoded._1, 2)
+    }
+
+    /// Verify that a struct that derives `PartialEq`, `Hash` and `PartialOrd` is `Equatable`,
+    /// `Hashable` and `Comparable` in Swift, comparing its fields in declaration order.
+    func testSharedStructDeriveComparisonTraits() throws {
+        let a = StructDeriveComparison(major: 1, name: "b".intoRustString())
+        let b = StructDeriveComparison(major: 1, name: "b".intoRustString())
+        let c = StructDeriveComparison(major: 1, name: "c".intoRustString())
+        let d = StructDeriveComparison(major: 2, name: "a".intoRustString())
+
+        XCTAssertEqual(a, b)
+        XCTAssertNotEqual(a, c)
+        XCTAssertEqual(Set([a, b, c]).count, 2)
+
+        XCTAssertLessThan(a, c)
+        XCTAssertLessThan(c, d)
+        XCTAssertFalse(a < b)
+    }
+}
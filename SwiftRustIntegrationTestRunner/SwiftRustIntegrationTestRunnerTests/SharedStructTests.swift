@@ -52,4 +52,12 @@ class SharedStructTests: XCTestCase {
         XCTAssertEqual(val._0, reflected._0)
         XCTAssertEqual(val._1, reflected._1)
     }
+
+    /// Verify that we can pass instantiations of a generic transparent struct between Rust and Swift.
+    func testGenericStruct() {
+        let val = swift_calls_rust_generic_struct(GenericPoint(x: Float(1.5), y: Float(2.5)))
+
+        XCTAssertEqual(val.x, 1.5)
+        XCTAssertEqual(val.y, 2.5)
+    }
 }
@@ -0,0 +1,101 @@
+//
+//  TraitObjectTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class TraitObjectTests: XCTestCase {
+    
+    /// Verify that Rust can call methods on a Swift class that conforms to a bridged protocol.
+    func testRustCallsSwiftProtocolMethods() throws {
+        let renderer = ScaledRenderer(scale: 3)
+        
+        XCTAssertEqual(rust_renders_with_swift_renderer(renderer, 5).toString(), "width: 15")
+    }
+    
+    /// Verify that Rust can call protocol methods that take `&mut self`.
+    func testRustMutatesSwiftProtocolObject() throws {
+        let renderer = ScaledRenderer(scale: 1)
+        
+        XCTAssertEqual(rust_scales_swift_renderer_twice(renderer, 2), 4)
+        XCTAssertEqual(renderer.currentScale, 4)
+    }
+    
+    /// Verify that we can pass an `Optional` protocol object to Rust.
+    func testOptionalSwiftProtocolObject() throws {
+        XCTAssertEqual(
+            rust_renders_with_optional_swift_renderer(ScaledRenderer(scale: 2))!.toString(),
+            "width: 20"
+        )
+        XCTAssertNil(rust_renders_with_optional_swift_renderer(nil))
+    }
+    
+    /// Verify that Rust releases the Swift object when it drops the `Box<dyn SwiftRenderer>`.
+    func testRustReleasesSwiftProtocolObject() throws {
+        weak var weakRenderer: ScaledRenderer?
+        do {
+            let renderer = ScaledRenderer(scale: 1)
+            weakRenderer = renderer
+            
+            rust_stores_swift_renderer(renderer)
+        }
+        XCTAssertNotNil(weakRenderer)
+        
+        rust_drops_stored_swift_renderer()
+        XCTAssertNil(weakRenderer)
+    }
+    
+    /// Run the tests where Rust calls methods on a protocol object that Swift returned.
+    func testRustCallsSwiftMadeRenderer() throws {
+        test_rust_calls_swift_made_renderer()
+    }
+    
+    /// Verify that Swift can call trait methods on the `Box<dyn Counter>` that Rust returns,
+    /// whichever implementation is behind it.
+    func testSwiftCallsRustTraitObjectMethods() throws {
+        let oneStep = make_counter(5, 1)
+        oneStep.increment()
+        XCTAssertEqual(oneStep.count(), 6)
+        
+        let tenSteps = make_counter(5, 10)
+        tenSteps.increment()
+        XCTAssertEqual(tenSteps.count(), 15)
+    }
+    
+    /// Verify that Swift code can work with the generated protocol instead of the concrete class.
+    func testRustTraitObjectConformsToProtocol() throws {
+        func incrementTwice(_ counter: Counter) {
+            counter.increment()
+            counter.increment()
+        }
+        
+        let counter = make_counter(0, 2)
+        incrementTwice(counter)
+        XCTAssertEqual(counter.count(), 4)
+    }
+    
+    /// Verify that Swift can pass a Rust trait object back to Rust as a `&dyn Trait` and a
+    /// `&mut dyn Trait`.
+    func testPassRustTraitObjectReferencesToRust() throws {
+        let counter = make_counter(1, 1)
+        
+        rust_increments_counter(counter)
+        rust_increments_counter(counter)
+        XCTAssertEqual(rust_reads_counter(counter), 3)
+    }
+    
+    /// Verify that Swift can give ownership of a Rust trait object back to Rust.
+    func testPassOwnedRustTraitObjectToRust() throws {
+        XCTAssertEqual(rust_consumes_counter(make_counter(7, 1)), 7)
+        
+        XCTAssertEqual(rust_consumes_optional_counter(make_counter(8, 1)), 8)
+        XCTAssertNil(rust_consumes_optional_counter(nil))
+    }
+    
+    /// Run the tests where Rust passes a trait object to a Swift function and gets it back.
+    func testRustPassesTraitObjectToSwift() throws {
+        test_swift_increments_rust_counter()
+    }
+}
@@ -87,6 +87,63 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.get(index: 0)!.text().toString(), "hello world")
     }
     
+    /// Verify that a Vec<T> of opaque Rust Copy types can be used as an argument and return
+    /// type for extern "Rust" functions.
+    func testReflectVecOfOpaqueCopyType() throws {
+        let vec: RustVec<ACopyTypeInsideVecT> = RustVec()
+        vec.push(value: ACopyTypeInsideVecT(123))
+        vec.push(value: ACopyTypeInsideVecT(456))
+        
+        let reflected = rust_reflect_vec_opaque_copy_type(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.value(), 123)
+        XCTAssertEqual(reflected.get(index: 1)!.value(), 456)
+        XCTAssertNil(reflected.get(index: 2))
+        
+        XCTAssertEqual(reflected.pop()!.value(), 456)
+        XCTAssertEqual(reflected.len(), 1)
+    }
+    
+    /// Verify that a Vec<T> of opaque Swift types can be used as an argument and return
+    /// type for extern "Rust" functions.
+    func testReflectVecOfOpaqueSwiftType() throws {
+        let first = ASwiftTypeInsideVecT(text: "hello")
+        let second = ASwiftTypeInsideVecT(text: "world")
+
+        let vec: RustVec<ASwiftTypeInsideVecT> = RustVec()
+        vec.push(value: first)
+        vec.push(value: second)
+
+        let reflected = rust_reflect_vec_opaque_swift_type(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssert(reflected.get(index: 0)! === first)
+        XCTAssertEqual(reflected[1].text, "world")
+        XCTAssertNil(reflected.get(index: 2))
+
+        let array: [ASwiftTypeInsideVecT] = Array(reflected)
+        XCTAssertEqual(array.map { $0.text }, ["hello", "world"])
+
+        XCTAssert(reflected.pop()! === second)
+        XCTAssertEqual(reflected.len(), 1)
+    }
+
+    /// Verify that the Rust `Vec` keeps its Swift class instances alive.
+    func testVecOfOpaqueSwiftTypeRetainsElements() throws {
+        let vec: RustVec<ASwiftTypeInsideVecT> = RustVec()
+        weak var weakRef: ASwiftTypeInsideVecT?
+
+        do {
+            let value = ASwiftTypeInsideVecT(text: "retained")
+            weakRef = value
+            vec.push(value: value)
+        }
+        XCTAssertNotNil(weakRef)
+        XCTAssertEqual(vec[0].text, "retained")
+
+        _ = vec.pop()
+        XCTAssertNil(weakRef)
+    }
+
     /// Verify that a Vec<T> of transparent enums can be used as an argument and return
     /// type for extern "Rust" functions.
     func testReflectVecOfTransparentEnum() throws {
@@ -98,6 +155,54 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.get(index: 0)!, TransparentEnumInsideVecT.VariantB)
         XCTAssertEqual(reflected.pop()!, TransparentEnumInsideVecT.VariantB)
     }
+
+    func testReflectVecOfTransparentStruct() throws {
+        let vec: RustVec<TransparentStructInsideVecT> = RustVec()
+        vec.push(value: TransparentStructInsideVecT(string: RustString("hello"), integer: 1))
+        vec.push(value: TransparentStructInsideVecT(string: RustString("world"), integer: 2))
+
+        let reflected = rust_reflect_vec_transparent_struct(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.string.toString(), "hello")
+        XCTAssertEqual(reflected.get(index: 1)!.integer, 2)
+
+        // Reading a struct out of the vec gives Swift its own copy, so reading it twice works.
+        XCTAssertEqual(reflected.get(index: 0)!.string.toString(), "hello")
+
+        let popped = reflected.pop()!
+        XCTAssertEqual(popped.string.toString(), "world")
+        XCTAssertEqual(popped.integer, 2)
+        XCTAssertEqual(reflected.len(), 1)
+    }
+
+    /// Verify that a Vec<String> can be used as an argument and return type for extern "Rust"
+    /// functions.
+    func testReflectVecOfString() throws {
+        let vec: RustVec<RustString> = RustVec()
+        vec.push(value: RustString("hello"))
+        vec.push(value: RustString("world"))
+
+        let reflected = rust_reflect_vec_string(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.toString(), "hello")
+        XCTAssertEqual(reflected[1].toString(), "world")
+        XCTAssertNil(reflected.get(index: 2))
+        XCTAssertEqual(reflected.toStringArray(), ["hello", "world"])
+
+        XCTAssertEqual(reflected.pop()!.toString(), "world")
+        XCTAssertEqual(reflected.toStringArray(), ["hello"])
+    }
+
+    /// Verify that a Vec<&str> can be used as an argument and return type for extern "Rust"
+    /// functions.
+    func testReflectVecOfStr() throws {
+        let vec: RustVec<RustString> = RustVec()
+        vec.push(value: RustString("hello"))
+        vec.push(value: RustString("world"))
+
+        let reflected = rust_reflect_vec_str(vec)
+        XCTAssertEqual(reflected.toStringArray(), ["hello", "world"])
+    }
     
     /// Verify that we can construct a RustVec of every primitive type.
     /// We tested all of the methods on  two different primitives above to be sure that our
@@ -1,5 +1,6 @@
 use crate::generate_core::boxed_fn_support::{
     C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN, SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN,
+    SWIFT_CLOSURE_BOX,
 };
 use crate::generate_core::option_support::{
     swift_option_primitive_support, C_OPTION_PRIMITIVE_SUPPORT,
@@ -12,6 +13,11 @@ const RUST_STRING_C: &'static str = include_str!("./generate_core/rust_string.c.
 
 const STRING_SWIFT: &'static str = include_str!("./generate_core/string.swift");
 const RUST_VEC_SWIFT: &'static str = include_str!("./generate_core/rust_vec.swift");
+const RUST_HASH_MAP_SWIFT: &'static str = include_str!("./generate_core/rust_hash_map.swift");
+const RUST_SET_SWIFT: &'static str = include_str!("./generate_core/rust_set.swift");
+const RUST_ARRAY_SWIFT: &'static str = include_str!("./generate_core/rust_array.swift");
+const INT128_SWIFT: &'static str = include_str!("./generate_core/int128.swift");
+const CODABLE_SWIFT: &'static str = include_str!("./generate_core/codable.swift");
 
 mod boxed_fn_support;
 mod option_support;
@@ -25,6 +31,8 @@ pub(super) fn write_core_swift_and_c(out_dir: &Path) {
     swift += "\n";
     swift += &SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     swift += "\n";
+    swift += &SWIFT_CLOSURE_BOX;
+    swift += "\n";
     swift += &SWIFT_RUST_RESULT;
     swift += "\n";
     swift += &swift_option_primitive_support();
@@ -48,6 +56,11 @@ fn core_swift() -> String {
 
     core_swift += STRING_SWIFT;
     core_swift += RUST_VEC_SWIFT;
+    core_swift += RUST_HASH_MAP_SWIFT;
+    core_swift += RUST_SET_SWIFT;
+    core_swift += RUST_ARRAY_SWIFT;
+    core_swift += INT128_SWIFT;
+    core_swift += CODABLE_SWIFT;
 
     for (swift_ty, rust_ty) in vec![
         ("UInt8", "u8"),
@@ -62,6 +75,9 @@ fn core_swift() -> String {
         ("Int64", "i64"),
         ("Int", "isize"),
         //
+        ("RustU128", "u128"),
+        ("RustI128", "i128"),
+        //
         ("Bool", "bool"),
         //
         ("Float", "f32"),
@@ -69,6 +85,25 @@ fn core_swift() -> String {
     ] {
         core_swift += &conform_to_vectorizable(swift_ty, rust_ty);
     }
+    core_swift += conform_unicode_scalar_to_vectorizable();
+
+    for (swift_ty, rust_ty) in vec![
+        ("UInt8", "u8"),
+        ("UInt16", "u16"),
+        ("UInt32", "u32"),
+        ("UInt64", "u64"),
+        ("UInt", "usize"),
+        //
+        ("Int8", "i8"),
+        ("Int16", "i16"),
+        ("Int32", "i32"),
+        ("Int64", "i64"),
+        ("Int", "isize"),
+        //
+        ("Bool", "bool"),
+    ] {
+        core_swift += &conform_to_rust_set_element(swift_ty, rust_ty);
+    }
 
     core_swift += &generic_freer();
     core_swift += &generic_copy_type_ffi_repr();
@@ -80,7 +115,10 @@ fn core_c_header() -> String {
     let mut header = r#"#include <stdint.h>
 #include <stdbool.h> 
 typedef struct RustStr { uint8_t* const start; uintptr_t len; } RustStr;
+typedef struct RustU128 { uint64_t low; uint64_t high; } RustU128;
+typedef struct RustI128 { uint64_t low; int64_t high; } RustI128;
 typedef struct __private__FfiSlice { void* const start; uintptr_t len; } __private__FfiSlice;
+typedef struct __private__SwiftStr { struct RustStr str; void* owner; void (*release)(void*); } __private__SwiftStr;
 void* __swift_bridge__null_pointer(void);
 
 "#
@@ -100,7 +138,11 @@ void* __swift_bridge__null_pointer(void);
         ("i64", "int64_t"),
         ("isize", "intptr_t"),
         //
+        ("u128", "struct RustU128"),
+        ("i128", "struct RustI128"),
+        //
         ("bool", "bool"),
+        ("char", "uint32_t"),
         //
         ("f32", "float"),
         ("f64", "double"),
@@ -108,6 +150,24 @@ void* __swift_bridge__null_pointer(void);
         header += &vec_of_primitive_headers(rust_ty, c_ty);
     }
 
+    for (rust_ty, c_ty) in vec![
+        ("u8", "uint8_t"),
+        ("u16", "uint16_t"),
+        ("u32", "uint32_t"),
+        ("u64", "uint64_t"),
+        ("usize", "uintptr_t"),
+        //
+        ("i8", "int8_t"),
+        ("i16", "int16_t"),
+        ("i32", "int32_t"),
+        ("i64", "int64_t"),
+        ("isize", "intptr_t"),
+        //
+        ("bool", "bool"),
+    ] {
+        header += &set_of_primitive_headers(rust_ty, c_ty);
+    }
+
     header
 }
 
@@ -139,6 +199,110 @@ void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {c_ty} val);
     )
 }
 
+/// Headers for HashSet<T> and BTreeSet<T> where T is a primitive such as u8, i32, bool
+fn set_of_primitive_headers(rust_ty: &str, c_ty: &str) -> String {
+    let mut chars = rust_ty.chars();
+
+    // u8 -> U8, bool -> Bool, etc...
+    let capatilized_first_letter =
+        chars.next().unwrap().to_string().to_uppercase() + chars.as_str();
+
+    // __private__OptionU8 ... etc
+    let option_ty = format!("{}{}", "__private__Option", capatilized_first_letter);
+
+    let mut headers = "".to_string();
+
+    for set in ["HashSet", "BTreeSet"] {
+        headers += &format!(
+            r#"
+void* __swift_bridge__${set}_{rust_ty}$new(void);
+void __swift_bridge__${set}_{rust_ty}$_free(void* const set);
+uintptr_t __swift_bridge__${set}_{rust_ty}$len(void* const set);
+bool __swift_bridge__${set}_{rust_ty}$contains(void* const set, {c_ty} val);
+bool __swift_bridge__${set}_{rust_ty}$insert(void* const set, {c_ty} val);
+{option_ty} __swift_bridge__${set}_{rust_ty}$remove(void* const set, {c_ty} val);
+void* __swift_bridge__${set}_{rust_ty}$iter(void* const set);
+"#,
+        );
+    }
+
+    headers += &format!(
+        r#"
+{option_ty} __swift_bridge__$SetIter_{rust_ty}$next(void* const iter);
+void __swift_bridge__$SetIter_{rust_ty}$_free(void* const iter);
+"#,
+    );
+
+    headers
+}
+
+fn conform_to_rust_set_element(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: RustSetElement {{
+    public static func setOfSelfNew(kind: RustSetKind) -> UnsafeMutableRawPointer {{
+        switch kind {{
+        case .hashSet: return __swift_bridge__$HashSet_{rust_ty}$new()
+        case .btreeSet: return __swift_bridge__$BTreeSet_{rust_ty}$new()
+        }}
+    }}
+
+    public static func setOfSelfFree(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) {{
+        switch kind {{
+        case .hashSet: __swift_bridge__$HashSet_{rust_ty}$_free(setPtr)
+        case .btreeSet: __swift_bridge__$BTreeSet_{rust_ty}$_free(setPtr)
+        }}
+    }}
+
+    public static func setOfSelfLen(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) -> UInt {{
+        switch kind {{
+        case .hashSet: return __swift_bridge__$HashSet_{rust_ty}$len(setPtr)
+        case .btreeSet: return __swift_bridge__$BTreeSet_{rust_ty}$len(setPtr)
+        }}
+    }}
+
+    public static func setOfSelfContains(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        switch kind {{
+        case .hashSet: return __swift_bridge__$HashSet_{rust_ty}$contains(setPtr, value)
+        case .btreeSet: return __swift_bridge__$BTreeSet_{rust_ty}$contains(setPtr, value)
+        }}
+    }}
+
+    public static func setOfSelfInsert(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: Self) -> (inserted: Bool, memberAfterInsert: Self) {{
+        switch kind {{
+        case .hashSet: return (__swift_bridge__$HashSet_{rust_ty}$insert(setPtr, value), value)
+        case .btreeSet: return (__swift_bridge__$BTreeSet_{rust_ty}$insert(setPtr, value), value)
+        }}
+    }}
+
+    public static func setOfSelfRemove(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: Self) -> Optional<Self> {{
+        switch kind {{
+        case .hashSet: return __swift_bridge__$HashSet_{rust_ty}$remove(setPtr, value).intoSwiftRepr()
+        case .btreeSet: return __swift_bridge__$BTreeSet_{rust_ty}$remove(setPtr, value).intoSwiftRepr()
+        }}
+    }}
+
+    public static func setOfSelfIter(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        switch kind {{
+        case .hashSet: return __swift_bridge__$HashSet_{rust_ty}$iter(setPtr)
+        case .btreeSet: return __swift_bridge__$BTreeSet_{rust_ty}$iter(setPtr)
+        }}
+    }}
+
+    public static func setIterOfSelfNext(iterPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        __swift_bridge__$SetIter_{rust_ty}$next(iterPtr).intoSwiftRepr()
+    }}
+
+    public static func setIterOfSelfFree(iterPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$SetIter_{rust_ty}$_free(iterPtr)
+    }}
+}}
+    "#,
+        rust_ty = rust_ty,
+        swift_ty = swift_ty
+    )
+}
+
 fn conform_to_vectorizable(swift_ty: &str, rust_ty: &str) -> String {
     format!(
         r#"
@@ -196,6 +360,46 @@ extension {swift_ty}: Vectorizable {{
     )
 }
 
+/// `char` is passed over FFI as a `UInt32`, so `Unicode.Scalar` can't use
+/// `conform_to_vectorizable`.
+fn conform_unicode_scalar_to_vectorizable() -> &'static str {
+    r#"
+extension Unicode.Scalar: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_char$new()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_char$_free(vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {
+        __swift_bridge__$Vec_char$push(vecPtr, value.value)
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        __swift_bridge__$Vec_char$pop(vecPtr).intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        __swift_bridge__$Vec_char$get(vecPtr, index).intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        __swift_bridge__$Vec_char$get_mut(vecPtr, index).intoSwiftRepr()
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_char$as_ptr(vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_char$len(vecPtr)
+    }
+}
+    "#
+}
+
 /// Used to free memory for generic Opaque Rust types such as `type SomeType<u32>`
 fn generic_freer() -> &'static str {
     r#"
@@ -25,9 +25,58 @@ public class __private__RustFnOnceCallbackNoArgsNoRet {
         return __swift_bridge__$call_boxed_fn_once_no_args_no_return(ptr)
     }
 }
+
+public class __private__RustFnMutCallbackNoArgsNoRet {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$free_boxed_fn_mut_no_args_no_return(ptr)
+    }
+
+    func call() {
+        return __swift_bridge__$call_boxed_fn_mut_no_args_no_return(ptr)
+    }
+}
+
+public class __private__RustFnCallbackNoArgsNoRet {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$free_boxed_fn_no_args_no_return(ptr)
+    }
+
+    func call() {
+        return __swift_bridge__$call_boxed_fn_no_args_no_return(ptr)
+    }
+}
+"#;
+
+/// Holds a Swift closure that was passed to Rust.
+///
+/// The closure is retained while Rust holds onto it and released when Rust drops it.
+pub const SWIFT_CLOSURE_BOX: &'static str = r#"
+public class __private__SwiftCallbackBox<T> {
+    let callback: T
+
+    init(_ callback: T) {
+        self.callback = callback
+    }
+}
 "#;
 
 pub const C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN: &'static str = r#"
 void __swift_bridge__$call_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
 void __swift_bridge__$free_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
+void __swift_bridge__$call_boxed_fn_mut_no_args_no_return(void* boxed_fnmut);
+void __swift_bridge__$free_boxed_fn_mut_no_args_no_return(void* boxed_fnmut);
+void __swift_bridge__$call_boxed_fn_no_args_no_return(void* boxed_fn);
+void __swift_bridge__$free_boxed_fn_no_args_no_return(void* boxed_fn);
 "#;
@@ -0,0 +1,60 @@
+public struct SwiftBridgeCodingKey: CodingKey {
+    public var stringValue: String
+    public var intValue: Int? { nil }
+
+    public init(_ stringValue: String) {
+        self.stringValue = stringValue
+    }
+
+    public init?(stringValue: String) {
+        self.stringValue = stringValue
+    }
+
+    public init?(intValue: Int) {
+        nil
+    }
+}
+
+// Thrown when a `#[swift_bridge(serde)]` value could not be encoded or decoded as JSON.
+public struct SwiftBridgeSerdeError: Error {
+    public let message: String
+}
+
+// Used to pass `#[swift_bridge(serde)]` types to Rust.
+// Swift encodes the value as JSON, and Rust decodes the JSON into a boxed Rust value.
+public func serdeIntoRust<T: Encodable>(_ value: T, _ fromJson: (UnsafeMutableRawPointer?) -> __private__ResultPtrAndPtr) throws -> UnsafeMutableRawPointer {
+    let json = try JSONEncoder().encode(value)
+    let rustString = String(decoding: json, as: UTF8.self).intoRustString()
+    rustString.isOwned = false
+
+    let result = fromJson(rustString.ptr)
+    if !result.is_ok {
+        throw SwiftBridgeSerdeError(message: RustString(ptr: result.ok_or_err!).toString())
+    }
+    return result.ok_or_err!
+}
+
+public func optionalSerdeIntoRust<T: Encodable>(_ value: Optional<T>, _ fromJson: (UnsafeMutableRawPointer?) -> __private__ResultPtrAndPtr) throws -> UnsafeMutableRawPointer? {
+    guard let value = value else {
+        return nil
+    }
+    return try serdeIntoRust(value, fromJson)
+}
+
+// Used to receive `#[swift_bridge(serde)]` types from Rust.
+// Rust encodes the boxed Rust value as JSON, and Swift decodes the JSON.
+public func serdeFromRust<T: Decodable>(_ type: T.Type, _ ptr: UnsafeMutableRawPointer, _ toJson: (UnsafeMutableRawPointer?) -> __private__ResultPtrAndPtr) throws -> T {
+    let result = toJson(ptr)
+    let json = RustString(ptr: result.ok_or_err!).toString()
+    if !result.is_ok {
+        throw SwiftBridgeSerdeError(message: json)
+    }
+    return try JSONDecoder().decode(type, from: Data(json.utf8))
+}
+
+public func optionalSerdeFromRust<T: Decodable>(_ type: T.Type, _ ptr: UnsafeMutableRawPointer?, _ toJson: (UnsafeMutableRawPointer?) -> __private__ResultPtrAndPtr) throws -> Optional<T> {
+    guard let ptr = ptr else {
+        return nil
+    }
+    return try serdeFromRust(type, ptr, toJson)
+}
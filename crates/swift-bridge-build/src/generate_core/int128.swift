@@ -0,0 +1,79 @@
+extension RustU128: ExpressibleByIntegerLiteral {
+    public init(_ value: UInt64) {
+        self.init(low: value, high: 0)
+    }
+
+    public init(integerLiteral value: UInt64) {
+        self.init(value)
+    }
+}
+
+extension RustU128: Equatable, Hashable {
+    public static func == (lhs: RustU128, rhs: RustU128) -> Bool {
+        lhs.low == rhs.low && lhs.high == rhs.high
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(low)
+        hasher.combine(high)
+    }
+}
+
+extension RustU128: Comparable {
+    public static func < (lhs: RustU128, rhs: RustU128) -> Bool {
+        (lhs.high, lhs.low) < (rhs.high, rhs.low)
+    }
+}
+
+extension RustI128: ExpressibleByIntegerLiteral {
+    public init(_ value: Int64) {
+        self.init(low: UInt64(bitPattern: value), high: value < 0 ? -1 : 0)
+    }
+
+    public init(integerLiteral value: Int64) {
+        self.init(value)
+    }
+}
+
+extension RustI128: Equatable, Hashable {
+    public static func == (lhs: RustI128, rhs: RustI128) -> Bool {
+        lhs.low == rhs.low && lhs.high == rhs.high
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(low)
+        hasher.combine(high)
+    }
+}
+
+extension RustI128: Comparable {
+    public static func < (lhs: RustI128, rhs: RustI128) -> Bool {
+        (lhs.high, lhs.low) < (rhs.high, rhs.low)
+    }
+}
+
+#if compiler(>=6.0)
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension RustU128 {
+    public init(_ value: UInt128) {
+        self.init(low: UInt64(truncatingIfNeeded: value), high: UInt64(truncatingIfNeeded: value >> 64))
+    }
+
+    /// Convert to a Swift `UInt128`.
+    public func toUInt128() -> UInt128 {
+        UInt128(high) << 64 | UInt128(low)
+    }
+}
+
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension RustI128 {
+    public init(_ value: Int128) {
+        self.init(low: UInt64(truncatingIfNeeded: value), high: Int64(truncatingIfNeeded: value >> 64))
+    }
+
+    /// Convert to a Swift `Int128`.
+    public func toInt128() -> Int128 {
+        Int128(high) << 64 | Int128(low)
+    }
+}
+#endif
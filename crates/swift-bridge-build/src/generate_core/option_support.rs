@@ -10,6 +10,8 @@ pub(super) fn swift_option_primitive_support() -> String {
         ("I64", "Int64", "123"),
         ("Usize", "UInt", "123"),
         ("Isize", "Int", "123"),
+        ("U128", "RustU128", "123"),
+        ("I128", "RustI128", "123"),
         ("F32", "Float", "123.4"),
         ("F64", "Double", "123.4"),
         ("Bool", "Bool", "false"),
@@ -47,6 +49,32 @@ extension Optional where Wrapped == {inner_ty} {{
         );
     }
 
+    // A `char` is passed over FFI as a `UInt32`.
+    all += r#"
+extension __private__OptionChar {
+    func intoSwiftRepr() -> Optional<Unicode.Scalar> {
+        if self.is_some {
+            return Unicode.Scalar(self.val)!
+        } else {
+            return nil
+        }
+    }
+
+    init(_ val: Optional<Unicode.Scalar>) {
+        if let val = val {
+            self = Self(val: val.value, is_some: true)
+        } else {
+            self = Self(val: 123, is_some: false)
+        }
+    }
+}
+extension Optional where Wrapped == Unicode.Scalar {
+    func intoFfiRepr() -> __private__OptionChar {
+        __private__OptionChar(self)
+    }
+}
+"#;
+
     all
 }
 
@@ -61,7 +89,10 @@ typedef struct __private__OptionU64 { uint64_t val; bool is_some; } __private__O
 typedef struct __private__OptionI64 { int64_t val; bool is_some; } __private__OptionI64;
 typedef struct __private__OptionUsize { uintptr_t val; bool is_some; } __private__OptionUsize;
 typedef struct __private__OptionIsize { intptr_t val; bool is_some; } __private__OptionIsize;
+typedef struct __private__OptionU128 { struct RustU128 val; bool is_some; } __private__OptionU128;
+typedef struct __private__OptionI128 { struct RustI128 val; bool is_some; } __private__OptionI128;
 typedef struct __private__OptionF32 { float val; bool is_some; } __private__OptionF32;
 typedef struct __private__OptionF64 { double val; bool is_some; } __private__OptionF64;
 typedef struct __private__OptionBool { bool val; bool is_some; } __private__OptionBool;
+typedef struct __private__OptionChar { uint32_t val; bool is_some; } __private__OptionChar;
 "#;
@@ -0,0 +1,43 @@
+/// A copy of a Rust fixed-size array `[T; N]`.
+///
+/// The number of elements is fixed when the array is created. Passing an array with a different
+/// number of elements than the Rust side expects is a programmer error.
+public struct RustArray<Element>: RandomAccessCollection, MutableCollection {
+    var elements: [Element]
+
+    public init(_ elements: [Element]) {
+        self.elements = elements
+    }
+
+    public var startIndex: Int {
+        0
+    }
+
+    public var endIndex: Int {
+        elements.count
+    }
+
+    public subscript(position: Int) -> Element {
+        get {
+            elements[position]
+        }
+        set {
+            elements[position] = newValue
+        }
+    }
+
+    /// Copy the array's elements into a Swift `Array`.
+    public func toArray() -> [Element] {
+        elements
+    }
+}
+
+extension RustArray: ExpressibleByArrayLiteral {
+    public init(arrayLiteral elements: Element...) {
+        self.init(elements)
+    }
+}
+
+extension RustArray: Equatable where Element: Equatable {}
+
+extension RustArray: Hashable where Element: Hashable {}
@@ -0,0 +1,200 @@
+public class RustHashMap<K, V> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let vtable: __private__RustHashMapVTable<K, V>
+
+    init(ptr: UnsafeMutableRawPointer, vtable: __private__RustHashMapVTable<K, V>) {
+        self.ptr = ptr
+        self.vtable = vtable
+    }
+
+    /// Rust returns a UInt, but we cast to an Int because many Swift APIs such as
+    /// `ForEach(0..rustHashMap.len())` expect Int.
+    public func len() -> Int {
+        Int(vtable.len(ptr))
+    }
+
+    public func get(_ key: K) -> Optional<V> {
+        vtable.get(ptr, key)
+    }
+
+    @discardableResult
+    public func insert(_ key: K, _ value: V) -> Optional<V> {
+        vtable.insert(ptr, key, value)
+    }
+
+    @discardableResult
+    public func remove(_ key: K) -> Optional<V> {
+        vtable.remove(ptr, key)
+    }
+
+    deinit {
+        if isOwned {
+            vtable.drop(ptr)
+        }
+    }
+}
+
+extension RustHashMap where K == RustString {
+    public func get(_ key: String) -> Optional<V> {
+        get(RustString(key))
+    }
+
+    @discardableResult
+    public func insert(_ key: String, _ value: V) -> Optional<V> {
+        insert(RustString(key), value)
+    }
+
+    @discardableResult
+    public func remove(_ key: String) -> Optional<V> {
+        remove(RustString(key))
+    }
+}
+
+extension RustHashMap: Sequence {
+    public func makeIterator() -> RustHashMapIterator<K, V> {
+        return RustHashMapIterator(self)
+    }
+}
+
+public class RustHashMapIterator<K, V>: IteratorProtocol {
+    let rustHashMap: RustHashMap<K, V>
+    let iterPtr: UnsafeMutableRawPointer
+
+    init (_ rustHashMap: RustHashMap<K, V>) {
+        self.rustHashMap = rustHashMap
+        self.iterPtr = rustHashMap.vtable.iter(rustHashMap.ptr)
+    }
+
+    public func next() -> (key: K, value: V)? {
+        let vtable = rustHashMap.vtable
+        if vtable.iterNext(iterPtr) {
+            return (key: vtable.iterKey(iterPtr), value: vtable.iterValue(iterPtr))
+        } else {
+            return nil
+        }
+    }
+
+    deinit {
+        rustHashMap.vtable.iterDrop(iterPtr)
+    }
+}
+
+/// The entries of a `RustHashMap`, copied out of Rust once so that walking over the map's
+/// indices doesn't need to call into Rust for every entry.
+final class RustHashMapSnapshot<K, V> {
+    let entries: [(key: K, value: V)]
+
+    init(_ entries: [(key: K, value: V)]) {
+        self.entries = entries
+    }
+}
+
+/// A position in a `RustHashMap`.
+///
+/// Entries are not stored contiguously, so `startIndex` takes a snapshot of the map's entries
+/// and every index that is reached from it shares that snapshot.
+/// Like any other `Collection`, indices are invalidated by mutating the map.
+public struct RustHashMapIndex<K, V>: Comparable {
+    let offset: Int
+    let snapshot: RustHashMapSnapshot<K, V>?
+
+    public static func == (lhs: RustHashMapIndex<K, V>, rhs: RustHashMapIndex<K, V>) -> Bool {
+        lhs.offset == rhs.offset
+    }
+
+    public static func < (lhs: RustHashMapIndex<K, V>, rhs: RustHashMapIndex<K, V>) -> Bool {
+        lhs.offset < rhs.offset
+    }
+}
+
+extension RustHashMap: Collection {
+    public typealias Index = RustHashMapIndex<K, V>
+
+    public func index(after i: RustHashMapIndex<K, V>) -> RustHashMapIndex<K, V> {
+        guard let snapshot = i.snapshot else {
+            fatalError("Cannot advance past the end of a RustHashMap")
+        }
+        let offset = i.offset + 1
+        if offset >= snapshot.entries.count {
+            return endIndex
+        }
+        return RustHashMapIndex(offset: offset, snapshot: snapshot)
+    }
+
+    public subscript(position: RustHashMapIndex<K, V>) -> (key: K, value: V) {
+        guard let snapshot = position.snapshot, position.offset < snapshot.entries.count else {
+            fatalError("Index out of range")
+        }
+        return snapshot.entries[position.offset]
+    }
+
+    public var startIndex: RustHashMapIndex<K, V> {
+        // We iterate manually since `Array(self)` would walk this collection's indices.
+        var entries: [(key: K, value: V)] = []
+        entries.reserveCapacity(len())
+        let iterator = makeIterator()
+        while let entry = iterator.next() {
+            entries.append(entry)
+        }
+
+        let snapshot = RustHashMapSnapshot(entries)
+        if snapshot.entries.isEmpty {
+            return endIndex
+        }
+        return RustHashMapIndex(offset: 0, snapshot: snapshot)
+    }
+
+    public var endIndex: RustHashMapIndex<K, V> {
+        RustHashMapIndex(offset: len(), snapshot: nil)
+    }
+
+    public var count: Int {
+        len()
+    }
+
+    public var isEmpty: Bool {
+        len() == 0
+    }
+}
+
+extension RustHashMap where K: Hashable {
+    /// Copy all of the map's entries into a Swift `Dictionary`.
+    public func toDictionary() -> [K: V] {
+        var dictionary: [K: V] = [:]
+        dictionary.reserveCapacity(len())
+        for (key, value) in self {
+            dictionary[key] = value
+        }
+        return dictionary
+    }
+}
+
+extension RustHashMap where K == RustString {
+    /// Copy all of the map's entries into a Swift `Dictionary`.
+    public func toDictionary() -> [String: V] {
+        var dictionary: [String: V] = [:]
+        dictionary.reserveCapacity(len())
+        for (key, value) in self {
+            dictionary[key.toString()] = value
+        }
+        return dictionary
+    }
+}
+
+/// The functions that a `RustHashMap<K, V>` uses to call into Rust.
+///
+/// Every `HashMap<K, V>` gets its own set of Rust functions, so the bridge module's generated
+/// code fills this in for each key and value type combination that it uses.
+struct __private__RustHashMapVTable<K, V> {
+    let drop: (UnsafeMutableRawPointer) -> ()
+    let len: (UnsafeMutableRawPointer) -> UInt
+    let get: (UnsafeMutableRawPointer, K) -> Optional<V>
+    let insert: (UnsafeMutableRawPointer, K, V) -> Optional<V>
+    let remove: (UnsafeMutableRawPointer, K) -> Optional<V>
+    let iter: (UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+    let iterNext: (UnsafeMutableRawPointer) -> Bool
+    let iterKey: (UnsafeMutableRawPointer) -> K
+    let iterValue: (UnsafeMutableRawPointer) -> V
+    let iterDrop: (UnsafeMutableRawPointer) -> ()
+}
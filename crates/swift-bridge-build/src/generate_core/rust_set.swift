@@ -0,0 +1,249 @@
+public final class RustSet<T: RustSetElement> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let kind: RustSetKind
+
+    public init(ptr: UnsafeMutableRawPointer, kind: RustSetKind) {
+        self.ptr = ptr
+        self.kind = kind
+    }
+
+    public init(kind: RustSetKind) {
+        ptr = T.setOfSelfNew(kind: kind)
+        self.kind = kind
+        isOwned = true
+    }
+
+    /// Creates an empty Rust `HashSet`.
+    public convenience init() {
+        self.init(kind: .hashSet)
+    }
+
+    /// Rust returns a UInt, but we cast to an Int because many Swift APIs such as
+    /// `ForEach(0..rustSet.len())` expect Int.
+    public func len() -> Int {
+        Int(T.setOfSelfLen(kind: kind, setPtr: ptr))
+    }
+
+    public var isEmpty: Bool {
+        len() == 0
+    }
+
+    public func contains(_ member: T) -> Bool {
+        T.setOfSelfContains(kind: kind, setPtr: ptr, value: member)
+    }
+
+    /// Inserting a `RustString` moves it into the Rust set, so the `RustString` that was passed
+    /// in can't be used afterwards. Use the returned `memberAfterInsert` instead.
+    @discardableResult
+    public func insert(_ newMember: T) -> (inserted: Bool, memberAfterInsert: T) {
+        T.setOfSelfInsert(kind: kind, setPtr: ptr, value: newMember)
+    }
+
+    @discardableResult
+    public func remove(_ member: T) -> Optional<T> {
+        T.setOfSelfRemove(kind: kind, setPtr: ptr, value: member)
+    }
+
+    /// Like `insert`, this moves a `RustString` into the Rust set.
+    @discardableResult
+    public func update(with newMember: T) -> Optional<T> {
+        let oldMember = remove(newMember)
+        insert(newMember)
+        return oldMember
+    }
+
+    deinit {
+        if isOwned {
+            T.setOfSelfFree(kind: kind, setPtr: ptr)
+        }
+    }
+}
+
+extension RustSet where T == RustString {
+    public func contains(_ member: String) -> Bool {
+        contains(RustString(member))
+    }
+
+    @discardableResult
+    public func insert(_ newMember: String) -> (inserted: Bool, memberAfterInsert: RustString) {
+        insert(RustString(newMember))
+    }
+
+    @discardableResult
+    public func remove(_ member: String) -> Optional<RustString> {
+        remove(RustString(member))
+    }
+}
+
+/// The Rust collection that is backing a `RustSet`.
+public enum RustSetKind {
+    case hashSet
+    case btreeSet
+}
+
+extension RustSet: Sequence {
+    public func makeIterator() -> RustSetIterator<T> {
+        return RustSetIterator(self)
+    }
+}
+
+public class RustSetIterator<T: RustSetElement>: IteratorProtocol {
+    let rustSet: RustSet<T>
+    let iterPtr: UnsafeMutableRawPointer
+
+    init (_ rustSet: RustSet<T>) {
+        self.rustSet = rustSet
+        self.iterPtr = T.setOfSelfIter(kind: rustSet.kind, setPtr: rustSet.ptr)
+    }
+
+    public func next() -> T? {
+        T.setIterOfSelfNext(iterPtr: iterPtr)
+    }
+
+    deinit {
+        T.setIterOfSelfFree(iterPtr: iterPtr)
+    }
+}
+
+// `RustSet` is a class, so it has reference semantics and does not conform to `SetAlgebra`,
+// which assumes that copying a set gives you an independent set.
+// Use `copy()` to get an independent set.
+extension RustSet: ExpressibleByArrayLiteral {
+    public convenience init(arrayLiteral elements: T...) {
+        self.init()
+        for element in elements {
+            insert(element)
+        }
+    }
+}
+
+extension RustSet: Equatable {
+    public static func == (lhs: RustSet<T>, rhs: RustSet<T>) -> Bool {
+        lhs.len() == rhs.len() && lhs.allSatisfy { rhs.contains($0) }
+    }
+}
+
+extension RustSet {
+    /// Copy the set's members into a new set of the same kind.
+    public func copy() -> RustSet<T> {
+        let set = RustSet(kind: kind)
+        set.formUnion(self)
+        return set
+    }
+
+    public func union(_ other: RustSet<T>) -> RustSet<T> {
+        let set = copy()
+        set.formUnion(other)
+        return set
+    }
+
+    public func intersection(_ other: RustSet<T>) -> RustSet<T> {
+        let set = RustSet(kind: kind)
+        for member in self where other.contains(member) {
+            set.insert(member)
+        }
+        return set
+    }
+
+    public func symmetricDifference(_ other: RustSet<T>) -> RustSet<T> {
+        let set = copy()
+        set.formSymmetricDifference(other)
+        return set
+    }
+
+    public func subtracting(_ other: RustSet<T>) -> RustSet<T> {
+        let set = RustSet(kind: kind)
+        for member in self where !other.contains(member) {
+            set.insert(member)
+        }
+        return set
+    }
+
+    public func formUnion(_ other: RustSet<T>) {
+        for member in other {
+            insert(member)
+        }
+    }
+
+    public func formIntersection(_ other: RustSet<T>) {
+        for member in self where !other.contains(member) {
+            remove(member)
+        }
+    }
+
+    public func formSymmetricDifference(_ other: RustSet<T>) {
+        for member in other {
+            if contains(member) {
+                remove(member)
+            } else {
+                insert(member)
+            }
+        }
+    }
+
+    public func subtract(_ other: RustSet<T>) {
+        for member in other {
+            remove(member)
+        }
+    }
+
+    public func isSubset(of other: RustSet<T>) -> Bool {
+        allSatisfy { other.contains($0) }
+    }
+
+    public func isSuperset(of other: RustSet<T>) -> Bool {
+        other.isSubset(of: self)
+    }
+
+    public func isDisjoint(with other: RustSet<T>) -> Bool {
+        !contains { other.contains($0) }
+    }
+}
+
+extension RustSet {
+    /// Rust functions expect a specific kind of set, so a set of the wrong kind gets copied into
+    /// a new set before being passed to Rust.
+    func intoRustSetKind(_ kind: RustSetKind) -> RustSet<T> {
+        if self.kind == kind {
+            return self
+        }
+        let set = RustSet(kind: kind)
+        set.formUnion(self)
+        return set
+    }
+}
+
+extension RustSet where T: Hashable {
+    /// Copy all of the set's members into a Swift `Set`.
+    public func toSet() -> Set<T> {
+        Set(self)
+    }
+}
+
+extension RustSet where T == RustString {
+    /// Copy all of the set's members into a Swift `Set`.
+    public func toSet() -> Set<String> {
+        Set(self.map { $0.toString() })
+    }
+}
+
+public protocol RustSetElement {
+    static func setOfSelfNew(kind: RustSetKind) -> UnsafeMutableRawPointer
+
+    static func setOfSelfFree(kind: RustSetKind, setPtr: UnsafeMutableRawPointer)
+
+    static func setOfSelfLen(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) -> UInt
+
+    static func setOfSelfContains(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: Self) -> Bool
+
+    static func setOfSelfInsert(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: Self) -> (inserted: Bool, memberAfterInsert: Self)
+
+    static func setOfSelfRemove(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: Self) -> Optional<Self>
+
+    static func setOfSelfIter(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    static func setIterOfSelfNext(iterPtr: UnsafeMutableRawPointer) -> Optional<Self>
+
+    static func setIterOfSelfFree(iterPtr: UnsafeMutableRawPointer)
+}
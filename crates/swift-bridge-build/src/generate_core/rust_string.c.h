@@ -2,14 +2,32 @@
 typedef struct RustString RustString;
 void __swift_bridge__$RustString$_free(void* self);
 
-void* __swift_bridge__$Vec_RustString$new(void);
-void __swift_bridge__$Vec_RustString$drop(void* vec_ptr);
-void __swift_bridge__$Vec_RustString$push(void* vec_ptr, void* item_ptr);
-void* __swift_bridge__$Vec_RustString$pop(void* vec_ptr);
-void* __swift_bridge__$Vec_RustString$get(void* vec_ptr, uintptr_t index);
-void* __swift_bridge__$Vec_RustString$get_mut(void* vec_ptr, uintptr_t index);
-uintptr_t __swift_bridge__$Vec_RustString$len(void* vec_ptr);
-void* __swift_bridge__$Vec_RustString$as_ptr(void* vec_ptr);
+void* __swift_bridge__$Vec_String$new(void);
+void __swift_bridge__$Vec_String$drop(void* vec_ptr);
+void __swift_bridge__$Vec_String$push(void* vec_ptr, void* item_ptr);
+void* __swift_bridge__$Vec_String$pop(void* vec_ptr);
+struct RustStr __swift_bridge__$Vec_String$get(void* vec_ptr, uintptr_t index);
+void* __swift_bridge__$Vec_String$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_String$len(void* vec_ptr);
+
+void* __swift_bridge__$HashSet_RustString$new(void);
+void __swift_bridge__$HashSet_RustString$_free(void* set_ptr);
+uintptr_t __swift_bridge__$HashSet_RustString$len(void* set_ptr);
+bool __swift_bridge__$HashSet_RustString$contains(void* set_ptr, struct RustStr val);
+bool __swift_bridge__$HashSet_RustString$insert(void* set_ptr, void* item_ptr);
+void* __swift_bridge__$HashSet_RustString$remove(void* set_ptr, struct RustStr val);
+void* __swift_bridge__$HashSet_RustString$iter(void* set_ptr);
+
+void* __swift_bridge__$BTreeSet_RustString$new(void);
+void __swift_bridge__$BTreeSet_RustString$_free(void* set_ptr);
+uintptr_t __swift_bridge__$BTreeSet_RustString$len(void* set_ptr);
+bool __swift_bridge__$BTreeSet_RustString$contains(void* set_ptr, struct RustStr val);
+bool __swift_bridge__$BTreeSet_RustString$insert(void* set_ptr, void* item_ptr);
+void* __swift_bridge__$BTreeSet_RustString$remove(void* set_ptr, struct RustStr val);
+void* __swift_bridge__$BTreeSet_RustString$iter(void* set_ptr);
+
+void* __swift_bridge__$SetIter_RustString$next(void* iter_ptr);
+void __swift_bridge__$SetIter_RustString$_free(void* iter_ptr);
 
 void* __swift_bridge__$RustString$new(void);
 void* __swift_bridge__$RustString$new_with_str(struct RustStr str);
@@ -5,6 +5,11 @@ public class RustString: RustStringRefMut {
         super.init(ptr: ptr)
     }
 
+    public required convenience init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        self.init(try container.decode(String.self))
+    }
+
     deinit {
         if isOwned {
             __swift_bridge__$RustString$_free(ptr)
@@ -22,6 +27,27 @@ extension RustString {
         }))
     }
 }
+extension RustString: Codable {
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(self.toString())
+    }
+}
+extension RustString: Equatable, Hashable {
+    public static func == (lhs: RustString, rhs: RustString) -> Bool {
+        lhs.as_str() == rhs.as_str()
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(bytes: UnsafeRawBufferPointer(self.as_str().toBufferPointer()))
+    }
+}
+extension RustString: Comparable {
+    /// Compares the UTF-8 bytes of the strings, the same way that Rust's `String` does.
+    public static func < (lhs: RustString, rhs: RustString) -> Bool {
+        lhs.as_str().toBufferPointer().lexicographicallyPrecedes(rhs.as_str().toBufferPointer())
+    }
+}
 public class RustStringRefMut: RustStringRef {
     public override init(ptr: UnsafeMutableRawPointer) {
         super.init(ptr: ptr)
@@ -49,19 +75,19 @@ extension RustStringRef {
 }
 extension RustString: Vectorizable {
     public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
-        __swift_bridge__$Vec_RustString$new()
+        __swift_bridge__$Vec_String$new()
     }
 
     public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
-        __swift_bridge__$Vec_RustString$drop(vecPtr)
+        __swift_bridge__$Vec_String$drop(vecPtr)
     }
 
     public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: RustString) {
-        __swift_bridge__$Vec_RustString$push(vecPtr, {value.isOwned = false; return value.ptr;}())
+        __swift_bridge__$Vec_String$push(vecPtr, {value.isOwned = false; return value.ptr;}())
     }
 
     public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
-        let pointer = __swift_bridge__$Vec_RustString$pop(vecPtr)
+        let pointer = __swift_bridge__$Vec_String$pop(vecPtr)
         if pointer == nil {
             return nil
         } else {
@@ -69,17 +95,17 @@ extension RustString: Vectorizable {
         }
     }
 
-    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustStringRef> {
-        let pointer = __swift_bridge__$Vec_RustString$get(vecPtr, index)
-        if pointer == nil {
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustStr> {
+        let val = __swift_bridge__$Vec_String$get(vecPtr, index)
+        if val.start == nil {
             return nil
         } else {
-            return RustStringRef(ptr: pointer!)
+            return val
         }
     }
 
     public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustStringRefMut> {
-        let pointer = __swift_bridge__$Vec_RustString$get_mut(vecPtr, index)
+        let pointer = __swift_bridge__$Vec_String$get_mut(vecPtr, index)
         if pointer == nil {
             return nil
         } else {
@@ -87,11 +113,93 @@ extension RustString: Vectorizable {
         }
     }
 
-    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<RustStringRef> {
-        UnsafePointer<RustStringRef>(OpaquePointer(__swift_bridge__$Vec_RustString$as_ptr(vecPtr)))
+    /// The Rust `Vec` holds `String`s and not `RustStr`s, so there is no pointer that Swift could
+    /// read the elements from. Use `get` instead.
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<RustStr> {
+        fatalError("RustVec<RustString>.as_ptr() is unsupported since the Rust Vec holds Strings and not RustStrs")
     }
 
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
-        __swift_bridge__$Vec_RustString$len(vecPtr)
+        __swift_bridge__$Vec_String$len(vecPtr)
+    }
+}
+
+extension RustVec where T == RustString {
+    /// Copy all of the vector's strings into a Swift `[String]`.
+    public func toStringArray() -> [String] {
+        self.map { $0.toString() }
     }
-}
\ No newline at end of file
+}
+
+extension RustString: RustSetElement {
+    public static func setOfSelfNew(kind: RustSetKind) -> UnsafeMutableRawPointer {
+        switch kind {
+        case .hashSet: return __swift_bridge__$HashSet_RustString$new()
+        case .btreeSet: return __swift_bridge__$BTreeSet_RustString$new()
+        }
+    }
+
+    public static func setOfSelfFree(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) {
+        switch kind {
+        case .hashSet: __swift_bridge__$HashSet_RustString$_free(setPtr)
+        case .btreeSet: __swift_bridge__$BTreeSet_RustString$_free(setPtr)
+        }
+    }
+
+    public static func setOfSelfLen(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) -> UInt {
+        switch kind {
+        case .hashSet: return __swift_bridge__$HashSet_RustString$len(setPtr)
+        case .btreeSet: return __swift_bridge__$BTreeSet_RustString$len(setPtr)
+        }
+    }
+
+    public static func setOfSelfContains(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: RustString) -> Bool {
+        switch kind {
+        case .hashSet: return __swift_bridge__$HashSet_RustString$contains(setPtr, value.as_str())
+        case .btreeSet: return __swift_bridge__$BTreeSet_RustString$contains(setPtr, value.as_str())
+        }
+    }
+
+    /// Rust takes ownership of the inserted string, so we hand back a copy of it.
+    public static func setOfSelfInsert(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: RustString) -> (inserted: Bool, memberAfterInsert: Self) {
+        let member = RustString(value.as_str()) as! Self
+        let valuePtr: UnsafeMutableRawPointer = {value.isOwned = false; return value.ptr;}()
+        switch kind {
+        case .hashSet: return (__swift_bridge__$HashSet_RustString$insert(setPtr, valuePtr), member)
+        case .btreeSet: return (__swift_bridge__$BTreeSet_RustString$insert(setPtr, valuePtr), member)
+        }
+    }
+
+    public static func setOfSelfRemove(kind: RustSetKind, setPtr: UnsafeMutableRawPointer, value: RustString) -> Optional<Self> {
+        let pointer: UnsafeMutableRawPointer?
+        switch kind {
+        case .hashSet: pointer = __swift_bridge__$HashSet_RustString$remove(setPtr, value.as_str())
+        case .btreeSet: pointer = __swift_bridge__$BTreeSet_RustString$remove(setPtr, value.as_str())
+        }
+        if pointer == nil {
+            return nil
+        } else {
+            return (RustString(ptr: pointer!) as! Self)
+        }
+    }
+
+    public static func setOfSelfIter(kind: RustSetKind, setPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+        switch kind {
+        case .hashSet: return __swift_bridge__$HashSet_RustString$iter(setPtr)
+        case .btreeSet: return __swift_bridge__$BTreeSet_RustString$iter(setPtr)
+        }
+    }
+
+    public static func setIterOfSelfNext(iterPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let pointer = __swift_bridge__$SetIter_RustString$next(iterPtr)
+        if pointer == nil {
+            return nil
+        } else {
+            return (RustString(ptr: pointer!) as! Self)
+        }
+    }
+
+    public static func setIterOfSelfFree(iterPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$SetIter_RustString$_free(iterPtr)
+    }
+}
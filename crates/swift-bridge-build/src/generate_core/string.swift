@@ -78,34 +78,77 @@ func optionalStringIntoRustString<S: IntoRustString>(_ string: Optional<S>) -> R
 ///    callback.
 /// 3. Pass the `RustStr` to the closure that was passed into `RustStr.toRustStr`.
 public protocol ToRustStr {
-    func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T;
+    func toRustStr<T> (_ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T;
 }
 
 extension String: ToRustStr {
     /// Safely get a scoped pointer to the String and then call the callback with a RustStr
     /// that uses that pointer.
-    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
-        return self.utf8CString.withUnsafeBufferPointer({ bufferPtr in
+    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T {
+        return try self.utf8CString.withUnsafeBufferPointer({ bufferPtr in
             let rustStr = RustStr(
                 start: UnsafeMutableRawPointer(mutating: bufferPtr.baseAddress!).assumingMemoryBound(to: UInt8.self),
                 // Subtract 1 because of the null termination character at the end
                 len: UInt(bufferPtr.count - 1)
             )
-            return withUnsafeRustStr(rustStr)
+            return try withUnsafeRustStr(rustStr)
         })
     }
 }
 
 extension RustStr: ToRustStr {
-    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
-        return withUnsafeRustStr(self)
+    public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T {
+        return try withUnsafeRustStr(self)
     }
 }
 
-func optionalRustStrToRustStr<S: ToRustStr, T>(_ str: Optional<S>, _ withUnsafeRustStr: (RustStr) -> T) -> T {
+func optionalRustStrToRustStr<S: ToRustStr, T>(_ str: Optional<S>, _ withUnsafeRustStr: (RustStr) throws -> T) rethrows -> T {
     if let val = str {
-        return val.toRustStr(withUnsafeRustStr)
+        return try val.toRustStr(withUnsafeRustStr)
     } else {
-        return withUnsafeRustStr(RustStr(start: nil, len: 0))
+        return try withUnsafeRustStr(RustStr(start: nil, len: 0))
+    }
+}
+
+/// Owns a copy of the utf8 bytes of a `String` that Swift returned to Rust as a `&str`.
+///
+/// The owner is retained when it is passed to Rust, and Rust releases it when it drops the
+/// `SwiftStr` that points into the bytes.
+final class SwiftStrOwner {
+    let bytes: UnsafeMutableBufferPointer<UInt8>
+    let len: Int
+
+    init(_ string: String) {
+        let utf8 = string.utf8
+        // An empty string still gets a non-null pointer, since Rust treats a null pointer as `None`.
+        bytes = UnsafeMutableBufferPointer<UInt8>.allocate(capacity: max(utf8.count, 1))
+        len = bytes.initialize(from: utf8).1
+    }
+
+    deinit {
+        bytes.deallocate()
+    }
+}
+
+extension String {
+    /// Give Rust a `&str` that points into a buffer that Swift keeps alive until Rust is done
+    /// with it.
+    func intoSwiftStr() -> __private__SwiftStr {
+        let owner = SwiftStrOwner(self)
+        return __private__SwiftStr(
+            str: RustStr(start: owner.bytes.baseAddress!, len: UInt(owner.len)),
+            owner: Unmanaged.passRetained(owner).toOpaque(),
+            release: { owner in
+                Unmanaged<SwiftStrOwner>.fromOpaque(owner!).release()
+            }
+        )
+    }
+}
+
+func optionalStringIntoSwiftStr(_ string: Optional<String>) -> __private__SwiftStr {
+    if let val = string {
+        return val.intoSwiftStr()
+    } else {
+        return __private__SwiftStr(str: RustStr(start: nil, len: 0), owner: nil, release: nil)
     }
 }
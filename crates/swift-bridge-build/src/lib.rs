@@ -7,7 +7,7 @@ mod package;
 use crate::generate_core::write_core_swift_and_c;
 pub use package::*;
 use std::path::Path;
-use swift_bridge_ir::{CodegenConfig, SwiftBridgeModule};
+use swift_bridge_ir::{CodegenConfig, GenericInstantiation, SwiftBridgeModule};
 use syn::__private::ToTokens;
 use syn::{File, Item};
 
@@ -68,6 +68,12 @@ impl GeneratedCode {
             concatenated_swift += &gen.swift;
             concatenated_c += &gen.c_header;
         }
+        for instantiation in self.generic_instantiations() {
+            concatenated_swift += &instantiation.swift;
+            concatenated_swift += "\n\n";
+            concatenated_c += &instantiation.c_header;
+            concatenated_c += "\n\n";
+        }
 
         let out = swift_bridge_out_dir.join(&crate_name);
         match std::fs::create_dir_all(&out) {
@@ -92,6 +98,10 @@ impl GeneratedCode {
         for gen in &self.generated {
             swift += &gen.swift;
         }
+        for instantiation in self.generic_instantiations() {
+            swift += &instantiation.swift;
+            swift += "\n\n";
+        }
 
         swift
     }
@@ -103,9 +113,37 @@ impl GeneratedCode {
         for gen in &self.generated {
             c_header += &gen.c_header;
         }
+        for instantiation in self.generic_instantiations() {
+            c_header += &instantiation.c_header;
+            c_header += "\n\n";
+        }
 
         c_header
     }
+
+    /// The generic built-in types, such as `HashMap<String, u32>`, that the crate's bridge
+    /// modules use.
+    ///
+    /// More than one bridge module can use the same type, so we only keep the first
+    /// instantiation with a given name.
+    fn generic_instantiations(&self) -> Vec<&GenericInstantiation> {
+        let mut instantiations: Vec<&GenericInstantiation> = vec![];
+
+        for instantiation in self
+            .generated
+            .iter()
+            .flat_map(|gen| gen.generic_instantiations.iter())
+        {
+            if !instantiations
+                .iter()
+                .any(|existing| existing.name == instantiation.name)
+            {
+                instantiations.push(instantiation);
+            }
+        }
+
+        instantiations
+    }
 }
 
 fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
@@ -114,6 +152,7 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
     let mut generated = GeneratedFromSwiftBridgeModule {
         c_header: "".to_string(),
         swift: "".to_string(),
+        generic_instantiations: vec![],
     };
 
     for item in file.items {
@@ -144,6 +183,10 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
                     let swift = &swift_and_c.swift;
                     generated.swift += &swift;
                     generated.swift += "\n\n";
+
+                    generated
+                        .generic_instantiations
+                        .extend(swift_and_c.generic_instantiations);
                 }
             }
             _ => {}
@@ -157,4 +200,5 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
 struct GeneratedFromSwiftBridgeModule {
     c_header: String,
     swift: String,
+    generic_instantiations: Vec<GenericInstantiation>,
 }
@@ -8,12 +8,17 @@ use quote::{quote, quote_spanned};
 use syn::{FnArg, Pat, PatType, Path, ReturnType, Type};
 
 pub(crate) use self::bridged_opaque_type::OpaqueForeignType;
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::{BoxedFnKind, BridgeableBoxedFn};
 use crate::bridged_type::bridgeable_pointer::{BuiltInPointer, Pointee, PointerKind};
 use crate::bridged_type::bridgeable_result::BuiltInResult;
 use crate::bridged_type::bridgeable_string::BridgedString;
+use crate::bridged_type::bridgeable_trait_object::BridgeableTraitObject;
+use crate::bridged_type::built_in_array::BuiltInArray;
+use crate::bridged_type::built_in_hash_map::BuiltInHashMap;
+use crate::bridged_type::built_in_set::BuiltInSet;
 use crate::bridged_type::built_in_tuple::BuiltInTuple;
 
+use crate::codegen::GenericInstantiation;
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 
 use self::bridged_option::BridgedOption;
@@ -23,11 +28,16 @@ pub(crate) use self::shared_struct::{SharedStruct, StructFields, StructSwiftRepr
 pub(crate) mod boxed_fn;
 mod bridgeable_pointer;
 mod bridgeable_result;
+pub(crate) mod bridgeable_serde_type;
 pub mod bridgeable_str;
 pub mod bridgeable_string;
+pub(crate) mod bridgeable_trait_object;
 pub mod bridged_opaque_type;
 mod bridged_option;
+mod built_in_array;
+mod built_in_hash_map;
 mod built_in_primitive;
+mod built_in_set;
 mod built_in_tuple;
 mod shared_enum;
 pub(crate) mod shared_struct;
@@ -110,6 +120,47 @@ pub(crate) trait BridgeableType: Debug {
     /// Some(vec![typedef struct __swift_bridge__$ResultVoidAndTransparentEnum { //... };])
     fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct>;
 
+    /// Generate the type's Swift support code if needed.
+    ///
+    /// # Examples
+    /// String -> None
+    /// [u8; 4] -> Some("extension __swift_bridge__$Array_U8_4 { //... }")
+    fn generate_custom_swift_code(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> Option<String>;
+
+    /// Generate the Swift code and C header declarations for a generic built-in type whose code
+    /// is shared by every bridge module in the crate.
+    ///
+    /// # Examples
+    /// String -> None
+    /// HashMap<String, u32> -> Some(GenericInstantiation {
+    ///     name: "HashMap_String_U32",
+    ///     swift: "extension RustHashMap where K == RustString, V == UInt32 { //... }",
+    ///     c_header: "void* __swift_bridge__$HashMap_String_U32$0123456789abcdef$new(void); //...",
+    /// })
+    fn generate_generic_instantiation(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<GenericInstantiation> {
+        None
+    }
+
+    /// Check that the type can be used in the given position.
+    ///
+    /// Some types are not yet supported everywhere, such as `Option<HashMap<K, V>>`. We report
+    /// these as parse errors instead of panicking while generating code.
+    ///
+    /// # Examples
+    /// HashMap<String, u32> in a shared struct field ->
+    /// Err("HashMap<K, V> shared struct fields are not yet supported.")
+    fn check_usage(&self, _usage: TypeUsage, _types: &TypeDeclarations) -> Result<(), String> {
+        Ok(())
+    }
+
     /// Get the Rust representation of this type.
     /// For a string this might be `std::string::String`.
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream;
@@ -205,7 +256,11 @@ pub(crate) trait BridgeableType: Debug {
     ) -> TokenStream;
 
     /// Convert an Option<Self> FFI representation to the Rust representation.
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream;
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream;
 
     /// Convert an FFI expression to this type's Swift representation.
     fn convert_ffi_expression_to_swift_type(
@@ -278,6 +333,11 @@ pub(crate) trait BridgeableType: Debug {
     ///  of checking the type.
     fn is_str(&self) -> bool;
 
+    /// Whether or not this is a `String`.
+    fn is_owned_string(&self) -> bool {
+        false
+    }
+
     /// Whether or not the type is a `String`, or a type that contains an owned String such as
     /// `Option<String>` or `struct Foo { field: String }`
     /// TODO: This is temporary as we move towards using this trait.. We should look at how
@@ -285,6 +345,34 @@ pub(crate) trait BridgeableType: Debug {
     ///  of checking the type.
     fn contains_owned_string_recursive(&self, types: &TypeDeclarations) -> bool;
 
+    /// Whether or not the type is a `Vec<T>` of the given custom type, or a type that contains one
+    /// such as `Option<Vec<T>>` or `(u8, Vec<T>)`.
+    fn contains_vec_of_recursive(&self, _ty_name: &str, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    /// Whether or not the type's Swift representation conforms to `Codable`, which a field of a
+    /// `#[swift_bridge(Codable)]` type must do.
+    fn is_swift_codable(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    /// Whether or not the type's Swift representation conforms to the given protocol, which a
+    /// field of a shared type that derives the corresponding Rust trait must do.
+    fn conforms_to_swift_comparison_protocol(
+        &self,
+        _protocol: ComparisonProtocol,
+        _types: &TypeDeclarations,
+    ) -> bool {
+        false
+    }
+
+    /// Whether or not the type is a `#[swift_bridge(serde)]` type, or a type that contains one
+    /// such as `Option<T>`.
+    fn contains_serde_type_recursive(&self) -> bool {
+        false
+    }
+
     /// Whether or not the type is a `&str`, or a type that contains a &str such as
     /// `Option<&str>` or `struct Foo { field: &'static str } `
     /// TODO: This is temporary as we move towards using this trait.. We should look at how
@@ -335,6 +423,19 @@ pub(crate) fn bridgeable_type_from_token_stream_str(
     if BridgedString::can_parse_token_stream_str(tokens) {
         return BridgedString::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
     }
+    if BuiltInHashMap::can_parse_token_stream_str(tokens) {
+        return BuiltInHashMap::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInSet::can_parse_token_stream_str(tokens) {
+        return BuiltInSet::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInArray::can_parse_token_stream_str(tokens) {
+        return BuiltInArray::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BridgeableTraitObject::can_parse_token_stream_str(tokens) {
+        return BridgeableTraitObject::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
 
     OpaqueForeignType::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _)
 }
@@ -385,9 +486,15 @@ pub(crate) enum StdLibType {
     I64,
     Usize,
     Isize,
+    /// Passed over FFI as a `RustU128`, since C and Swift don't have a portable 128 bit integer.
+    U128,
+    /// Passed over FFI as a `RustI128`, since C and Swift don't have a portable 128 bit integer.
+    I128,
     F32,
     F64,
     Bool,
+    /// `char`. Passed over FFI as a `u32` and seen on the Swift side as a `Unicode.Scalar`.
+    Char,
     /// `*const T` or `*mut T`
     Pointer(BuiltInPointer),
     /// `&[T]` or `&mut [T]`
@@ -395,7 +502,7 @@ pub(crate) enum StdLibType {
     /// &str
     Str,
     Vec(BuiltInVec),
-    BoxedFnOnce(BridgeableBoxedFnOnce),
+    BoxedFn(BridgeableBoxedFn),
     Option(BridgedOption),
     Result(BuiltInResult),
     Tuple(BuiltInTuple),
@@ -411,6 +518,62 @@ pub(crate) enum TypePosition {
     SwiftCallsRustAsyncOnCompleteReturnTy,
 }
 
+/// Where a type is used within a bridge module.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum TypeUsage {
+    /// A function argument, function return type or shared struct field.
+    Direct(TypePosition),
+    /// The `T` in an `Option<T>` that is used at the given position.
+    OptionInner(TypePosition),
+    /// The `T` or `E` in a `Result<T, E>` that is used at the given position.
+    ResultInner(TypePosition),
+    /// The `T` in a `Vec<T>` that is used at the given position.
+    VecElement(TypePosition),
+}
+
+impl TypeUsage {
+    /// The position of the outermost type, such as the function argument that an `Option<T>` is
+    /// passed as.
+    pub fn position(&self) -> TypePosition {
+        match self {
+            TypeUsage::Direct(position)
+            | TypeUsage::OptionInner(position)
+            | TypeUsage::ResultInner(position)
+            | TypeUsage::VecElement(position) => *position,
+        }
+    }
+}
+
+/// A Swift protocol that we generate a conformance to when a shared type derives the
+/// corresponding Rust trait.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum ComparisonProtocol {
+    /// `#[derive(PartialEq)]`
+    Equatable,
+    /// `#[derive(Hash)]`
+    Hashable,
+    /// `#[derive(PartialOrd)]`
+    Comparable,
+}
+
+impl ComparisonProtocol {
+    pub fn swift_name(&self) -> &'static str {
+        match self {
+            ComparisonProtocol::Equatable => "Equatable",
+            ComparisonProtocol::Hashable => "Hashable",
+            ComparisonProtocol::Comparable => "Comparable",
+        }
+    }
+
+    pub fn rust_derive(&self) -> &'static str {
+        match self {
+            ComparisonProtocol::Equatable => "PartialEq",
+            ComparisonProtocol::Hashable => "Hash",
+            ComparisonProtocol::Comparable => "PartialOrd",
+        }
+    }
+}
+
 /// &[T]
 #[derive(Debug)]
 pub(crate) struct BuiltInRefSlice {
@@ -423,6 +586,49 @@ pub(crate) struct BuiltInVec {
     pub ty: Box<BridgedType>,
 }
 
+impl BuiltInVec {
+    /// Whether or not this is a `Vec<String>` or a `Vec<&str>`.
+    ///
+    /// Both of these are passed over FFI as a `*mut Vec<String>` and become a
+    /// `RustVec<RustString>` on the Swift side.
+    fn is_vec_of_strings(&self, types: &TypeDeclarations) -> bool {
+        match self.ty.deref() {
+            BridgedType::StdLib(StdLibType::Str) => true,
+            BridgedType::Bridgeable(b) => {
+                b.is_built_in_type() && b.to_alpha_numeric_underscore_name(types) == "String"
+            }
+            _ => false,
+        }
+    }
+
+    /// Vec<u8> -> "RustVec<UInt8>"
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if self.is_vec_of_strings(types) {
+            return "RustVec<RustString>".to_string();
+        }
+
+        format!(
+            "RustVec<{}>",
+            self.ty.to_swift_type(type_pos, types, swift_bridge_path)
+        )
+    }
+
+    /// Vec<u8> -> *mut Vec<u8>
+    fn to_ffi_compatible_rust_type(&self, types: &TypeDeclarations) -> TokenStream {
+        if self.is_vec_of_strings(types) {
+            return quote! { *mut Vec<String> };
+        }
+
+        let ty = self.ty.to_rust_type_path(types);
+        quote! { *mut Vec<#ty> }
+    }
+}
+
 impl BridgedType {
     pub fn is_null(&self) -> bool {
         matches!(self, BridgedType::StdLib(StdLibType::Null))
@@ -533,7 +739,48 @@ impl BridgeableType for BridgedType {
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
-            BridgedType::Bridgeable(_) => None,
+            BridgedType::Bridgeable(ty) => ty.generate_custom_c_ffi_types(types),
+        }
+    }
+
+    fn generate_custom_swift_code(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> Option<String> {
+        match self {
+            BridgedType::Bridgeable(ty) => ty.generate_custom_swift_code(types, swift_bridge_path),
+            _ => None,
+        }
+    }
+
+    fn generate_generic_instantiation(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> Option<GenericInstantiation> {
+        match self {
+            BridgedType::Bridgeable(ty) => {
+                ty.generate_generic_instantiation(types, swift_bridge_path)
+            }
+            _ => None,
+        }
+    }
+
+    fn check_usage(&self, usage: TypeUsage, types: &TypeDeclarations) -> Result<(), String> {
+        match self {
+            BridgedType::StdLib(StdLibType::Option(opt)) => opt
+                .ty
+                .check_usage(TypeUsage::OptionInner(usage.position()), types),
+            BridgedType::StdLib(StdLibType::Result(result)) => result.check_usage(usage, types),
+            BridgedType::StdLib(StdLibType::Vec(vec)) => vec
+                .ty
+                .check_usage(TypeUsage::VecElement(usage.position()), types),
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.check_usage(usage)
+            }
+            BridgedType::Bridgeable(ty) => ty.check_usage(usage, types),
+            _ => Ok(()),
         }
     }
 
@@ -632,7 +879,11 @@ impl BridgeableType for BridgedType {
         todo!()
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
@@ -708,6 +959,30 @@ impl BridgeableType for BridgedType {
         self.contains_owned_string_recursive(types)
     }
 
+    fn contains_vec_of_recursive(&self, ty_name: &str, types: &TypeDeclarations) -> bool {
+        self.contains_vec_of_recursive(ty_name, types)
+    }
+
+    fn is_swift_codable(&self, types: &TypeDeclarations) -> bool {
+        self.is_swift_codable(types)
+    }
+
+    fn conforms_to_swift_comparison_protocol(
+        &self,
+        protocol: ComparisonProtocol,
+        types: &TypeDeclarations,
+    ) -> bool {
+        self.conforms_to_swift_comparison_protocol(protocol, types)
+    }
+
+    fn is_owned_string(&self) -> bool {
+        self.is_owned_string()
+    }
+
+    fn contains_serde_type_recursive(&self) -> bool {
+        self.contains_serde_type_recursive()
+    }
+
     fn contains_ref_string_recursive(&self) -> bool {
         todo!()
     }
@@ -773,8 +1048,12 @@ impl BridgedType {
                 Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
                     BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
                 }),
+                Type::TraitObject(_) => BridgeableTraitObject::from_type(ty, types)
+                    .map(|trait_object| BridgedType::Bridgeable(Box::new(trait_object))),
                 _ => None,
             },
+            Type::Array(_) => BuiltInArray::from_type(ty, types)
+                .map(|array| BridgedType::Bridgeable(Box::new(array))),
             Type::Tuple(tuple) => {
                 if tuple.elems.len() == 0 {
                     Some(BridgedType::StdLib(StdLibType::Null))
@@ -808,7 +1087,15 @@ impl BridgedType {
         let tokens = tokens.as_str();
         if tokens.starts_with("Vec < ") {
             let inner = tokens.trim_start_matches("Vec < ");
-            let inner = inner.trim_end_matches(" >");
+            let inner = inner.strip_suffix(" >").unwrap_or(inner);
+
+            // Remove spaces from generics. i.e. "SomeType < u32 >" -> "SomeType<u32>"
+            let inner = if inner.contains("<") {
+                inner.replace(" ", "")
+            } else {
+                inner.to_string()
+            };
+            let inner = inner.as_str();
 
             let inner = if let Some(declared_ty) = types.get(inner) {
                 declared_ty.to_bridged_type(false, false)
@@ -826,14 +1113,7 @@ impl BridgedType {
             let inner = &tokens[0..last_bracket];
             let inner = inner.trim_start_matches("Option < ");
 
-            // Remove spaces from generics. i.e. "SomeType < u32 > " -> "SomeType<u32>"
-            let inner = if inner.contains("<") {
-                inner.replace(" ", "")
-            } else {
-                inner.to_string()
-            };
-
-            let inner: Type = syn::parse2(TokenStream::from_str(&inner).unwrap()).unwrap();
+            let inner: Type = syn::parse2(TokenStream::from_str(inner).unwrap()).unwrap();
             let inner = BridgedType::new_with_type(&inner, types)?;
 
             return Some(BridgedType::StdLib(StdLibType::Option(BridgedOption {
@@ -843,9 +1123,9 @@ impl BridgedType {
             return Some(BridgedType::StdLib(StdLibType::Result(
                 BuiltInResult::from_str_tokens(&tokens, types)?,
             )));
-        } else if tokens.starts_with("Box < dyn FnOnce") {
-            return Some(BridgedType::StdLib(StdLibType::BoxedFnOnce(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, types)?,
+        } else if BoxedFnKind::from_str_tokens(tokens).is_some() {
+            return Some(BridgedType::StdLib(StdLibType::BoxedFn(
+                BridgeableBoxedFn::from_str_tokens(&tokens, types)?,
             )));
         } else if tokens.starts_with("(") {
             let tuple: Type = syn::parse2(TokenStream::from_str(&tokens).unwrap()).unwrap();
@@ -863,9 +1143,12 @@ impl BridgedType {
             "i64" => BridgedType::StdLib(StdLibType::I64),
             "usize" => BridgedType::StdLib(StdLibType::Usize),
             "isize" => BridgedType::StdLib(StdLibType::Isize),
+            "u128" => BridgedType::StdLib(StdLibType::U128),
+            "i128" => BridgedType::StdLib(StdLibType::I128),
             "f32" => BridgedType::StdLib(StdLibType::F32),
             "f64" => BridgedType::StdLib(StdLibType::F64),
             "bool" => BridgedType::StdLib(StdLibType::Bool),
+            "char" => BridgedType::StdLib(StdLibType::Char),
             "()" => BridgedType::StdLib(StdLibType::Null),
             _ => {
                 if let Some(b) = bridgeable_type_from_token_stream_str(tokens, types) {
@@ -901,9 +1184,12 @@ impl BridgedType {
                 StdLibType::I64 => quote! { i64 },
                 StdLibType::Usize => quote! { usize },
                 StdLibType::Isize => quote! { isize },
+                StdLibType::U128 => quote! { u128 },
+                StdLibType::I128 => quote! { i128 },
                 StdLibType::F32 => quote! { f32 },
                 StdLibType::F64 => quote! { f64 },
                 StdLibType::Bool => quote! { bool },
+                StdLibType::Char => quote! { char },
                 StdLibType::Pointer(ptr) => ptr.to_rust_type_path(types),
                 StdLibType::RefSlice(ref_slice) => {
                     let ty = ref_slice.ty.to_rust_type_path(types);
@@ -919,11 +1205,11 @@ impl BridgedType {
                     quote! { Option<#ty> }
                 }
                 StdLibType::Result(result) => result.to_rust_type_path(types),
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_rust_type_path(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_rust_type_path(types),
                 StdLibType::Tuple(tuple) => tuple.to_rust_type_path(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                let ty_name = &shared_struct.name;
+                let ty_name = shared_struct.rust_type_tokens(types);
                 if shared_struct.already_declared {
                     quote! {
                         super::#ty_name
@@ -974,7 +1260,10 @@ impl BridgedType {
                 StdLibType::F64 => quote! { f64 },
                 StdLibType::Usize => quote! { usize },
                 StdLibType::Isize => quote! { isize },
+                StdLibType::U128 => quote! { #swift_bridge_path::int128::RustU128 },
+                StdLibType::I128 => quote! { #swift_bridge_path::int128::RustI128 },
                 StdLibType::Bool => quote! { bool },
+                StdLibType::Char => quote! { u32 },
                 StdLibType::Pointer(ptr) => {
                     ptr.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
@@ -990,10 +1279,7 @@ impl BridgedType {
                 StdLibType::Null => {
                     quote! { () }
                 }
-                StdLibType::Vec(ty) => {
-                    let ty = ty.ty.to_rust_type_path(types);
-                    quote! { *mut Vec<#ty> }
-                }
+                StdLibType::Vec(ty) => ty.to_ffi_compatible_rust_type(types),
                 StdLibType::Option(opt) => match opt.ty.deref() {
                     BridgedType::Bridgeable(b) => {
                         b.to_ffi_compatible_option_rust_type(swift_bridge_path, types)
@@ -1032,6 +1318,12 @@ impl BridgedType {
                         StdLibType::Isize => {
                             quote! { #swift_bridge_path::option::OptionIsize }
                         }
+                        StdLibType::U128 => {
+                            quote! { #swift_bridge_path::option::OptionU128 }
+                        }
+                        StdLibType::I128 => {
+                            quote! { #swift_bridge_path::option::OptionI128 }
+                        }
                         StdLibType::F32 => {
                             quote! { #swift_bridge_path::option::OptionF32 }
                         }
@@ -1041,6 +1333,9 @@ impl BridgedType {
                         StdLibType::Bool => {
                             quote! { #swift_bridge_path::option::OptionBool }
                         }
+                        StdLibType::Char => {
+                            quote! { #swift_bridge_path::option::OptionChar }
+                        }
                         StdLibType::Pointer(_) => {
                             todo!("Option<*const T> and Option<*mut T> are not yet supported")
                         }
@@ -1050,17 +1345,14 @@ impl BridgedType {
                         StdLibType::Str => {
                             quote! { #swift_bridge_path::string::RustStr }
                         }
-                        StdLibType::Vec(ty) => {
-                            let ty = ty.ty.to_rust_type_path(types);
-                            quote! { *mut Vec<#ty> }
-                        }
+                        StdLibType::Vec(ty) => ty.to_ffi_compatible_rust_type(types),
                         StdLibType::Option(_) => {
                             todo!("Option<Option<T>> is not yet supported")
                         }
                         StdLibType::Result(_) => {
                             todo!("Option<Result<T, E>> is not yet supported")
                         }
-                        StdLibType::BoxedFnOnce(_) => {
+                        StdLibType::BoxedFn(_) => {
                             todo!("Support Box<dyn FnOnce(A, B) -> C>")
                         }
                         StdLibType::Tuple(_) => todo!(),
@@ -1081,13 +1373,13 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
                 StdLibType::Tuple(tuple) => {
                     tuple.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                shared_struct.type_name_with_swift_bridge_prefix(swift_bridge_path)
+                shared_struct.type_name_with_swift_bridge_prefix(swift_bridge_path, types)
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
                 let ty_name = &shared_enum.name;
@@ -1128,7 +1420,23 @@ impl BridgedType {
                 StdLibType::F64 => "Double".to_string(),
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::Isize => "Int".to_string(),
+                StdLibType::U128 => "RustU128".to_string(),
+                StdLibType::I128 => "RustI128".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
+                StdLibType::Char => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_rust() {
+                            "Unicode.Scalar".to_string()
+                        } else {
+                            "UInt32".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField
+                    | TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "Unicode.Scalar".to_string()
+                    }
+                },
                 StdLibType::Pointer(ptr) => {
                     let maybe_mutable = match ptr.kind {
                         PointerKind::Const => "",
@@ -1188,36 +1496,30 @@ impl BridgedType {
                 StdLibType::Vec(ty) => match type_pos {
                     TypePosition::FnArg(func_host_lang, _) => {
                         if func_host_lang.is_rust() {
-                            format!(
-                                "RustVec<{}>",
-                                ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
-                            )
+                            ty.to_swift_type(type_pos, types, swift_bridge_path)
                         } else {
                             "UnsafeMutableRawPointer".to_string()
                         }
                     }
                     TypePosition::FnReturn(func_host_lang) => {
                         if func_host_lang.is_rust() {
-                            format!(
-                                "RustVec<{}>",
-                                ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
-                            )
+                            ty.to_swift_type(type_pos, types, swift_bridge_path)
                         } else {
                             "UnsafeMutableRawPointer".to_string()
                         }
                     }
-                    _ => {
-                        format!(
-                            "RustVec<{}>",
-                            ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
-                        )
-                    }
+                    _ => ty.to_swift_type(type_pos, types, swift_bridge_path),
                 },
                 StdLibType::Option(opt) => opt.to_swift_type(swift_bridge_path, type_pos, types),
                 StdLibType::Result(result) => {
                     result.to_swift_type(type_pos, types, swift_bridge_path)
                 }
-                StdLibType::BoxedFnOnce(boxed_fn) => boxed_fn.to_swift_type().to_string(),
+                StdLibType::BoxedFn(boxed_fn) => match type_pos {
+                    TypePosition::FnArg(HostLang::Rust, _) => {
+                        boxed_fn.swift_closure_type(types, swift_bridge_path)
+                    }
+                    _ => boxed_fn.to_swift_type().to_string(),
+                },
                 StdLibType::Tuple(tuple) => tuple.to_swift_type(type_pos, types, swift_bridge_path),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
@@ -1225,12 +1527,14 @@ impl BridgedType {
                     TypePosition::FnArg(func_host_lang, _)
                     | TypePosition::FnReturn(func_host_lang) => {
                         if func_host_lang.is_rust() {
-                            shared_struct.swift_name_string()
+                            shared_struct.swift_type_string(types, swift_bridge_path)
                         } else {
                             shared_struct.ffi_name_string()
                         }
                     }
-                    TypePosition::SharedStructField => shared_struct.swift_name_string(),
+                    TypePosition::SharedStructField => {
+                        shared_struct.swift_type_string(types, swift_bridge_path)
+                    }
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         shared_struct.ffi_name_string()
                     }
@@ -1271,7 +1575,10 @@ impl BridgedType {
                 StdLibType::F64 => "double".to_string(),
                 StdLibType::Usize => "uintptr_t".to_string(),
                 StdLibType::Isize => "intptr_t".to_string(),
+                StdLibType::U128 => "struct RustU128".to_string(),
+                StdLibType::I128 => "struct RustI128".to_string(),
                 StdLibType::Bool => "bool".to_string(),
+                StdLibType::Char => "uint32_t".to_string(),
                 StdLibType::Pointer(ptr) => {
                     let maybe_const = match ptr.kind {
                         PointerKind::Const => " const ",
@@ -1291,7 +1598,7 @@ impl BridgedType {
                 StdLibType::Vec(_) => "void*".to_string(),
                 StdLibType::Option(opt) => opt.to_c(),
                 StdLibType::Result(result) => result.to_c(types).to_string(),
-                StdLibType::BoxedFnOnce(_) => "void*".to_string(),
+                StdLibType::BoxedFn(_) => "void*".to_string(),
                 StdLibType::Tuple(tuple) => tuple.to_c_type(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
@@ -1367,6 +1674,15 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote! { #expression }
                 }
+                StdLibType::U128 => {
+                    quote! { #swift_bridge_path::int128::RustU128::from_u128(#expression) }
+                }
+                StdLibType::I128 => {
+                    quote! { #swift_bridge_path::int128::RustI128::from_i128(#expression) }
+                }
+                StdLibType::Char => {
+                    quote! { (#expression) as u32 }
+                }
                 StdLibType::Pointer(_) => {
                     quote! {
                         #expression
@@ -1382,8 +1698,17 @@ impl BridgedType {
                         #swift_bridge_path::string::RustStr::from_str( #expression )
                     }
                 }
-                StdLibType::Vec(_) => {
-                    quote! { Box::into_raw(Box::new( #expression )) }
+                StdLibType::Vec(vec) => {
+                    if vec.ty.is_str() {
+                        // Swift can't hold on to borrowed strings, so we give it owned copies.
+                        quote! {
+                            Box::into_raw(Box::new(
+                                #expression.into_iter().map(|s| s.to_string()).collect::<Vec<String>>()
+                            ))
+                        }
+                    } else {
+                        quote! { Box::into_raw(Box::new( #expression )) }
+                    }
                 }
                 StdLibType::Option(opt) => {
                     opt.convert_rust_expression_to_ffi_type(expression, swift_bridge_path)
@@ -1394,7 +1719,7 @@ impl BridgedType {
                     types,
                     span,
                 ),
-                StdLibType::BoxedFnOnce(fn_once) => {
+                StdLibType::BoxedFn(fn_once) => {
                     fn_once.convert_rust_value_to_ffi_compatible_value(expression, types)
                 }
                 StdLibType::Tuple(tuple) => tuple.convert_rust_expression_to_ffi_type(
@@ -1457,6 +1782,15 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote_spanned! {span=> #value }
                 }
+                StdLibType::U128 => {
+                    quote_spanned! {span=> #value.to_u128() }
+                }
+                StdLibType::I128 => {
+                    quote_spanned! {span=> #value.to_i128() }
+                }
+                StdLibType::Char => {
+                    quote_spanned! {span=> char::from_u32(#value).unwrap() }
+                }
                 StdLibType::Pointer(_) => {
                     quote_spanned! {span=> #value }
                 }
@@ -1466,18 +1800,26 @@ impl BridgedType {
                 StdLibType::Str => {
                     quote_spanned! {span=> #value.to_str() }
                 }
-                StdLibType::Vec(_) => {
-                    quote_spanned! {span=>
-                        unsafe { * Box::from_raw(#value) }
+                StdLibType::Vec(vec) => {
+                    if vec.ty.is_str() {
+                        // The boxed `Vec<String>` is a temporary, so it lives until the end of the
+                        // statement that the borrowed strings get used in.
+                        quote_spanned! {span=>
+                            unsafe { Box::from_raw(#value) }.iter().map(|s| s.as_str()).collect::<Vec<&str>>()
+                        }
+                    } else {
+                        quote_spanned! {span=>
+                            unsafe { * Box::from_raw(#value) }
+                        }
                     }
                 }
                 StdLibType::Option(bridged_option) => {
-                    bridged_option.convert_ffi_expression_to_rust_type(value)
+                    bridged_option.convert_ffi_expression_to_rust_type(value, swift_bridge_path)
                 }
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_rust_value(value, span, swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(tuple) => {
@@ -1535,9 +1877,12 @@ impl BridgedType {
                 | StdLibType::I64
                 | StdLibType::Usize
                 | StdLibType::Isize
+                | StdLibType::U128
+                | StdLibType::I128
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => expression.to_string(),
+                StdLibType::Char => format!("Unicode.Scalar({})!", expression),
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
                     Pointee::BuiltIn(_) => expression.to_string(),
                     Pointee::Void(_ty) => match ptr.kind {
@@ -1580,9 +1925,7 @@ impl BridgedType {
                     types,
                     swift_bridge_path,
                 ),
-                StdLibType::BoxedFnOnce(fn_once) => {
-                    fn_once.convert_ffi_value_to_swift_value(type_pos)
-                }
+                StdLibType::BoxedFn(fn_once) => fn_once.convert_ffi_value_to_swift_value(type_pos),
                 StdLibType::Tuple(tuple) => tuple.convert_ffi_expression_to_swift_type(
                     expression,
                     type_pos,
@@ -1628,9 +1971,12 @@ impl BridgedType {
                 | StdLibType::I64
                 | StdLibType::Usize
                 | StdLibType::Isize
+                | StdLibType::U128
+                | StdLibType::I128
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => expression.to_string(),
+                StdLibType::Char => format!("{}.value", expression),
                 StdLibType::RefSlice(_) => {
                     format!("{}.toFfiSlice()", expression)
                 }
@@ -1681,7 +2027,7 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.convert_swift_expression_to_ffi_compatible(expression, types, type_pos)
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(tuple) => {
@@ -1740,7 +2086,8 @@ impl BridgedType {
                 | StdLibType::U64
                 | StdLibType::I64
                 | StdLibType::Usize
-                | StdLibType::Isize => Some(vec!["stdint.h"]),
+                | StdLibType::Isize
+                | StdLibType::Char => Some(vec!["stdint.h"]),
                 StdLibType::Bool => Some(vec!["stdbool.h"]),
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
                     Pointee::BuiltIn(ty) => ty.to_c_include(types),
@@ -1785,10 +2132,22 @@ impl BridgedType {
                     rust: quote! { 123 },
                     swift: "123".into(),
                 },
+                StdLibType::U128 => UnusedOptionNoneValue {
+                    rust: quote! { #swift_bridge_path::int128::RustU128 { low: 123, high: 0 } },
+                    swift: "123".into(),
+                },
+                StdLibType::I128 => UnusedOptionNoneValue {
+                    rust: quote! { #swift_bridge_path::int128::RustI128 { low: 123, high: 0 } },
+                    swift: "123".into(),
+                },
                 StdLibType::F32 | StdLibType::F64 => UnusedOptionNoneValue {
                     rust: quote! { 0.123 },
                     swift: "0.123".into(),
                 },
+                StdLibType::Char => UnusedOptionNoneValue {
+                    rust: quote! { 123 },
+                    swift: "123".into(),
+                },
                 StdLibType::Bool => UnusedOptionNoneValue {
                     rust: quote! { bool },
                     swift: "bool".into(),
@@ -1799,17 +2158,12 @@ impl BridgedType {
                 StdLibType::RefSlice(_) => {
                     todo!("Support Option<&[T]>")
                 }
-                StdLibType::Str => {
-                    UnusedOptionNoneValue {
-                        rust: quote! {
-                            #swift_bridge_path::string::RustStr {start: std::ptr::null::<u8>(), len: 0}
-                        },
-                        // TODO: Add integration tests:
-                        //  Rust: crates/swift-integration-tests/src/option.rs
-                        //  Swift: OptionTests.swift
-                        swift: "TODO_SWIFT_OPTIONAL_STR_SUPPORT".to_string(),
-                    }
-                }
+                StdLibType::Str => UnusedOptionNoneValue {
+                    rust: quote! {
+                        #swift_bridge_path::string::RustStr {start: std::ptr::null::<u8>(), len: 0}
+                    },
+                    swift: "RustStr(start: nil, len: 0)".to_string(),
+                },
                 StdLibType::Vec(_) => {
                     todo!("Support Option<Vec<T>>")
                 }
@@ -1819,7 +2173,7 @@ impl BridgedType {
                 StdLibType::Result(_) => {
                     todo!("Result<T, E> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(_tuple) => todo!(),
@@ -1828,26 +2182,43 @@ impl BridgedType {
                 let option_name = shared_struct.ffi_option_name_tokens();
                 UnusedOptionNoneValue {
                     rust: quote! { #option_name { is_some: false, val: std::mem::MaybeUninit::uninit() } },
-                    swift: "TODO..Support Swift Option<Enum>::None value".into(),
+                    swift: format!(
+                        "{}(is_some: false, val: {}())",
+                        shared_struct.ffi_option_name_string(),
+                        shared_struct.ffi_name_string()
+                    ),
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
                 let option_name = shared_enum.ffi_option_name_tokens();
                 UnusedOptionNoneValue {
                     rust: quote! { #option_name { is_some: false, val: std::mem::MaybeUninit::uninit() } },
-                    swift: "TODO..Support Swift Option<Enum>::None value".into(),
+                    swift: format!(
+                        "{}(is_some: false, val: {}())",
+                        shared_enum.ffi_option_name_string(),
+                        shared_enum.ffi_name_string()
+                    ),
                 }
             }
         }
     }
 
+    /// Whether or not this is a `String`.
+    pub fn is_owned_string(&self) -> bool {
+        match self {
+            BridgedType::Bridgeable(b) => b.is_owned_string(),
+            _ => false,
+        }
+    }
+
     /// Whether or not the type is a `String`, or a type that contains an owned String such as
     /// `Option<String>` or `struct Foo { field: String } `
+    ///
+    /// `Vec<String>` does not count since Swift passes it as a `RustVec<RustString>`.
     pub fn contains_owned_string_recursive(&self, types: &TypeDeclarations) -> bool {
         match self {
             BridgedType::Bridgeable(b) => b.contains_owned_string_recursive(types),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Vec(inner) => inner.ty.contains_owned_string_recursive(types),
                 StdLibType::Option(inner) => inner.ty.contains_owned_string_recursive(types),
                 StdLibType::Result(inner) => {
                     inner.ok_ty.contains_owned_string_recursive(types)
@@ -1867,14 +2238,149 @@ impl BridgedType {
         }
     }
 
+    /// Whether or not the type is a `Vec<T>` of the given custom type, or a type that contains one
+    /// such as `Option<Vec<T>>`.
+    pub fn contains_vec_of_recursive(&self, ty_name: &str, types: &TypeDeclarations) -> bool {
+        match self {
+            BridgedType::Bridgeable(b) => b.contains_vec_of_recursive(ty_name, types),
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::Vec(inner) => {
+                    inner.ty.to_rust_type_path(types).to_string() == ty_name
+                        || inner.ty.contains_vec_of_recursive(ty_name, types)
+                }
+                StdLibType::Option(inner) => inner.ty.contains_vec_of_recursive(ty_name, types),
+                StdLibType::Result(inner) => {
+                    inner.ok_ty.contains_vec_of_recursive(ty_name, types)
+                        || inner.err_ty.contains_vec_of_recursive(ty_name, types)
+                }
+                StdLibType::Tuple(ty) => ty.contains_vec_of_recursive(ty_name, types),
+                _ => false,
+            },
+            BridgedType::Foreign(_) => false,
+        }
+    }
+
+    /// Whether or not the type is a `#[swift_bridge(serde)]` type, or a type that contains one
+    /// such as `Option<T>`.
+    pub fn contains_serde_type_recursive(&self) -> bool {
+        match self {
+            BridgedType::Bridgeable(b) => b.contains_serde_type_recursive(),
+            BridgedType::StdLib(StdLibType::Option(inner)) => {
+                inner.ty.contains_serde_type_recursive()
+            }
+            BridgedType::StdLib(StdLibType::Result(inner)) => {
+                inner.ok_ty.contains_serde_type_recursive()
+                    || inner.err_ty.contains_serde_type_recursive()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether or not the type's Swift representation conforms to `Equatable`, `Hashable` or
+    /// `Comparable`.
+    ///
+    /// Swift's `Bool` and `Optional<T>` are not `Comparable`, and `RustStr` is only `Equatable`.
+    pub fn conforms_to_swift_comparison_protocol(
+        &self,
+        protocol: ComparisonProtocol,
+        types: &TypeDeclarations,
+    ) -> bool {
+        match self {
+            BridgedType::Bridgeable(b) => b.conforms_to_swift_comparison_protocol(protocol, types),
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Char => true,
+                StdLibType::Bool => protocol != ComparisonProtocol::Comparable,
+                StdLibType::Str => protocol == ComparisonProtocol::Equatable,
+                StdLibType::Option(inner) => {
+                    protocol != ComparisonProtocol::Comparable
+                        && inner
+                            .ty
+                            .conforms_to_swift_comparison_protocol(protocol, types)
+                }
+                _ => false,
+            },
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                // An already declared struct's derives live in the module that declares it.
+                if shared_struct.already_declared {
+                    return true;
+                }
+                let derives = &shared_struct.derives;
+                match protocol {
+                    ComparisonProtocol::Equatable => derives.partial_eq,
+                    ComparisonProtocol::Hashable => derives.hash,
+                    ComparisonProtocol::Comparable => derives.partial_ord,
+                }
+            }
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                if shared_enum.already_declared {
+                    return true;
+                }
+                let derives = &shared_enum.derive;
+                match protocol {
+                    ComparisonProtocol::Equatable => derives.partial_eq,
+                    ComparisonProtocol::Hashable => derives.hash,
+                    ComparisonProtocol::Comparable => derives.partial_ord,
+                }
+            }
+        }
+    }
+
+    /// Whether or not the type's Swift representation conforms to `Codable`, such as a `u32`,
+    /// `String`, `Option<T>` of a `Codable` type or another `#[swift_bridge(Codable)]` type.
+    ///
+    /// 128 bit integers, `char`, tuples, `RustVec<T>` and opaque type classes do not conform.
+    pub fn is_swift_codable(&self, types: &TypeDeclarations) -> bool {
+        match self {
+            BridgedType::Bridgeable(b) => b.is_swift_codable(types),
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool => true,
+                StdLibType::Option(inner) => inner.ty.is_swift_codable(types),
+                _ => false,
+            },
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.codable || shared_struct.serde
+            }
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                shared_enum.codable || shared_enum.serde
+            }
+        }
+    }
+
     /// Whether or not the type is a `&str`, or a type that contains a &str such as
     /// `Option<&str>` or `struct Foo { field: &'static str } `
+    ///
+    /// `Vec<&str>` does not count since Swift passes it as a `RustVec<RustString>`.
     pub fn contains_ref_string_recursive(&self) -> bool {
         match self {
             BridgedType::Bridgeable(b) => b.contains_ref_string_recursive(),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Str => true,
-                StdLibType::Vec(inner) => inner.ty.contains_ref_string_recursive(),
                 StdLibType::Option(inner) => inner.ty.contains_ref_string_recursive(),
                 _ => false,
             },
@@ -1890,10 +2396,14 @@ impl BridgedType {
     }
 
     /// Convert a rust expression into this type using
-    pub fn rust_expression_into(&self, expression: &TokenStream) -> TokenStream {
+    pub fn rust_expression_into(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
         match self {
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                let struct_name = &shared_struct.name;
+                let struct_name = shared_struct.rust_type_tokens(types);
 
                 let maybe_super = if shared_struct.already_declared {
                     quote! { super:: }
@@ -1934,12 +2444,17 @@ impl BridgedType {
                 StdLibType::U8 => "U8".to_string(),
                 StdLibType::U16 => "U16".to_string(),
                 StdLibType::U32 => "U32".to_string(),
+                StdLibType::U64 => "U64".to_string(),
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::I8 => "I8".to_string(),
                 StdLibType::I16 => "I16".to_string(),
                 StdLibType::I32 => "I32".to_string(),
+                StdLibType::I64 => "I64".to_string(),
                 StdLibType::Isize => "Int".to_string(),
+                StdLibType::U128 => "U128".to_string(),
+                StdLibType::I128 => "I128".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
+                StdLibType::Char => "Char".to_string(),
                 StdLibType::F32 => "F32".to_string(),
                 StdLibType::F64 => "F64".to_string(),
                 StdLibType::Tuple(ty) => ty.to_alpha_numeric_underscore_name(types),
@@ -1947,7 +2462,7 @@ impl BridgedType {
             },
             BridgedType::Foreign(ty) => match ty {
                 CustomBridgedType::Shared(ty) => match ty {
-                    SharedType::Struct(ty) => ty.name.to_string(),
+                    SharedType::Struct(ty) => ty.name.to_string() + &ty.generics.concrete_string(),
                     SharedType::Enum(ty) => ty.name.to_string(),
                 },
             },
@@ -1975,6 +2490,40 @@ mod tests {
 
     use super::*;
 
+    /// Verify that we check the element type of a `Vec<T>`, so that types that can't be used in a
+    /// `Vec<T>` are rejected.
+    #[test]
+    fn error_if_unsupported_vec_element() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn maps() -> Vec<HashMap<String, u8>>;
+                    fn sets() -> Vec<HashSet<u8>>;
+                    fn arrays() -> Vec<[u8; 2]>;
+                }
+            }
+        };
+
+        let errors = crate::test_utils::parse_errors(tokens);
+
+        let messages: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                crate::errors::ParseError::UnsupportedType { message, .. } => message.as_str(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Vec<HashMap<K, V>> is not yet supported.",
+                "Vec<HashSet<T>> is not yet supported.",
+                "Vec<[T; N]> is not yet supported.",
+            ]
+        );
+    }
+
     /// Verify that we treat newline characters as spaces when parsing a type from string.
     /// Not sure what can lead a stringified token stream to have newline characters in it but
     /// we've observed it in the wild so this test guards against mishandling that scenario.
@@ -1984,7 +2533,7 @@ mod tests {
 
         let parsed = BridgedType::new_with_str(tokens, &TypeDeclarations::default()).unwrap();
         match parsed {
-            BridgedType::StdLib(StdLibType::BoxedFnOnce(_)) => {}
+            BridgedType::StdLib(StdLibType::BoxedFn(_)) => {}
             _ => panic!(),
         };
     }
@@ -1,4 +1,4 @@
-use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::bridged_type::{BridgeableType, BridgedType, StdLibType, TypePosition};
 use crate::parse::HostLang;
 use crate::parsed_extern_fn::SwiftFuncGenerics;
 use crate::TypeDeclarations;
@@ -10,15 +10,85 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Path, Type};
 
-/// Box<dyn FnOnce(A, B, C) -> ()>
+/// Box<dyn FnOnce(A, B, C) -> ()>, Box<dyn FnMut(A) -> B> or Box<dyn Fn() -> C>
 #[derive(Debug)]
-pub(crate) struct BridgeableBoxedFnOnce {
+pub(crate) struct BridgeableBoxedFn {
+    /// Whether this is an `FnOnce`, `FnMut` or `Fn`.
+    pub kind: BoxedFnKind,
+    /// The `Send` and `Sync` bounds in `Box<dyn Fn() + Send + Sync>`.
+    pub auto_traits: Vec<Ident>,
     /// The functions parameters.
     pub params: Vec<BridgedType>,
     /// The functions return type.
     pub ret: Box<BridgedType>,
 }
 
+/// The `Fn*` trait that a boxed function implements.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum BoxedFnKind {
+    /// Box<dyn FnOnce()>
+    FnOnce,
+    /// Box<dyn FnMut()>
+    FnMut,
+    /// Box<dyn Fn()>
+    Fn,
+}
+
+impl BoxedFnKind {
+    /// Get the kind of boxed function and the rest of its signature.
+    ///
+    /// "Box < dyn FnMut (u8) -> u8 >" would give us:
+    /// (BoxedFnKind::FnMut, " (u8) -> u8 >")
+    pub fn from_str_tokens(string: &str) -> Option<(Self, &str)> {
+        let string = string.strip_prefix("Box < dyn ")?;
+
+        let (kind, signature) = if let Some(signature) = string.strip_prefix("FnOnce") {
+            (BoxedFnKind::FnOnce, signature)
+        } else if let Some(signature) = string.strip_prefix("FnMut") {
+            (BoxedFnKind::FnMut, signature)
+        } else if let Some(signature) = string.strip_prefix("Fn") {
+            (BoxedFnKind::Fn, signature)
+        } else {
+            return None;
+        };
+
+        if !signature.trim_start().starts_with("(") {
+            return None;
+        }
+
+        Some((kind, signature))
+    }
+
+    /// FnOnce, FnMut or Fn
+    pub fn to_trait_tokens(self) -> TokenStream {
+        match self {
+            BoxedFnKind::FnOnce => quote! { FnOnce },
+            BoxedFnKind::FnMut => quote! { FnMut },
+            BoxedFnKind::Fn => quote! { Fn },
+        }
+    }
+
+    /// Whether or not the function can only be called once.
+    pub fn is_fn_once(&self) -> bool {
+        matches!(self, BoxedFnKind::FnOnce)
+    }
+
+    /// Whether or not the function needs mutable access to itself when it is called.
+    pub fn is_fn_mut(&self) -> bool {
+        matches!(self, BoxedFnKind::FnMut)
+    }
+
+    /// The prefix of the Swift class that wraps the boxed function.
+    /// For example: "__private__RustFnMutCallback"
+    pub fn swift_callback_class_prefix(&self) -> &'static str {
+        match self {
+            BoxedFnKind::FnOnce => "__private__RustFnOnceCallback",
+            BoxedFnKind::FnMut => "__private__RustFnMutCallback",
+            BoxedFnKind::Fn => "__private__RustFnCallback",
+        }
+    }
+}
+
 /// example: Vec<SomeType, AnotherType, u32>
 pub(crate) struct FunctionArguments(pub Vec<Type>);
 impl Parse for FunctionArguments {
@@ -28,7 +98,7 @@ impl Parse for FunctionArguments {
     }
 }
 
-impl BridgeableBoxedFnOnce {
+impl BridgeableBoxedFn {
     pub fn does_not_have_params_or_return(&self) -> bool {
         self.params.is_empty() && self.ret.is_null()
     }
@@ -41,8 +111,10 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let kind = self.kind.to_trait_tokens();
+        let auto_traits = &self.auto_traits;
         quote! {
-            Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box<dyn #kind(#(#args),*) -> #ret #(+ #auto_traits)*>
         }
     }
 
@@ -51,27 +123,35 @@ impl BridgeableBoxedFnOnce {
         expression: &TokenStream,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let args: Vec<TokenStream> = self
-            .params
-            .iter()
-            .map(|a| a.to_rust_type_path(types))
-            .collect();
-        let ret = &self.ret.to_rust_type_path(types);
+        let ffi_ty = self.to_ffi_compatible_rust_type(types);
 
-        quote! {
-            Box::into_raw(Box::new(#expression)) as *mut Box<dyn FnOnce(#(#args),*) -> #ret>
+        if self.kind.is_fn_mut() {
+            quote! {
+                Box::into_raw(Box::new(std::cell::RefCell::new(#expression))) as #ffi_ty
+            }
+        } else {
+            quote! {
+                Box::into_raw(Box::new(#expression)) as #ffi_ty
+            }
         }
     }
 
+    /// *mut Box<dyn FnOnce(A, B) -> C>
+    ///
+    /// An `FnMut` is wrapped in a `RefCell` so that we can abort if Swift calls it again while it
+    /// is already running, instead of handing out two mutable references to it.
+    /// *mut std::cell::RefCell<Box<dyn FnMut(A, B) -> C>>
     pub fn to_ffi_compatible_rust_type(&self, types: &TypeDeclarations) -> TokenStream {
-        let params: Vec<TokenStream> = self
-            .params
-            .iter()
-            .map(|a| a.to_rust_type_path(types))
-            .collect();
-        let ret = &self.ret.to_rust_type_path(types);
-        quote! {
-            *mut Box<dyn FnOnce(#(#params),*) -> #ret>
+        let boxed_fn = self.to_rust_type_path(types);
+
+        if self.kind.is_fn_mut() {
+            quote! {
+                *mut std::cell::RefCell<#boxed_fn>
+            }
+        } else {
+            quote! {
+                *mut #boxed_fn
+            }
         }
     }
 
@@ -248,12 +328,297 @@ impl BridgeableBoxedFnOnce {
     }
 }
 
-impl BridgeableBoxedFnOnce {
+/// Support for passing a Swift closure to an `extern "Rust"` function, such as
+/// `fn some_function(callback: Box<dyn Fn(u32) -> bool + Send>)`.
+///
+/// Swift retains the closure and passes it to Rust as a `#[repr(C)]` struct that holds the
+/// retained closure along with trampolines for calling and releasing it.
+/// Rust wraps this struct in a boxed closure and releases the Swift closure when it is dropped.
+impl BridgeableBoxedFn {
+    /// Generates the `#[repr(C)]` struct that holds a Swift closure.
+    ///
+    /// ```ignore
+    /// #[repr(C)]
+    /// #[doc(hidden)]
+    /// pub struct __swift_bridge__some_function_param0 {
+    ///     context: *mut std::ffi::c_void,
+    ///     call: extern "C" fn(*mut std::ffi::c_void, u32) -> bool,
+    ///     release: extern "C" fn(*mut std::ffi::c_void),
+    /// }
+    /// impl __swift_bridge__some_function_param0 {
+    ///     fn call(&self, arg0: u32) -> bool {
+    ///         (self.call)(self.context, arg0)
+    ///     }
+    /// }
+    /// impl Drop for __swift_bridge__some_function_param0 {
+    ///     fn drop(&mut self) {
+    ///         (self.release)(self.context)
+    ///     }
+    /// }
+    /// unsafe impl Send for __swift_bridge__some_function_param0 {}
+    /// ```
+    pub fn swift_closure_rust_struct(
+        &self,
+        struct_name: &Ident,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ffi_param_tys: Vec<TokenStream> = self
+            .params
+            .iter()
+            .map(|ty| ty.to_ffi_compatible_rust_type(swift_bridge_path, types))
+            .collect();
+
+        let rust_params: Vec<TokenStream> = self
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let param_name = Ident::new(&format!("arg{}", idx), Span::call_site());
+                let param_ty = ty.to_rust_type_path(types);
+
+                quote! {
+                    #param_name: #param_ty
+                }
+            })
+            .collect();
+        let call_args: Vec<TokenStream> = self
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let arg_name = Ident::new(&format!("arg{}", idx), Span::call_site());
+                ty.convert_rust_expression_to_ffi_type(
+                    &arg_name.to_token_stream(),
+                    swift_bridge_path,
+                    types,
+                    arg_name.span(),
+                )
+            })
+            .collect();
+
+        let (ffi_ret, rust_ret) = if self.ret.is_null() {
+            (quote! {}, quote! {})
+        } else {
+            let ffi_ret = self
+                .ret
+                .to_ffi_compatible_rust_type(swift_bridge_path, types);
+            let rust_ret = self.ret.to_rust_type_path(types);
+            (quote! { -> #ffi_ret }, quote! { -> #rust_ret })
+        };
+
+        let call = quote! {
+            (self.call)(self.context #(, #call_args)*)
+        };
+        let call = self.ret.convert_ffi_expression_to_rust_type(
+            &call,
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+
+        let auto_trait_impls = self.auto_traits.iter().map(|auto_trait| {
+            quote! {
+                unsafe impl #auto_trait for #struct_name {}
+            }
+        });
+
+        quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #struct_name {
+                context: *mut std::ffi::c_void,
+                call: extern "C" fn(*mut std::ffi::c_void #(, #ffi_param_tys)*) #ffi_ret,
+                release: extern "C" fn(*mut std::ffi::c_void),
+            }
+            impl #struct_name {
+                fn call(&self, #(#rust_params),*) #rust_ret {
+                    #call
+                }
+            }
+            impl Drop for #struct_name {
+                fn drop(&mut self) {
+                    (self.release)(self.context)
+                }
+            }
+            #(#auto_trait_impls)*
+        }
+    }
+
+    /// Wraps the struct that holds a Swift closure in a Rust boxed closure.
+    ///
+    /// Box::new(move |arg0: u32| callback.call(arg0))
+    pub fn swift_closure_into_boxed_fn(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let params: Vec<TokenStream> = self
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let param_name = Ident::new(&format!("arg{}", idx), Span::call_site());
+                let param_ty = ty.to_rust_type_path(types);
+
+                quote! {
+                    #param_name: #param_ty
+                }
+            })
+            .collect();
+        let args: Vec<Ident> = (0..self.params.len())
+            .map(|idx| Ident::new(&format!("arg{}", idx), Span::call_site()))
+            .collect();
+
+        quote! {
+            Box::new(move |#(#params),*| #expression.call(#(#args),*))
+        }
+    }
+
+    /// The Swift type of the closure that gets passed to Rust.
+    ///
+    /// "@escaping (UInt32) -> Bool"
+    /// "@escaping @Sendable (UInt32) -> Bool" for `Box<dyn Fn(u32) -> bool + Send>`
+    pub fn swift_closure_type(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|ty| {
+                ty.to_swift_type(
+                    TypePosition::FnReturn(HostLang::Rust),
+                    types,
+                    swift_bridge_path,
+                )
+            })
+            .collect();
+        let ret = self.ret.to_swift_type(
+            TypePosition::FnArg(HostLang::Rust, 0),
+            types,
+            swift_bridge_path,
+        );
+
+        // Rust can call a `Send` or `Sync` function from other threads, so Swift needs to make
+        // sure that the closure is safe to call from them.
+        let maybe_sendable = if self.auto_traits.is_empty() {
+            ""
+        } else {
+            "@Sendable "
+        };
+
+        format!(
+            "@escaping {}({}) -> {}",
+            maybe_sendable,
+            params.join(", "),
+            ret
+        )
+    }
+
+    /// Retains a Swift closure and creates the struct that gets passed to Rust.
+    ///
+    /// __swift_bridge__$some_function$param0(
+    ///     context: Unmanaged.passRetained(__private__SwiftCallbackBox(callback)).toOpaque(),
+    ///     call: { context, arg0 in
+    ///         Unmanaged<__private__SwiftCallbackBox<(UInt32) -> Bool>>.fromOpaque(context!)
+    ///             .takeUnretainedValue().callback(arg0)
+    ///     },
+    ///     release: { context in Unmanaged<AnyObject>.fromOpaque(context!).release() }
+    /// )
+    pub fn convert_swift_closure_to_ffi_type(
+        &self,
+        expression: &str,
+        ffi_struct_name: &str,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let closure_ty = self
+            .swift_closure_type(types, swift_bridge_path)
+            .trim_start_matches("@escaping ")
+            .to_string();
+
+        let args: Vec<String> = self
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                ty.convert_ffi_expression_to_swift_type(
+                    &format!("arg{idx}"),
+                    TypePosition::FnReturn(HostLang::Rust),
+                    types,
+                    swift_bridge_path,
+                )
+            })
+            .collect();
+        let call = format!(
+            "Unmanaged<__private__SwiftCallbackBox<{closure_ty}>>.fromOpaque(context!).takeUnretainedValue().callback({})",
+            args.join(", ")
+        );
+        let call = self.ret.convert_swift_expression_to_ffi_type(
+            &call,
+            types,
+            TypePosition::FnArg(HostLang::Rust, 0),
+        );
+
+        let mut call_params = vec!["context".to_string()];
+        call_params.extend((0..self.params.len()).map(|idx| format!("arg{idx}")));
+        let call_params = call_params.join(", ");
+
+        format!(
+            "{ffi_struct_name}(context: Unmanaged.passRetained(__private__SwiftCallbackBox({expression})).toOpaque(), call: {{ {call_params} in {call} }}, release: {{ context in Unmanaged<AnyObject>.fromOpaque(context!).release() }})"
+        )
+    }
+
+    /// Declares the struct that holds a Swift closure along with the typedefs for its trampolines.
+    ///
+    /// typedef bool (*__swift_bridge__$some_function$param0$call)(void* context, uint32_t arg0);
+    /// typedef void (*__swift_bridge__$some_function$param0$release)(void* context);
+    /// typedef struct __swift_bridge__$some_function$param0 { ... } __swift_bridge__$some_function$param0;
+    pub fn swift_closure_c_typedefs(
+        &self,
+        ffi_struct_name: &str,
+        types: &TypeDeclarations,
+    ) -> String {
+        let ret = self.ret.to_c(types);
+
+        let maybe_params = if self.params.is_empty() {
+            "".to_string()
+        } else {
+            format!(", {}", self.params_to_c_types(types))
+        };
+
+        format!(
+            r#"typedef {ret} (*{ffi_struct_name}$call)(void* context{maybe_params});
+typedef void (*{ffi_struct_name}$release)(void* context);
+typedef struct {ffi_struct_name} {{ void* context; {ffi_struct_name}$call call; {ffi_struct_name}$release release; }} {ffi_struct_name};
+"#
+        )
+    }
+}
+
+impl BridgeableBoxedFn {
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
         // ( A , B , C ) -> D >
         //   OR
         // ( A , B , C ) >
-        let signature = string.trim_start_matches("Box < dyn FnOnce");
+        let (kind, signature) = BoxedFnKind::from_str_tokens(string)?;
+
+        // Parse out the closing `>` and the comma in:
+        //   Box<dyn FnOnce() -> (),>
+        let signature = signature.trim_end();
+        let signature = signature.strip_suffix(">").unwrap_or(signature).trim_end();
+        let mut signature = signature.strip_suffix(",").unwrap_or(signature);
+
+        // Parse out the auto traits in:
+        //   Box<dyn Fn() -> u8 + Send + Sync>
+        let mut auto_traits = vec![];
+        while let Some((rest, bound)) = signature.rsplit_once(" + ") {
+            let bound = bound.trim();
+            if bound != "Send" && bound != "Sync" {
+                break;
+            }
+
+            auto_traits.insert(0, Ident::new(bound, Span::call_site()));
+            signature = rest;
+        }
 
         let open_parens = signature.find("(").unwrap();
         let closing_parens = signature.find(")").unwrap();
@@ -263,16 +628,12 @@ impl BridgeableBoxedFnOnce {
         let return_idx = signature.rfind("->");
 
         // D
-        let ret = return_idx.map(|idx| &signature[(idx + 3)..signature.len() - 2]);
+        let ret = return_idx.map(|idx| &signature[(idx + 2)..]);
 
         let args = TokenStream::from_str(args).unwrap();
         let args: FunctionArguments = syn::parse2(args).unwrap();
 
         let ret = if let Some(ret) = ret {
-            // Parse out the comma in:
-            //   Box<dyn FnOnce() -> (),>
-            let ret = ret.trim_end_matches(",");
-
             let ret = syn::parse2::<Type>(TokenStream::from_str(ret).unwrap()).unwrap();
             BridgedType::new_with_type(&ret, types)?
         } else {
@@ -284,7 +645,9 @@ impl BridgeableBoxedFnOnce {
             args_bridged_tys.push(BridgedType::new_with_type(&arg, types)?);
         }
 
-        return Some(BridgeableBoxedFnOnce {
+        return Some(BridgeableBoxedFn {
+            kind,
+            auto_traits,
             params: args_bridged_tys,
             ret: Box::new(ret),
         });
@@ -301,7 +664,7 @@ mod tests {
         let tokens = quote! {Box<dyn FnOnce()>}.to_token_stream().to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null()
@@ -316,7 +679,7 @@ mod tests {
             .to_string();
 
         assert!(matches!(
-            *BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            *BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret,
             BridgedType::StdLib(StdLibType::U8)
@@ -331,7 +694,7 @@ mod tests {
             .to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
@@ -347,7 +710,7 @@ mod tests {
         let tokens = "Box < dyn FnOnce() -> () >";
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
@@ -372,11 +735,59 @@ mod tests {
             let tokens = test.to_token_stream().to_string();
 
             assert!(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+                BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                     .unwrap()
                     .ret
                     .is_null(),
             );
         }
     }
+
+    /// Verify that we parse whether a boxed fn is an `FnOnce`, `FnMut` or `Fn`.
+    #[test]
+    fn parses_boxed_fn_kind() {
+        let tests = vec![
+            (quote! {Box<dyn FnOnce(u8)>}, BoxedFnKind::FnOnce),
+            (quote! {Box<dyn FnMut(u8) -> u8>}, BoxedFnKind::FnMut),
+            (quote! {Box<dyn Fn() -> u8>}, BoxedFnKind::Fn),
+        ];
+
+        for (tokens, expected_kind) in tests {
+            let tokens = tokens.to_token_stream().to_string();
+
+            assert_eq!(
+                BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
+                    .unwrap()
+                    .kind,
+                expected_kind
+            );
+        }
+    }
+
+    /// Verify that a boxed trait object whose name starts with `Fn` is not parsed as a boxed fn.
+    #[test]
+    fn does_not_parse_trait_starting_with_fn() {
+        let tokens = quote! {Box<dyn FnLike>}.to_token_stream().to_string();
+
+        assert!(BoxedFnKind::from_str_tokens(&tokens).is_none());
+    }
+
+    /// Verify that we parse the `Send` and `Sync` bounds of a boxed fn.
+    #[test]
+    fn parses_auto_traits() {
+        let tokens = quote! {Box<dyn Fn(u32) -> bool + Send + Sync>}
+            .to_token_stream()
+            .to_string();
+
+        let boxed_fn =
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+
+        assert_eq!(boxed_fn.auto_traits.len(), 2);
+        assert_eq!(boxed_fn.auto_traits[0], "Send");
+        assert_eq!(boxed_fn.auto_traits[1], "Sync");
+        assert!(matches!(
+            *boxed_fn.ret,
+            BridgedType::StdLib(StdLibType::Bool)
+        ));
+    }
 }
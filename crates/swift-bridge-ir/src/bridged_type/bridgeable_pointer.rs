@@ -57,6 +57,14 @@ impl BridgeableType for BuiltInPointer {
         None
     }
 
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
         match &self.pointee {
             Pointee::BuiltIn(ty) => {
@@ -173,7 +181,11 @@ impl BridgeableType for BuiltInPointer {
         todo!()
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
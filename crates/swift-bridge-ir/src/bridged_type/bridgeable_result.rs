@@ -1,4 +1,4 @@
-use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, TypePosition};
+use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, TypePosition, TypeUsage};
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
@@ -549,6 +549,18 @@ impl BuiltInResult {
             err_ty: Box::new(err),
         })
     }
+
+    /// Check that the `T` and `E` of the `Result<T, E>` can be used inside of a `Result`.
+    pub(super) fn check_usage(
+        &self,
+        usage: TypeUsage,
+        types: &TypeDeclarations,
+    ) -> Result<(), String> {
+        let usage = TypeUsage::ResultInner(usage.position());
+
+        self.ok_ty.check_usage(usage, types)?;
+        self.err_ty.check_usage(usage, types)
+    }
 }
 
 impl BuiltInResult {
@@ -0,0 +1,619 @@
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, TypeUsage, UnusedOptionNoneValue,
+};
+use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::fmt::{Debug, Formatter};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// A shared struct or enum that is annotated with `#[swift_bridge(serde)]`.
+///
+/// The value gets passed over FFI as a pointer to the boxed Rust value.
+/// Swift converts the boxed value to and from a generated `Codable` type by passing JSON through
+/// the type's generated `$_to_json` and `$_from_json` functions, and throws any serde error.
+#[derive(Clone)]
+pub(crate) struct BridgeableSerdeType {
+    pub ty: Ident,
+    pub swift_name: String,
+}
+
+impl BridgeableType for BridgeableSerdeType {
+    fn is_built_in_type(&self) -> bool {
+        false
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
+    fn check_usage(&self, usage: TypeUsage, _types: &TypeDeclarations) -> Result<(), String> {
+        let position = match usage {
+            TypeUsage::VecElement(_) => {
+                return Err(
+                    "#[swift_bridge(serde)] types within a Vec<T> are not yet supported."
+                        .to_string(),
+                )
+            }
+            TypeUsage::ResultInner(_) => {
+                return Err(
+                    "#[swift_bridge(serde)] types within a Result<T, E> are not yet supported."
+                        .to_string(),
+                )
+            }
+            TypeUsage::Direct(position) | TypeUsage::OptionInner(position) => position,
+        };
+
+        // Swift can only throw an encoding or decoding error when it calls a Rust function.
+        match position {
+            TypePosition::FnArg(HostLang::Rust, _) | TypePosition::FnReturn(HostLang::Rust) => {
+                Ok(())
+            }
+            TypePosition::FnArg(HostLang::Swift, _) | TypePosition::FnReturn(HostLang::Swift) => {
+                Err(
+                    r#"#[swift_bridge(serde)] types can not yet be used in extern "Swift" functions."#
+                        .to_string(),
+                )
+            }
+            TypePosition::SharedStructField => Err(
+                "#[swift_bridge(serde)] types can only be fields of other #[swift_bridge(serde)] types."
+                    .to_string(),
+            ),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => Err(
+                "#[swift_bridge(serde)] types can not yet be used in async functions.".to_string(),
+            ),
+        }
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.ty;
+        quote! { #ty }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_name.clone()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!(
+                    "Serde types in non-serde shared struct fields are rejected while parsing"
+                )
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                unreachable!("Serde types in async functions are rejected while parsing")
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! {
+            Box::into_raw(Box::new(#expression))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new(val))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "try serdeIntoRust({}, {})",
+            expression,
+            self.ffi_from_json_fn_name()
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "try optionalSerdeIntoRust({}, {})",
+            expression,
+            self.ffi_from_json_fn_name()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! {
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(unsafe { * Box::from_raw(val) })
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        format!(
+            "try serdeFromRust({}.self, {}, {})",
+            self.swift_name,
+            expression,
+            self.ffi_to_json_fn_name()
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "try optionalSerdeFromRust({}.self, {}, {})",
+            self.swift_name,
+            expression,
+            self.ffi_to_json_fn_name()
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Serde types within a Result are rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Serde types within a Result are rejected while parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        let ty = &self.ty;
+
+        UnusedOptionNoneValue {
+            rust: quote! {
+                std::ptr::null_mut::<#ty>()
+            },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(_tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::parse_token_stream_str(&ty.to_token_stream().to_string(), types)
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        types.get(tokens)?.to_serde_type()
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn is_swift_codable(&self, _types: &TypeDeclarations) -> bool {
+        true
+    }
+
+    fn contains_serde_type_recursive(&self) -> bool {
+        true
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        self.ty.to_string()
+    }
+}
+
+impl BridgeableSerdeType {
+    /// The Rust function that Swift calls to convert the boxed Rust value into JSON.
+    ///
+    /// __swift_bridge__$SomeType$_to_json
+    pub fn ffi_to_json_fn_name(&self) -> String {
+        format!("{}${}$_to_json", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
+    /// The Rust function that Swift calls to convert JSON into a boxed Rust value.
+    ///
+    /// __swift_bridge__$SomeType$_from_json
+    pub fn ffi_from_json_fn_name(&self) -> String {
+        format!("{}${}$_from_json", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+}
+
+impl TypeDeclaration {
+    /// Some if this is a `#[swift_bridge(serde)]` struct or enum.
+    pub(crate) fn to_serde_type(&self) -> Option<BridgeableSerdeType> {
+        match self {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))
+                if shared_struct.serde =>
+            {
+                Some(BridgeableSerdeType {
+                    ty: shared_struct.name.clone(),
+                    swift_name: shared_struct.swift_name_string(),
+                })
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum))
+                if shared_enum.serde =>
+            {
+                Some(BridgeableSerdeType {
+                    ty: shared_enum.name.clone(),
+                    swift_name: shared_enum.swift_name_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Get the Swift type that a field of a `#[swift_bridge(serde)]` type is decoded into.
+///
+/// Since the value is decoded from JSON we use Swift's own types instead of our FFI types.
+///
+/// Returns the unsupported type if the field's type, or a type within it, has no Swift
+/// equivalent that we know of.
+///
+/// # Examples
+/// String -> String
+/// Vec<u8> -> [UInt8]
+/// Option<String> -> Optional<String>
+/// HashMap<String, u32> -> [String: UInt32]
+/// SomeSerdeType -> SomeSerdeType
+pub(crate) fn serde_field_swift_type<'a>(
+    ty: &'a Type,
+    types: &TypeDeclarations,
+) -> Result<String, &'a Type> {
+    let path = match ty {
+        Type::Path(path) => &path.path,
+        Type::Reference(reference) => return serde_field_swift_type(&reference.elem, types),
+        Type::Paren(paren) => return serde_field_swift_type(&paren.elem, types),
+        Type::Slice(slice) => {
+            return Ok(format!("[{}]", serde_field_swift_type(&slice.elem, types)?))
+        }
+        Type::Array(array) => {
+            return Ok(format!("[{}]", serde_field_swift_type(&array.elem, types)?))
+        }
+        _ => return Err(ty),
+    };
+    let last = path.segments.last().unwrap();
+
+    let mut generic_args = vec![];
+    if let PathArguments::AngleBracketed(args) = &last.arguments {
+        for arg in args.args.iter() {
+            if let GenericArgument::Type(ty) = arg {
+                generic_args.push(serde_field_swift_type(ty, types)?);
+            }
+        }
+    }
+
+    let name = last.ident.to_string();
+    let swift_ty = match (name.as_str(), generic_args.as_slice()) {
+        ("u8", []) => "UInt8".to_string(),
+        ("u16", []) => "UInt16".to_string(),
+        ("u32", []) => "UInt32".to_string(),
+        ("u64", []) => "UInt64".to_string(),
+        ("usize", []) => "UInt".to_string(),
+        ("i8", []) => "Int8".to_string(),
+        ("i16", []) => "Int16".to_string(),
+        ("i32", []) => "Int32".to_string(),
+        ("i64", []) => "Int64".to_string(),
+        ("isize", []) => "Int".to_string(),
+        ("f32", []) => "Float".to_string(),
+        ("f64", []) => "Double".to_string(),
+        ("bool", []) => "Bool".to_string(),
+        ("String" | "str" | "char", []) => "String".to_string(),
+        ("Box", [inner]) => inner.clone(),
+        ("Option", [inner]) => format!("Optional<{}>", inner),
+        ("Vec" | "VecDeque" | "HashSet" | "BTreeSet", [inner]) => format!("[{}]", inner),
+        ("HashMap" | "BTreeMap", [key, value]) => format!("[{}: {}]", key, value),
+        (_, []) => match types.get(&name) {
+            Some(TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)))
+                if shared_struct.serde =>
+            {
+                shared_struct.swift_name_string()
+            }
+            Some(TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)))
+                if shared_enum.serde =>
+            {
+                shared_enum.swift_name_string()
+            }
+            _ => return Err(ty),
+        },
+        _ => return Err(ty),
+    };
+
+    Ok(swift_ty)
+}
+
+impl Debug for BridgeableSerdeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BridgeableSerdeType")
+            .field("ty", &self.ty.to_token_stream())
+            .field("swift_name", &self.swift_name)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseError;
+    use crate::test_utils::parse_errors;
+    use quote::{quote, ToTokens};
+
+    /// Verify that we reject serde types in positions where the generated Swift code can not throw.
+    #[test]
+    fn error_if_unsupported_position() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(serde)]
+                struct Config {
+                    name: String,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Wrapper {
+                    config: Config,
+                }
+
+                extern "Rust" {
+                    type Settings;
+
+                    fn try_load_config() -> Result<Config, String>;
+                    async fn save_config(config: Config);
+                    async fn load_config() -> Config;
+
+                    #[swift_bridge(property = "config")]
+                    fn config(&self) -> Config;
+                    #[swift_bridge(setter = "config")]
+                    fn set_config(&mut self, config: Config);
+                }
+
+                extern "Swift" {
+                    fn swift_config() -> Config;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let messages: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedType { message, .. } => message.as_str(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "#[swift_bridge(serde)] types within a Result<T, E> are not yet supported.",
+                "#[swift_bridge(serde)] types can not yet be used in async functions.",
+                "#[swift_bridge(serde)] types can not yet be used in async functions.",
+                "#[swift_bridge(serde)] types can not yet be used in property getters or setters.",
+                "#[swift_bridge(serde)] types can not yet be used in property getters or setters.",
+                r#"#[swift_bridge(serde)] types can not yet be used in extern "Swift" functions."#,
+                "#[swift_bridge(serde)] types can only be fields of other #[swift_bridge(serde)] types.",
+            ]
+        );
+    }
+
+    /// Verify that we reject serde types within a `Vec<T>`, since Swift has no `RustVec<T>` support
+    /// for them.
+    #[test]
+    fn error_if_vec_of_serde_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(serde)]
+                struct Config {
+                    name: String,
+                }
+
+                extern "Rust" {
+                    fn load_configs() -> Vec<Config>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::UnsupportedType { ty, message } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Vec < Config >");
+                assert_eq!(
+                    message,
+                    "#[swift_bridge(serde)] types within a Vec<T> are not yet supported."
+                );
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we reject serde type fields that have no Swift type to be decoded into.
+    #[test]
+    fn error_if_unsupported_field_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(serde)]
+                struct Config {
+                    name: String,
+                    id: u128,
+                    ids: Vec<(u8, u8)>,
+                }
+
+                #[swift_bridge(serde)]
+                enum Shape {
+                    Polygon(Vec<f64>),
+                    Custom { points: Vec<NotSerde> },
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct NotSerde {
+                    x: f64,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedType { ty, .. } => ty.to_token_stream().to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(tys, vec!["u128", "(u8 , u8)", "NotSerde"]);
+    }
+}
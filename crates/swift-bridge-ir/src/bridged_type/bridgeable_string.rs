@@ -1,5 +1,6 @@
 use crate::bridged_type::{
-    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+    BridgeableType, CFfiStruct, ComparisonProtocol, OnlyEncoding, TypePosition,
+    UnusedOptionNoneValue,
 };
 use crate::TypeDeclarations;
 use proc_macro2::{Span, TokenStream};
@@ -42,6 +43,14 @@ impl BridgeableType for BridgedString {
         None
     }
 
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
     fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
         // FIXME: Change to `::std::string::String`
         quote! { String }
@@ -179,7 +188,7 @@ impl BridgeableType for BridgedString {
         type_pos: TypePosition,
     ) -> String {
         match type_pos {
-            TypePosition::FnArg(_func_host_lang, _) => {
+            TypePosition::FnArg(_, _) | TypePosition::SharedStructField => {
                 format!(
                     "{{ if let rustString = optionalStringIntoRustString({expression}) {{ rustString.isOwned = false; return rustString.ptr }} else {{ return nil }} }}()",
                     expression = expression
@@ -195,9 +204,6 @@ impl BridgeableType for BridgedString {
                     )
                 }
             }
-            TypePosition::SharedStructField => {
-                todo!("Option<String> fields in structs are not yet supported.")
-            }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                 unimplemented!()
             }
@@ -216,7 +222,11 @@ impl BridgeableType for BridgedString {
         }
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         quote! {
             {
                 let val = #expression;
@@ -284,10 +294,7 @@ impl BridgeableType for BridgedString {
             rust: quote! {
                 std::ptr::null::<#swift_bridge_path::string::RustString>() as *mut #swift_bridge_path::string::RustString
             },
-            // TODO: Add integration tests:
-            //  Rust: crates/swift-integration-tests/src/option.rs
-            //  Swift: OptionTests.swift
-            swift: "TODO_SWIFT_OPTIONAL_STRING_SUPPORT".to_string(),
+            swift: "nil".to_string(),
         }
     }
 
@@ -326,10 +333,26 @@ impl BridgeableType for BridgedString {
         false
     }
 
+    fn is_owned_string(&self) -> bool {
+        true
+    }
+
     fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
         true
     }
 
+    fn is_swift_codable(&self, _types: &TypeDeclarations) -> bool {
+        true
+    }
+
+    fn conforms_to_swift_comparison_protocol(
+        &self,
+        _protocol: ComparisonProtocol,
+        _types: &TypeDeclarations,
+    ) -> bool {
+        true
+    }
+
     fn contains_ref_string_recursive(&self) -> bool {
         false
     }
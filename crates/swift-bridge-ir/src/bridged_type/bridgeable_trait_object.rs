@@ -0,0 +1,595 @@
+use crate::bridged_type::{
+    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, TypeUsage, UnusedOptionNoneValue,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::fmt::{Debug, Formatter};
+use syn::{Path, Type};
+
+/// A `Box<dyn SomeProtocol>`, `&dyn SomeProtocol` or `&mut dyn SomeProtocol` where
+/// `SomeProtocol` is a `#[swift_bridge(protocol)]` type.
+///
+/// A Swift protocol type gets passed to Rust as a retained Swift object, which Rust wraps in a
+/// generated adapter struct that implements the trait by calling into Swift.
+///
+/// A Rust trait gets passed to Swift as a pointer to a `Box<dyn SomeProtocol>`, which Swift wraps
+/// in a generated class that conforms to the protocol by calling into Rust.
+#[derive(Clone)]
+pub(crate) struct BridgeableTraitObject {
+    pub ty: Ident,
+    pub host_lang: HostLang,
+    pub reference: bool,
+    pub mutable: bool,
+}
+
+impl BridgeableType for BridgeableTraitObject {
+    fn is_built_in_type(&self) -> bool {
+        false
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&super::bridgeable_result::BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
+    fn check_usage(&self, usage: TypeUsage, _types: &TypeDeclarations) -> Result<(), String> {
+        let position = match usage {
+            TypeUsage::VecElement(_) => {
+                return Err("Trait objects within a Vec<T> are not yet supported.".to_string())
+            }
+            TypeUsage::ResultInner(_) => {
+                return Err("Trait objects within a Result<T, E> are not yet supported.".to_string())
+            }
+            TypeUsage::Direct(position) | TypeUsage::OptionInner(position) => position,
+        };
+
+        let rust_to_swift = match position {
+            TypePosition::FnArg(func_host_lang, _) => func_host_lang.is_swift(),
+            TypePosition::FnReturn(func_host_lang) => func_host_lang.is_rust(),
+            TypePosition::SharedStructField => {
+                return Err("Trait object shared struct fields are not yet supported.".to_string())
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                return Err(
+                    "Returning a trait object from an async function is not yet supported."
+                        .to_string(),
+                )
+            }
+        };
+
+        if rust_to_swift && self.host_lang.is_swift() {
+            return Err(
+                "Passing a Box<dyn SomeProtocol> from Rust to Swift is not yet supported."
+                    .to_string(),
+            );
+        }
+        if rust_to_swift && self.reference {
+            return Err(
+                "Passing a &dyn SomeTrait from Rust to Swift is not yet supported.".to_string(),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.ty;
+
+        let trait_path = if self.host_lang.is_rust() {
+            quote! { super::#ty }
+        } else {
+            quote! { #ty }
+        };
+
+        if self.reference {
+            let maybe_mut = if self.mutable {
+                quote! { mut }
+            } else {
+                quote! {}
+            };
+            quote! { & #maybe_mut dyn #trait_path }
+        } else {
+            quote! { Box<dyn #trait_path> }
+        }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_name()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!("Trait object shared struct fields are rejected while parsing")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                unreachable!("Async functions that return trait objects are rejected while parsing")
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.host_lang.is_rust() {
+            let ty = &self.ty;
+            let ptr = if self.reference && !self.mutable {
+                quote! { *const }
+            } else {
+                quote! { *mut }
+            };
+            quote! { #ptr Box<dyn super::#ty> }
+        } else {
+            quote! { *mut std::ffi::c_void }
+        }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        if self.host_lang.is_swift() {
+            unreachable!(
+                "Passing a Box<dyn SomeProtocol> from Rust to Swift is rejected while parsing"
+            )
+        }
+        if self.reference {
+            unreachable!("Passing a &dyn SomeTrait from Rust to Swift is rejected while parsing")
+        }
+
+        quote! {
+            Box::into_raw(Box::new(#expression))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        if self.host_lang.is_swift() {
+            unreachable!(
+                "Passing a Box<dyn SomeProtocol> from Rust to Swift is rejected while parsing"
+            )
+        }
+        if self.reference {
+            unreachable!("Passing a &dyn SomeTrait from Rust to Swift is rejected while parsing")
+        }
+
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new(val))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        if self.host_lang.is_swift() {
+            format!(
+                "Unmanaged.passRetained({} as AnyObject).toOpaque()",
+                expression
+            )
+        } else if self.reference {
+            format!("{}.ptr", expression)
+        } else {
+            format!(
+                "{{ let val = {expression}; val.isOwned = false; return val.ptr }}()",
+                expression = expression
+            )
+        }
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        if self.host_lang.is_swift() {
+            format!(
+                "{{ if let val = {expression} {{ return Unmanaged.passRetained(val as AnyObject).toOpaque() }} else {{ return nil }} }}()",
+                expression = expression,
+            )
+        } else if self.reference {
+            format!(
+                "{{ if let val = {expression} {{ return val.ptr }} else {{ return nil }} }}()",
+                expression = expression,
+            )
+        } else {
+            format!(
+                "{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()",
+                expression = expression,
+            )
+        }
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+
+        if self.host_lang.is_swift() {
+            let adapter = self.swift_protocol_adapter_ident();
+
+            quote! {
+                Box::new(#adapter(#expression)) as Box<dyn #ty>
+            }
+        } else if self.reference {
+            let maybe_mut = if self.mutable {
+                quote! { mut }
+            } else {
+                quote! {}
+            };
+
+            quote! {
+                unsafe { & #maybe_mut ** #expression }
+            }
+        } else {
+            quote! {
+                unsafe { * Box::from_raw(#expression) }
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let ty = &self.ty;
+
+        let val = if self.host_lang.is_swift() {
+            let adapter = self.swift_protocol_adapter_ident();
+            quote! { Box::new(#adapter(val)) as Box<dyn #ty> }
+        } else if self.reference {
+            let maybe_mut = if self.mutable {
+                quote! { mut }
+            } else {
+                quote! {}
+            };
+            quote! { unsafe { & #maybe_mut ** val } }
+        } else {
+            quote! { unsafe { * Box::from_raw(val) } }
+        };
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(#val)
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        if self.host_lang.is_swift() {
+            unreachable!(
+                "Passing a Box<dyn SomeProtocol> from Rust to Swift is rejected while parsing"
+            )
+        }
+
+        format!(
+            "{class_name}(ptr: {value})",
+            class_name = self.swift_name(),
+            value = expression
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        if self.host_lang.is_swift() {
+            unreachable!(
+                "Passing a Box<dyn SomeProtocol> from Rust to Swift is rejected while parsing"
+            )
+        }
+
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return {class_name}(ptr: val!) }} else {{ return nil }} }}()",
+            expression = expression,
+            class_name = self.swift_name()
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<Box<dyn SomeProtocol>, E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, Box<dyn SomeProtocol>> is rejected while parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        let rust = if self.host_lang.is_rust() {
+            let ty = &self.ty;
+            quote! { std::ptr::null_mut::<Box<dyn super::#ty>>() }
+        } else {
+            quote! { std::ptr::null_mut::<std::ffi::c_void>() }
+        };
+
+        UnusedOptionNoneValue {
+            rust,
+            swift: "nil".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Box < dyn ")
+            || tokens.starts_with("& dyn ")
+            || tokens.starts_with("& mut dyn ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(_) | Type::Reference(_) => {
+                Self::parse_token_stream_str(&ty.to_token_stream().to_string(), types)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let (trait_name, reference, mutable) =
+            if let Some(boxed) = tokens.strip_prefix("Box < dyn ") {
+                (boxed.strip_suffix(" >")?, false, false)
+            } else if let Some(ref_mut) = tokens.strip_prefix("& mut dyn ") {
+                (ref_mut, true, true)
+            } else {
+                (tokens.strip_prefix("& dyn ")?, true, false)
+            };
+
+        let ty = types.get(trait_name)?.as_opaque()?;
+        if reference && ty.host_lang.is_swift() {
+            // TODO: Support passing a `&dyn SomeProtocol` from Swift to Rust.
+            return None;
+        }
+
+        ty.to_trait_object(reference, mutable)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        if self.reference {
+            format!("Ref_dyn_{}", self.ty)
+        } else {
+            format!("Box_dyn_{}", self.ty)
+        }
+    }
+}
+
+impl BridgeableTraitObject {
+    /// The Rust struct that implements a Swift protocol's trait by calling into a Swift object.
+    ///
+    /// __swift_bridge__SomeProtocol
+    pub fn swift_protocol_adapter_ident(&self) -> Ident {
+        Ident::new(
+            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.ty),
+            self.ty.span(),
+        )
+    }
+
+    /// The name of the Swift type that Swift code sees.
+    ///
+    /// A Swift protocol is seen as itself, while a Rust trait object is seen as the generated
+    /// `RustSomeTrait` class that conforms to the `SomeTrait` protocol.
+    pub fn swift_name(&self) -> String {
+        if self.host_lang.is_rust() {
+            rust_trait_object_class_name(&self.ty)
+        } else {
+            self.ty.to_string()
+        }
+    }
+}
+
+/// The Swift class that wraps a `Box<dyn SomeTrait>` and conforms to the `SomeTrait` protocol.
+///
+/// RustSomeTrait
+pub(crate) fn rust_trait_object_class_name(trait_name: &Ident) -> String {
+    format!("Rust{}", trait_name)
+}
+
+impl Debug for BridgeableTraitObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BridgeableTraitObject")
+            .field("ty", &self.ty.to_token_stream())
+            .field("host_lang", &self.host_lang)
+            .field("reference", &self.reference)
+            .field("mutable", &self.mutable)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseError;
+    use crate::test_utils::parse_errors;
+    use quote::quote;
+
+    /// Verify that we reject trait objects in positions that we do not yet support.
+    #[test]
+    fn error_if_unsupported_position() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Box<dyn Renderer>,
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(protocol)]
+                    type Renderer;
+
+                    fn render(&self);
+
+                    fn make_renderer() -> Box<dyn Renderer>;
+                    fn borrow_renderer(renderer: &dyn Renderer) -> &dyn Renderer;
+                    fn try_make_renderer() -> Result<Box<dyn Renderer>, u8>;
+                    async fn make_renderer_async() -> Box<dyn Renderer>;
+                    fn make_swift_renderer() -> Option<Box<dyn SwiftRenderer>>;
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type SwiftRenderer;
+
+                    fn draw(&self);
+
+                    fn take_swift_renderer(renderer: Box<dyn SwiftRenderer>);
+                    fn take_renderer(renderer: Box<dyn Renderer>) -> Box<dyn Renderer>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let messages: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedType { message, .. } => message.as_str(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Passing a &dyn SomeTrait from Rust to Swift is not yet supported.",
+                "Trait objects within a Result<T, E> are not yet supported.",
+                "Returning a trait object from an async function is not yet supported.",
+                "Passing a Box<dyn SomeProtocol> from Rust to Swift is not yet supported.",
+                "Passing a Box<dyn SomeProtocol> from Rust to Swift is not yet supported.",
+                "Trait object shared struct fields are not yet supported.",
+            ]
+        );
+    }
+}
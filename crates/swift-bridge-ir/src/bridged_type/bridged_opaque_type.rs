@@ -1,7 +1,8 @@
 use crate::bridged_type::{
-    BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+    BridgeableType, CFfiStruct, ComparisonProtocol, OnlyEncoding, TypePosition,
+    UnusedOptionNoneValue,
 };
-use crate::parse::{HostLang, OpaqueRustTypeGenerics};
+use crate::parse::{HostLang, OpaqueRustTypeGenerics, TypeDeclaration};
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
@@ -52,6 +53,14 @@ impl BridgeableType for OpaqueForeignType {
         None
     }
 
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
         let ty_name = &self.ty;
         let generics = self
@@ -205,6 +214,8 @@ impl BridgeableType for OpaqueForeignType {
         if self.has_swift_bridge_copy_annotation {
             let option_ty = self.option_copy_rust_repr_type();
             quote! { #option_ty }
+        } else if self.host_lang.is_swift() {
+            quote! { *mut std::ffi::c_void }
         } else {
             let generics = self
                 .generics
@@ -224,7 +235,11 @@ impl BridgeableType for OpaqueForeignType {
         _swift_bridge_path: &Path,
         _types: &TypeDeclarations,
     ) -> String {
-        todo!()
+        if self.has_swift_bridge_copy_annotation {
+            self.option_copy_ffi_repr_type_string()
+        } else {
+            "UnsafeMutableRawPointer?".to_string()
+        }
     }
 
     fn to_ffi_compatible_option_c_type(&self) -> String {
@@ -310,6 +325,17 @@ impl BridgeableType for OpaqueForeignType {
                     std::ptr::null()
                 }
             }
+        } else if self.host_lang.is_swift() {
+            // Swift holds a retained reference to the class, so we hand it over to Swift
+            // without running our `Drop` implementation, which would release it.
+            quote! {
+                if let Some(val) = #expression {
+                    let val = std::mem::ManuallyDrop::new(val);
+                    val.0
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
         } else {
             quote! {
                 if let Some(val) = #expression {
@@ -408,6 +434,11 @@ impl BridgeableType for OpaqueForeignType {
                         option_ffi_repr = option_ffi_repr,
                         ffi_repr = ffi_repr
                     )
+        } else if self.host_lang.is_swift() {
+            format!(
+                "{{ if let val = {expression} {{ return Unmanaged.passRetained(val).toOpaque() }} else {{ return nil }} }}()",
+                expression = expression,
+            )
         } else if self.reference {
             format!(
                 "{{ if let val = {expression} {{ return val.ptr }} else {{ return nil }} }}()",
@@ -461,29 +492,54 @@ impl BridgeableType for OpaqueForeignType {
         }
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         if self.has_swift_bridge_copy_annotation {
             quote! {
-                if #expression.is_some {
-                    Some(unsafe{ #expression.val.assume_init() }.into_rust_repr())
-                } else {
-                    None
+                {
+                    let val = #expression;
+                    if val.is_some {
+                        Some(unsafe{ val.val.assume_init() }.into_rust_repr())
+                    } else {
+                        None
+                    }
+                }
+            }
+        } else if self.host_lang.is_swift() {
+            let ty = &self.ty;
+            quote! {
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(#ty(val))
+                    }
                 }
             }
         } else if self.reference {
             quote! {
-                if #expression.is_null() {
-                    None
-                } else {
-                    Some(unsafe {& * #expression} )
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe {& * val} )
+                    }
                 }
             }
         } else {
             quote! {
-                if #expression.is_null() {
-                    None
-                } else {
-                    Some(unsafe { * Box::from_raw(#expression) } )
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { * Box::from_raw(val) } )
+                    }
                 }
             }
         }
@@ -543,6 +599,13 @@ impl BridgeableType for OpaqueForeignType {
                 expression = expression,
                 type_name = type_name
             )
+        } else if self.host_lang.is_swift() {
+            let type_name = self.swift_name();
+            format!(
+                "{{ let val = {expression}; if val != nil {{ return Unmanaged<{type_name}>.fromOpaque(val!).takeRetainedValue() }} else {{ return nil }} }}()",
+                expression = expression,
+                type_name = type_name
+            )
         } else {
             let type_name = self.swift_name();
             format!(
@@ -602,10 +665,15 @@ impl BridgeableType for OpaqueForeignType {
 
         if self.reference {
             todo!("Support returning Option<&T> where T is an opaque type")
+        } else if self.host_lang.is_swift() {
+            UnusedOptionNoneValue {
+                rust: quote! { std::ptr::null_mut::<std::ffi::c_void>() },
+                swift: "nil".into(),
+            }
         } else {
             UnusedOptionNoneValue {
                 rust: quote! { std::ptr::null::<#ty_name>() as *mut super::#ty_name },
-                swift: "TODO..Support Swift Option<T>::None value".into(),
+                swift: "nil".into(),
             }
         }
     }
@@ -673,6 +741,26 @@ impl BridgeableType for OpaqueForeignType {
         false
     }
 
+    fn conforms_to_swift_comparison_protocol(
+        &self,
+        protocol: ComparisonProtocol,
+        types: &TypeDeclarations,
+    ) -> bool {
+        // The `#[swift_bridge(Equatable)]` style attributes are only supported on Rust types.
+        if !self.host_lang.is_rust() {
+            return false;
+        }
+
+        match types.get(&self.ty.to_string()) {
+            Some(TypeDeclaration::Opaque(opaque)) => match protocol {
+                ComparisonProtocol::Equatable => opaque.attributes.equatable,
+                ComparisonProtocol::Hashable => opaque.attributes.hashable,
+                ComparisonProtocol::Comparable => opaque.attributes.comparable,
+            },
+            _ => false,
+        }
+    }
+
     fn has_swift_bridge_copy_annotation(&self) -> bool {
         self.has_swift_bridge_copy_annotation
     }
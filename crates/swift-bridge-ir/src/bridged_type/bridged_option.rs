@@ -29,6 +29,18 @@ impl BridgedOption {
                 }
             };
 
+        // For primitives whose FFI representation differs from their Rust representation.
+        let option_rust_primitive_to_ffi_repr =
+            move |ffi_option_name: TokenStream, val: TokenStream, unused_none: TokenStream| {
+                quote! {
+                    if let Some(val) = #expression {
+                        #swift_bridge_path::option::#ffi_option_name { val: #val, is_some: true }
+                    } else {
+                        #swift_bridge_path::option::#ffi_option_name { val: #unused_none, is_some: false }
+                    }
+                }
+            };
+
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => {
                 b.convert_option_rust_expression_to_ffi_type(expression, swift_bridge_path)
@@ -67,6 +79,16 @@ impl BridgedOption {
                 StdLibType::Isize => {
                     option_rust_primitive_to_ffi_primitive(quote! {OptionIsize}, quote! {123})
                 }
+                StdLibType::U128 => option_rust_primitive_to_ffi_repr(
+                    quote! {OptionU128},
+                    quote! { #swift_bridge_path::int128::RustU128::from_u128(val) },
+                    quote! { #swift_bridge_path::int128::RustU128 { low: 123, high: 0 } },
+                ),
+                StdLibType::I128 => option_rust_primitive_to_ffi_repr(
+                    quote! {OptionI128},
+                    quote! { #swift_bridge_path::int128::RustI128::from_i128(val) },
+                    quote! { #swift_bridge_path::int128::RustI128 { low: 123, high: 0 } },
+                ),
                 StdLibType::F32 => {
                     option_rust_primitive_to_ffi_primitive(quote! {OptionF32}, quote! {123.4})
                 }
@@ -76,6 +98,11 @@ impl BridgedOption {
                 StdLibType::Bool => {
                     option_rust_primitive_to_ffi_primitive(quote! {OptionBool}, quote! {false})
                 }
+                StdLibType::Char => option_rust_primitive_to_ffi_repr(
+                    quote! {OptionChar},
+                    quote! { val as u32 },
+                    quote! {123},
+                ),
                 StdLibType::Pointer(_) => {
                     todo!("Support Option<*const T> and Option<*mut T>")
                 }
@@ -106,7 +133,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Support Option<Result<T, E>>")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -129,9 +156,12 @@ impl BridgedOption {
     pub(super) fn convert_ffi_expression_to_rust_type(
         &self,
         expression: &TokenStream,
+        swift_bridge_path: &Path,
     ) -> TokenStream {
         match self.ty.deref() {
-            BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_rust_type(expression),
+            BridgedType::Bridgeable(b) => {
+                b.convert_ffi_option_expression_to_rust_type(expression, swift_bridge_path)
+            }
             BridgedType::StdLib(stdlib_ty) => match stdlib_ty {
                 StdLibType::Null => {
                     todo!("Option<()> is not yet supported")
@@ -160,6 +190,42 @@ impl BridgedOption {
                         }
                     }
                 }
+                StdLibType::U128 => {
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_some {
+                                Some(val.val.to_u128())
+                            } else {
+                                None
+                            }
+                        }
+                    }
+                }
+                StdLibType::I128 => {
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_some {
+                                Some(val.val.to_i128())
+                            } else {
+                                None
+                            }
+                        }
+                    }
+                }
+                StdLibType::Char => {
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_some {
+                                Some(char::from_u32(val.val).unwrap())
+                            } else {
+                                None
+                            }
+                        }
+                    }
+                }
                 StdLibType::Pointer(_) => {
                     todo!("Option<*const T> and Option<*mut T> are not yet supported.")
                 }
@@ -190,7 +256,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -225,9 +291,12 @@ impl BridgedOption {
                 | StdLibType::I64
                 | StdLibType::Usize
                 | StdLibType::Isize
+                | StdLibType::U128
+                | StdLibType::I128
                 | StdLibType::F32
                 | StdLibType::F64
-                | StdLibType::Bool => {
+                | StdLibType::Bool
+                | StdLibType::Char => {
                     format!("{expression}.intoSwiftRepr()")
                 }
                 StdLibType::Pointer(_) => {
@@ -255,7 +324,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -292,9 +361,12 @@ impl BridgedOption {
                 | StdLibType::I64
                 | StdLibType::Usize
                 | StdLibType::Isize
+                | StdLibType::U128
+                | StdLibType::I128
                 | StdLibType::F32
                 | StdLibType::F64
-                | StdLibType::Bool => {
+                | StdLibType::Bool
+                | StdLibType::Char => {
                     format!("{expression}.intoFfiRepr()")
                 }
                 StdLibType::Pointer(_) => {
@@ -311,11 +383,15 @@ impl BridgedOption {
                             todo!()
                         }
                     }
+                    // The `RustStr` that Swift returns points to memory that Rust owns, such as a
+                    // `&str` argument, so Rust can borrow it for as long as the arguments live.
                     TypePosition::FnReturn(host_lang) => {
                         if host_lang.is_rust() {
                             format!("{expression}AsRustStr", expression = expression)
                         } else {
-                            todo!()
+                            format!(
+                                "{{ if let val = {expression} {{ return val }} else {{ return RustStr(start: nil, len: 0) }} }}()"
+                            )
                         }
                     }
                     TypePosition::SharedStructField => {
@@ -337,7 +413,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -421,9 +497,12 @@ impl BridgedOption {
                 | StdLibType::I64
                 | StdLibType::Usize
                 | StdLibType::Isize
+                | StdLibType::U128
+                | StdLibType::I128
                 | StdLibType::F32
                 | StdLibType::F64
-                | StdLibType::Bool => BuiltInPrimitive::new_with_stdlib_type(stdlib_type)
+                | StdLibType::Bool
+                | StdLibType::Char => BuiltInPrimitive::new_with_stdlib_type(stdlib_type)
                     .unwrap()
                     .to_option_ffi_repr_name()
                     .to_string(),
@@ -437,7 +516,7 @@ impl BridgedOption {
                 StdLibType::Vec(_) => {
                     todo!()
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!()
                 }
                 StdLibType::Option(_) => {
@@ -450,8 +529,11 @@ impl BridgedOption {
                     todo!()
                 }
             },
-            BridgedType::Foreign(_) => {
-                todo!()
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.ffi_option_name_string()
+            }
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                shared_enum.ffi_option_name_string()
             }
             BridgedType::Bridgeable(bridgeable) => {
                 bridgeable.to_ffi_compatible_option_swift_type(type_pos, swift_bridge_path, types)
@@ -480,7 +562,10 @@ impl BridgedOption {
                 StdLibType::Isize => "struct __private__OptionIsize".to_string(),
                 StdLibType::F32 => "struct __private__OptionF32".to_string(),
                 StdLibType::F64 => "struct __private__OptionF64".to_string(),
+                StdLibType::U128 => "struct __private__OptionU128".to_string(),
+                StdLibType::I128 => "struct __private__OptionI128".to_string(),
                 StdLibType::Bool => "struct __private__OptionBool".to_string(),
+                StdLibType::Char => "struct __private__OptionChar".to_string(),
                 StdLibType::Pointer(_) => {
                     todo!("Option<*const T> and Option<*mut T> are not yet supported")
                 }
@@ -495,7 +580,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
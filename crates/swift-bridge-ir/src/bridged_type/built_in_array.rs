@@ -0,0 +1,542 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, CustomBridgedType, OnlyEncoding,
+    SharedType, StdLibType, StructSwiftRepr, TypePosition, TypeUsage, UnusedOptionNoneValue,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+use syn::{Expr, Lit, Path, Type};
+
+/// [T; N]
+///
+/// On the Swift side this becomes a `RustArray<T>`.
+///
+/// The array is passed by value, wrapped in a `#[repr(C)]` struct since C does not allow arrays
+/// to be passed to or returned from functions.
+pub(crate) struct BuiltInArray {
+    pub ty: Type,
+    pub len: usize,
+}
+
+impl BuiltInArray {
+    fn inner_ty(&self, types: &TypeDeclarations) -> BridgedType {
+        BridgedType::new_with_type(&self.ty, types).unwrap()
+    }
+
+    /// Primitives have the same representation in Rust, Swift and C, so their elements can be
+    /// used without any conversion.
+    fn inner_is_primitive(&self, types: &TypeDeclarations) -> bool {
+        match self.inner_ty(types) {
+            BridgedType::StdLib(ty) => is_primitive(&ty),
+            _ => false,
+        }
+    }
+
+    /// [u8; 32] -> __swift_bridge__Array_U8_32
+    fn ffi_name_ident(&self, types: &TypeDeclarations) -> Ident {
+        Ident::new(
+            &format!(
+                "{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                self.to_alpha_numeric_underscore_name(types)
+            ),
+            Span::call_site(),
+        )
+    }
+
+    /// [u8; 32] -> "__swift_bridge__$Array_U8_32"
+    fn ffi_name_string(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.to_alpha_numeric_underscore_name(types)
+        )
+    }
+
+    /// [u8; 32] -> "RustArray<UInt8>"
+    fn swift_struct_name(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        format!(
+            "RustArray<{}>",
+            self.inner_ty(types).to_swift_type(
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+                swift_bridge_path
+            )
+        )
+    }
+}
+
+impl BridgeableType for BuiltInArray {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ffi_name = self.ffi_name_ident(types);
+        let inner_ffi_ty = self
+            .inner_ty(types)
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let len = Literal::usize_unsuffixed(self.len);
+
+        Some(vec![quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #ffi_name([#inner_ffi_ty; #len]);
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ffi_name = self.ffi_name_string(types);
+        let inner = self.inner_ty(types).to_c(types);
+        let len = self.len;
+
+        Some(CFfiStruct {
+            c_ffi_type: format!(
+                "typedef struct {ffi_name} {{ {inner} elements[{len}]; }} {ffi_name};"
+            ),
+            fields: vec![],
+        })
+    }
+
+    fn generate_custom_swift_code(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> Option<String> {
+        let ffi_name = self.ffi_name_string(types);
+        let swift_struct_name = self.swift_struct_name(types, swift_bridge_path);
+        let inner = self.inner_ty(types);
+        let inner_ffi_ty = inner.to_swift_type(
+            TypePosition::FnArg(HostLang::Swift, 0),
+            types,
+            swift_bridge_path,
+        );
+        let len = self.len;
+
+        let convert_to_ffi = inner.convert_swift_expression_to_ffi_type(
+            "element",
+            types,
+            TypePosition::FnArg(HostLang::Rust, 0),
+        );
+        let convert_to_swift = inner.convert_ffi_expression_to_swift_type(
+            "$0",
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+
+        Some(format!(
+            r#"extension {ffi_name} {{
+    init(_ array: {swift_struct_name}) {{
+        precondition(array.count == {len}, "Expected an array of {len} elements")
+        self.init()
+        withUnsafeMutableBytes(of: &self.elements) {{ buffer in
+            let elements = buffer.bindMemory(to: {inner_ffi_ty}.self)
+            for (index, element) in array.enumerated() {{
+                elements[index] = {convert_to_ffi}
+            }}
+        }}
+    }}
+
+    func intoSwiftRepr() -> {swift_struct_name} {{
+        withUnsafeBytes(of: self.elements) {{ buffer in
+            RustArray(buffer.bindMemory(to: {inner_ffi_ty}.self).map {{ {convert_to_swift} }})
+        }}
+    }}
+}}"#
+        ))
+    }
+
+    fn check_usage(&self, usage: TypeUsage, _types: &TypeDeclarations) -> Result<(), String> {
+        // C does not allow zero length arrays, so we can't declare the array's FFI struct.
+        if self.len == 0 {
+            return Err("[T; 0] arrays are not supported.".to_string());
+        }
+
+        match usage {
+            TypeUsage::Direct(TypePosition::SharedStructField) => {
+                Err("[T; N] shared struct fields are not yet supported.".to_string())
+            }
+            TypeUsage::OptionInner(_) => Err("Option<[T; N]> is not yet supported.".to_string()),
+            TypeUsage::ResultInner(_) => {
+                Err("[T; N] within a Result<T, E> is not yet supported.".to_string())
+            }
+            TypeUsage::VecElement(_) => Err("Vec<[T; N]> is not yet supported.".to_string()),
+            TypeUsage::Direct(_) => Ok(()),
+        }
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.inner_ty(types).to_rust_type_path(types);
+        let len = Literal::usize_unsuffixed(self.len);
+        quote! { [#ty; #len] }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_struct_name(types, swift_bridge_path)
+                } else {
+                    self.ffi_name_string(types)
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!("[T; N] shared struct fields are rejected while parsing")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.ffi_name_string(types),
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        format!("struct {}", self.ffi_name_string(types))
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.inner_ty(types).to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_name_ident(types).to_token_stream()
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ffi_name = self.ffi_name_ident(types);
+
+        if self.inner_is_primitive(types) {
+            return quote! { #ffi_name(#expression) };
+        }
+
+        let convert_element = self.inner_ty(types).convert_rust_expression_to_ffi_type(
+            &quote! { val },
+            swift_bridge_path,
+            types,
+            span,
+        );
+        quote! {
+            { let val = #expression; #ffi_name(val.map(|val| #convert_element)) }
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}({})", self.ffi_name_string(types), expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.inner_is_primitive(types) {
+            return quote! { #expression.0 };
+        }
+
+        let convert_element = self.inner_ty(types).convert_ffi_expression_to_rust_type(
+            &quote! { val },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        quote! {
+            #expression.0.map(|val| #convert_element)
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        format!("{}.intoSwiftRepr()", expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<[T; N], E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, [T; N]> is rejected while parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("[")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let array = match ty {
+            Type::Array(array) => array,
+            _ => return None,
+        };
+
+        let len = match &array.len {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(int) => int.base10_parse::<usize>().ok()?,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        // Elements are copied into and out of the array's FFI representation, so we only
+        // support elements that can be passed by value.
+        match BridgedType::new_with_type(&array.elem, types)? {
+            BridgedType::StdLib(ty) if is_primitive(&ty) => {}
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct)))
+                if shared_struct.swift_repr == StructSwiftRepr::Structure => {}
+            _ => return None,
+        };
+
+        Some(BuiltInArray {
+            ty: *array.elem.clone(),
+            len,
+        })
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse2(TokenStream::from_str(tokens).ok()?).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "Array_{}_{}",
+            self.inner_ty(types).to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+}
+
+fn is_primitive(ty: &StdLibType) -> bool {
+    matches!(
+        ty,
+        StdLibType::U8
+            | StdLibType::I8
+            | StdLibType::U16
+            | StdLibType::I16
+            | StdLibType::U32
+            | StdLibType::I32
+            | StdLibType::U64
+            | StdLibType::I64
+            | StdLibType::Usize
+            | StdLibType::Isize
+            | StdLibType::F32
+            | StdLibType::F64
+            | StdLibType::Bool
+    )
+}
+
+impl Debug for BuiltInArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BuiltInArray")
+            .field("ty", &self.ty.to_token_stream())
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ParseError;
+    use crate::test_utils::parse_errors;
+
+    /// Verify that we can parse a `[T; N]` of primitives.
+    #[test]
+    fn parse_array() {
+        let types = TypeDeclarations::default();
+
+        for tokens in ["[u8 ; 32]", "[f32 ; 4]", "[bool ; 1]"] {
+            assert!(BuiltInArray::can_parse_token_stream_str(tokens));
+            assert!(BuiltInArray::parse_token_stream_str(tokens, &types).is_some());
+        }
+    }
+
+    /// Verify that we do not parse a `[T; N]` whose elements can't be passed by value, or whose
+    /// length is not an integer literal.
+    #[test]
+    fn does_not_parse_unsupported_array() {
+        let types = TypeDeclarations::default();
+
+        for tokens in ["[String ; 2]", "[Vec < u8 > ; 2]", "[u8 ; LEN]"] {
+            assert!(BuiltInArray::parse_token_stream_str(tokens, &types).is_none());
+        }
+    }
+
+    /// Verify that we reject arrays in positions that we do not yet support, as well as zero
+    /// length arrays.
+    #[test]
+    fn error_if_unsupported_position() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: [u8; 4],
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Option<[u8; 4]>);
+                    fn another_function() -> Result<[u8; 4], u8>;
+                    fn zero_length(arg: [u8; 0]);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let messages: Vec<&str> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedType { message, .. } => message.as_str(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Option<[T; N]> is not yet supported.",
+                "[T; N] within a Result<T, E> is not yet supported.",
+                "[T; 0] arrays are not supported.",
+                "[T; N] shared struct fields are not yet supported.",
+            ]
+        );
+    }
+}
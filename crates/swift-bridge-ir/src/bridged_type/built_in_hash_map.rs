@@ -0,0 +1,859 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, CustomBridgedType, OnlyEncoding,
+    SharedType, StdLibType, TypePosition, TypeUsage, UnusedOptionNoneValue,
+};
+use crate::codegen::GenericInstantiation;
+use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// HashMap<K, V>
+///
+/// On the Swift side this becomes a `RustHashMap<K, V>`.
+///
+/// Every `HashMap<K, V>` that appears in a bridge module gets its own set of `extern "C"`
+/// functions that Swift uses to create, query, mutate and iterate over the map.
+/// Their names include the module's [`TypeDeclarations::module_id`] so that more than one bridge
+/// module in a crate can use the same `HashMap<K, V>`.
+pub(crate) struct BuiltInHashMap {
+    pub key: Type,
+    pub value: Type,
+}
+
+impl BuiltInHashMap {
+    fn key_ty(&self, types: &TypeDeclarations) -> BridgedType {
+        BridgedType::new_with_type(&self.key, types).unwrap()
+    }
+
+    fn value_ty(&self, types: &TypeDeclarations) -> BridgedType {
+        BridgedType::new_with_type(&self.value, types).unwrap()
+    }
+
+    /// `Option<V>`, used for the values returned by `get`, `insert` and `remove`.
+    fn option_value_ty(&self, types: &TypeDeclarations) -> BridgedType {
+        let value = &self.value;
+        let option: Type = syn::parse2(quote! { Option<#value> }).unwrap();
+        BridgedType::new_with_type(&option, types).unwrap()
+    }
+
+    /// Lookups borrow the key instead of taking ownership of it, so `String` keys are passed to
+    /// Rust as a `RustStr`.
+    fn key_is_string(&self, types: &TypeDeclarations) -> bool {
+        self.key_ty(types).is_owned_string()
+    }
+
+    /// HashMap<String, u32> -> "__swift_bridge__$HashMap_String_U32$0123456789abcdef"
+    fn ffi_name_prefix(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}${}${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.to_alpha_numeric_underscore_name(types),
+            types.module_id()
+        )
+    }
+
+    /// Whether or not the value type implements `Clone`, which `get` and iterating over the map
+    /// rely on.
+    fn value_is_clone(&self, types: &TypeDeclarations) -> bool {
+        if self.value_ty(types).is_owned_string() {
+            return true;
+        }
+        if let Type::Path(_) = &self.value {
+            if let Some(TypeDeclaration::Opaque(opaque)) = types.get_with_type(&self.value) {
+                return opaque.host_lang.is_rust()
+                    && (opaque.attributes.copy.is_some() || opaque.attributes.clone);
+            }
+        }
+
+        match self.value_ty(types) {
+            BridgedType::StdLib(
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool
+                | StdLibType::Char,
+            ) => true,
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.derives.clone
+            }
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                !shared_enum.has_one_or_more_variants_with_data()
+            }
+            _ => false,
+        }
+    }
+
+    /// HashMap<String, u32> -> "RustHashMap<RustString, UInt32>"
+    fn swift_class_name(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        let pos = TypePosition::FnReturn(HostLang::Rust);
+        format!(
+            "RustHashMap<{}, {}>",
+            self.key_ty(types)
+                .to_swift_type(pos, types, swift_bridge_path),
+            self.value_ty(types)
+                .to_swift_type(pos, types, swift_bridge_path)
+        )
+    }
+
+    fn lookup_key_ffi_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.key_is_string(types) {
+            quote! { #swift_bridge_path::string::RustStr }
+        } else {
+            self.key_ty(types)
+                .to_ffi_compatible_rust_type(swift_bridge_path, types)
+        }
+    }
+
+    fn lookup_key_c_type(&self, types: &TypeDeclarations) -> String {
+        if self.key_is_string(types) {
+            "struct RustStr".to_string()
+        } else {
+            self.key_ty(types).to_c(types)
+        }
+    }
+}
+
+impl BridgeableType for BuiltInHashMap {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let map_ty = self.to_rust_type_path(types);
+        let key_ty = self.key_ty(types);
+        let value_ty = self.value_ty(types);
+        let option_value_ty = self.option_value_ty(types);
+
+        let key_rust_ty = key_ty.to_rust_type_path(types);
+        let value_rust_ty = value_ty.to_rust_type_path(types);
+
+        let key_ffi_ty = key_ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let value_ffi_ty = value_ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let option_value_ffi_ty =
+            option_value_ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let lookup_key_ffi_ty = self.lookup_key_ffi_rust_type(swift_bridge_path, types);
+
+        let lookup_key = if self.key_is_string(types) {
+            quote! { key.to_str() }
+        } else {
+            quote! { &key }
+        };
+        let convert_key = key_ty.convert_ffi_expression_to_rust_type(
+            &quote! { key },
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+        let convert_value = value_ty.convert_ffi_expression_to_rust_type(
+            &quote! { value },
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+        let return_option_value = option_value_ty.convert_rust_expression_to_ffi_type(
+            &quote! { val },
+            swift_bridge_path,
+            types,
+            Span::call_site(),
+        );
+        let return_key = key_ty.convert_rust_expression_to_ffi_type(
+            &quote! { key },
+            swift_bridge_path,
+            types,
+            Span::call_site(),
+        );
+        let return_value = value_ty.convert_rust_expression_to_ffi_type(
+            &quote! { value },
+            swift_bridge_path,
+            types,
+            Span::call_site(),
+        );
+
+        let iter_ty = quote! {
+            #swift_bridge_path::rust_hash_map::HashMapIter<#key_rust_ty, #value_rust_ty>
+        };
+
+        // examples:
+        // "__swift_bridge__$HashMap_String_U32$0123456789abcdef$new"
+        // "__swift_bridge__$HashMap_String_U32$0123456789abcdef$drop"
+        let prefix = self.ffi_name_prefix(types);
+        let make_export_name = |fn_name| format!("{}${}", prefix, fn_name);
+        let export_name_new = make_export_name("new");
+        let export_name_drop = make_export_name("drop");
+        let export_name_len = make_export_name("len");
+        let export_name_get = make_export_name("get");
+        let export_name_insert = make_export_name("insert");
+        let export_name_remove = make_export_name("remove");
+        let export_name_iter = make_export_name("iter");
+        let export_name_iter_next = make_export_name("iter_next");
+        let export_name_iter_key = make_export_name("iter_key");
+        let export_name_iter_value = make_export_name("iter_value");
+        let export_name_iter_drop = make_export_name("iter_drop");
+
+        Some(vec![quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[export_name = #export_name_new]
+                pub extern "C" fn _new() -> *mut #map_ty {
+                    Box::into_raw(Box::new(std::collections::HashMap::new()))
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_drop]
+                pub extern "C" fn _drop(map: *mut #map_ty) {
+                    let map = unsafe { Box::from_raw(map) };
+                    drop(map)
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_len]
+                pub extern "C" fn _len(map: *const #map_ty) -> usize {
+                    unsafe { &*map }.len()
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_get]
+                pub extern "C" fn _get(map: *const #map_ty, key: #lookup_key_ffi_ty) -> #option_value_ffi_ty {
+                    let map = unsafe { &*map };
+                    let val = map.get(#lookup_key).cloned();
+                    #return_option_value
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_insert]
+                pub extern "C" fn _insert(map: *mut #map_ty, key: #key_ffi_ty, value: #value_ffi_ty) -> #option_value_ffi_ty {
+                    let map = unsafe { &mut *map };
+                    let val = map.insert(#convert_key, #convert_value);
+                    #return_option_value
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_remove]
+                pub extern "C" fn _remove(map: *mut #map_ty, key: #lookup_key_ffi_ty) -> #option_value_ffi_ty {
+                    let map = unsafe { &mut *map };
+                    let val = map.remove(#lookup_key);
+                    #return_option_value
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_iter]
+                pub extern "C" fn _iter(map: *const #map_ty) -> *mut #iter_ty {
+                    Box::into_raw(Box::new(#swift_bridge_path::rust_hash_map::HashMapIter::new(unsafe { &*map })))
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_iter_next]
+                pub extern "C" fn _iter_next(iter: *mut #iter_ty) -> bool {
+                    unsafe { &mut *iter }.advance()
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_iter_key]
+                pub extern "C" fn _iter_key(iter: *mut #iter_ty) -> #key_ffi_ty {
+                    let key = unsafe { &mut *iter }.take_key();
+                    #return_key
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_iter_value]
+                pub extern "C" fn _iter_value(iter: *mut #iter_ty) -> #value_ffi_ty {
+                    let value = unsafe { &mut *iter }.take_value();
+                    #return_value
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_iter_drop]
+                pub extern "C" fn _iter_drop(iter: *mut #iter_ty) {
+                    let iter = unsafe { Box::from_raw(iter) };
+                    drop(iter)
+                }
+            };
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
+    fn generate_generic_instantiation(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> Option<GenericInstantiation> {
+        let prefix = self.ffi_name_prefix(types);
+        let key_ty = self.key_ty(types);
+        let value_ty = self.value_ty(types);
+        let option_value_ty = self.option_value_ty(types);
+
+        let ret = TypePosition::FnReturn(HostLang::Rust);
+        let key_swift_ty = key_ty.to_swift_type(ret, types, swift_bridge_path);
+        let value_swift_ty = value_ty.to_swift_type(ret, types, swift_bridge_path);
+
+        let lookup_key = if self.key_is_string(types) {
+            "key.as_str()".to_string()
+        } else {
+            "key".to_string()
+        };
+        let convert_key = key_ty.convert_swift_expression_to_ffi_type(
+            "key",
+            types,
+            TypePosition::FnArg(HostLang::Rust, 1),
+        );
+        let convert_value = value_ty.convert_swift_expression_to_ffi_type(
+            "value",
+            types,
+            TypePosition::FnArg(HostLang::Rust, 2),
+        );
+        let convert_option_value = |expression: &str| {
+            option_value_ty.convert_ffi_expression_to_swift_type(
+                expression,
+                ret,
+                types,
+                swift_bridge_path,
+            )
+        };
+        let get = convert_option_value(&format!("{prefix}$get(ptr, {lookup_key})"));
+        let insert = convert_option_value(&format!(
+            "{prefix}$insert(ptr, {convert_key}, {convert_value})"
+        ));
+        let remove = convert_option_value(&format!("{prefix}$remove(ptr, {lookup_key})"));
+        let iter_key = key_ty.convert_ffi_expression_to_swift_type(
+            &format!("{prefix}$iter_key(iter)"),
+            ret,
+            types,
+            swift_bridge_path,
+        );
+        let iter_value = value_ty.convert_ffi_expression_to_swift_type(
+            &format!("{prefix}$iter_value(iter)"),
+            ret,
+            types,
+            swift_bridge_path,
+        );
+
+        let swift = format!(
+            r#"extension RustHashMap where K == {key_swift_ty}, V == {value_swift_ty} {{
+    public convenience init() {{
+        self.init(ptr: {prefix}$new())
+    }}
+
+    convenience init(ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr, vtable: __private__RustHashMapVTable(
+            drop: {{ ptr in {prefix}$drop(ptr) }},
+            len: {{ ptr in {prefix}$len(ptr) }},
+            get: {{ ptr, key in {get} }},
+            insert: {{ ptr, key, value in {insert} }},
+            remove: {{ ptr, key in {remove} }},
+            iter: {{ ptr in {prefix}$iter(ptr) }},
+            iterNext: {{ iter in {prefix}$iter_next(iter) }},
+            iterKey: {{ iter in {iter_key} }},
+            iterValue: {{ iter in {iter_value} }},
+            iterDrop: {{ iter in {prefix}$iter_drop(iter) }}
+        ))
+    }}
+}}"#
+        );
+
+        let key_c = key_ty.to_c(types);
+        let value_c = value_ty.to_c(types);
+        let option_value_c = option_value_ty.to_c(types);
+        let lookup_key_c = self.lookup_key_c_type(types);
+        let c_header = format!(
+            r#"void* {prefix}$new(void);
+void {prefix}$drop(void* map);
+uintptr_t {prefix}$len(void* map);
+{option_value_c} {prefix}$get(void* map, {lookup_key_c} key);
+{option_value_c} {prefix}$insert(void* map, {key_c} key, {value_c} value);
+{option_value_c} {prefix}$remove(void* map, {lookup_key_c} key);
+void* {prefix}$iter(void* map);
+bool {prefix}$iter_next(void* iter);
+{key_c} {prefix}$iter_key(void* iter);
+{value_c} {prefix}$iter_value(void* iter);
+void {prefix}$iter_drop(void* iter);"#
+        );
+
+        Some(GenericInstantiation {
+            name: self.to_alpha_numeric_underscore_name(types),
+            swift,
+            c_header,
+        })
+    }
+
+    fn check_usage(&self, usage: TypeUsage, types: &TypeDeclarations) -> Result<(), String> {
+        match usage {
+            TypeUsage::Direct(TypePosition::SharedStructField) => {
+                return Err("HashMap<K, V> shared struct fields are not yet supported.".to_string());
+            }
+            TypeUsage::OptionInner(_) => {
+                return Err("Option<HashMap<K, V>> is not yet supported.".to_string());
+            }
+            TypeUsage::ResultInner(_) => {
+                return Err(
+                    "Result<HashMap<K, V>, E> and Result<T, HashMap<K, V>> are not yet supported."
+                        .to_string(),
+                );
+            }
+            TypeUsage::VecElement(_) => {
+                return Err("Vec<HashMap<K, V>> is not yet supported.".to_string());
+            }
+            TypeUsage::Direct(_) => {}
+        };
+
+        if !self.value_is_clone(types) {
+            return Err(format!(
+                "HashMap<K, V> values must implement Clone. `{}` does not.",
+                self.value.to_token_stream()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let key = self.key_ty(types).to_rust_type_path(types);
+        let value = self.value_ty(types).to_rust_type_path(types);
+        quote! { std::collections::HashMap<#key, #value> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class_name(types, swift_bridge_path)
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!("HashMap<K, V> shared struct fields are rejected while parsing")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        let mut includes = vec!["stdint.h", "stdbool.h"];
+        for ty in [self.key_ty(types), self.value_ty(types)] {
+            if let Some(inner) = ty.to_c_include(types) {
+                for include in inner {
+                    if !includes.contains(&include) {
+                        includes.push(include);
+                    }
+                }
+            }
+        }
+        Some(includes)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! {
+            Box::into_raw(Box::new(#expression))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{{ let val = {expression}; val.isOwned = false; return val.ptr }}()")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! {
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let class_name = self.swift_class_name(types, swift_bridge_path);
+
+        match type_pos {
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{class_name}(ptr: {expression}!)")
+            }
+            _ => format!("{class_name}(ptr: {expression})"),
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { *Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { *Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Option<HashMap<K, V>> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("HashMap < ") || tokens.starts_with("std :: collections :: HashMap < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse2(TokenStream::from_str(tokens).ok()?).ok()?;
+        let path = match ty {
+            Type::Path(path) => path,
+            _ => return None,
+        };
+
+        let last = path.path.segments.last()?;
+        if last.ident != "HashMap" {
+            return None;
+        }
+
+        let mut generics = match &last.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            }),
+            _ => return None,
+        };
+        let key = generics.next()?;
+        let value = generics.next()?;
+        if generics.next().is_some() {
+            return None;
+        }
+
+        // Keys need to be hashable on the Rust side and cheap to hand back to Swift, so we
+        // only support integers, bools and Strings.
+        match BridgedType::new_with_type(&key, types)? {
+            BridgedType::StdLib(
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::Bool,
+            ) => {}
+            BridgedType::Bridgeable(_) if key.to_token_stream().to_string() == "String" => {}
+            _ => return None,
+        };
+        BridgedType::new_with_type(&value, types)?;
+
+        Some(BuiltInHashMap { key, value })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "HashMap_{}_{}",
+            self.key_ty(types).to_alpha_numeric_underscore_name(types),
+            self.value_ty(types).to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
+
+impl Debug for BuiltInHashMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BuiltInHashMap")
+            .field("key", &self.key.to_token_stream())
+            .field("value", &self.value.to_token_stream())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
+
+    /// Verify that we can parse a `HashMap<K, V>` with or without its full path.
+    #[test]
+    fn parse_hash_map() {
+        let types = TypeDeclarations::default();
+
+        for tokens in [
+            "HashMap < String , u32 >",
+            "std :: collections :: HashMap < u8 , bool >",
+        ] {
+            assert!(BuiltInHashMap::can_parse_token_stream_str(tokens));
+            assert!(BuiltInHashMap::parse_token_stream_str(tokens, &types).is_some());
+        }
+    }
+
+    /// Verify that we do not parse a `HashMap<K, V>` whose key type we do not support.
+    #[test]
+    fn does_not_parse_unsupported_key() {
+        let types = TypeDeclarations::default();
+
+        for tokens in ["HashMap < f32 , u32 >", "HashMap < Vec < u8 > , u32 >"] {
+            assert!(BuiltInHashMap::parse_token_stream_str(tokens, &types).is_none());
+        }
+    }
+
+    /// Verify that we get an error when a `HashMap<K, V>` is used in a position that we do not yet
+    /// support.
+    #[test]
+    fn error_if_unsupported_position() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: HashMap<String, u32>,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Option<HashMap<String, u32>>);
+                    fn another_function() -> Result<HashMap<String, u32>, String>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            match error {
+                ParseError::UnsupportedType { .. } => {}
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we get an error if a `HashMap<K, V>`'s value type does not implement `Clone`.
+    #[test]
+    fn error_if_value_is_not_clone() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function() -> HashMap<String, SomeType>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::UnsupportedType { ty, message } => {
+                assert_eq!(
+                    ty.to_token_stream().to_string(),
+                    "HashMap < String , SomeType >"
+                );
+                assert_eq!(
+                    message,
+                    "HashMap<K, V> values must implement Clone. `SomeType` does not."
+                );
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can use an opaque Rust type that implements `Clone` as a `HashMap<K, V>`
+    /// value.
+    #[test]
+    fn clone_opaque_value() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type SomeType;
+                    fn some_function() -> HashMap<String, SomeType>;
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
+}
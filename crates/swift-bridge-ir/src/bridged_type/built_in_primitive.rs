@@ -13,9 +13,12 @@ pub(crate) enum BuiltInPrimitive {
     I64,
     Usize,
     Isize,
+    U128,
+    I128,
     F32,
     F64,
     Bool,
+    Char,
 }
 
 impl BuiltInPrimitive {
@@ -33,9 +36,12 @@ impl BuiltInPrimitive {
             BuiltInPrimitive::I64 => "__private__OptionI64",
             BuiltInPrimitive::Usize => "__private__OptionUsize",
             BuiltInPrimitive::Isize => "__private__OptionIsize",
+            BuiltInPrimitive::U128 => "__private__OptionU128",
+            BuiltInPrimitive::I128 => "__private__OptionI128",
             BuiltInPrimitive::F32 => "__private__OptionF32",
             BuiltInPrimitive::F64 => "__private__OptionF64",
             BuiltInPrimitive::Bool => "__private__OptionBool",
+            BuiltInPrimitive::Char => "__private__OptionChar",
         }
     }
 
@@ -54,9 +60,12 @@ impl BuiltInPrimitive {
             StdLibType::I64 => Self::I64,
             StdLibType::Usize => Self::Usize,
             StdLibType::Isize => Self::Isize,
+            StdLibType::U128 => Self::U128,
+            StdLibType::I128 => Self::I128,
             StdLibType::F32 => Self::F32,
             StdLibType::F64 => Self::F64,
             StdLibType::Bool => Self::Bool,
+            StdLibType::Char => Self::Char,
             _ => None?,
         };
         Some(ty)
@@ -0,0 +1,516 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    TypeUsage, UnusedOptionNoneValue,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// HashSet<T> or BTreeSet<T>
+///
+/// On the Swift side both become a `RustSet<T>`.
+///
+/// The `extern "C"` functions that Swift uses to work with the set live in the `swift-bridge`
+/// crate, so we only support the element types that it has functions for.
+pub(crate) struct BuiltInSet {
+    pub kind: SetKind,
+    pub ty: Type,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum SetKind {
+    HashSet,
+    BTreeSet,
+}
+
+impl SetKind {
+    fn rust_name(&self) -> &'static str {
+        match self {
+            SetKind::HashSet => "HashSet",
+            SetKind::BTreeSet => "BTreeSet",
+        }
+    }
+
+    /// The `RustSetKind` case on the Swift side.
+    fn swift_kind(&self) -> &'static str {
+        match self {
+            SetKind::HashSet => ".hashSet",
+            SetKind::BTreeSet => ".btreeSet",
+        }
+    }
+}
+
+impl BuiltInSet {
+    fn inner_ty(&self, types: &TypeDeclarations) -> BridgedType {
+        BridgedType::new_with_type(&self.ty, types).unwrap()
+    }
+
+    /// HashSet<u32> -> "RustSet<UInt32>"
+    fn swift_class_name(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        format!(
+            "RustSet<{}>",
+            self.inner_ty(types).to_swift_type(
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+                swift_bridge_path
+            )
+        )
+    }
+}
+
+impl BridgeableType for BuiltInSet {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
+    fn check_usage(&self, usage: TypeUsage, _types: &TypeDeclarations) -> Result<(), String> {
+        match usage {
+            TypeUsage::Direct(TypePosition::SharedStructField) => Err(format!(
+                "{}<T> shared struct fields are not yet supported.",
+                self.kind.rust_name()
+            )),
+            TypeUsage::OptionInner(_) => Err(format!(
+                "Option<{}<T>> is not yet supported.",
+                self.kind.rust_name()
+            )),
+            TypeUsage::VecElement(_) => Err(format!(
+                "Vec<{}<T>> is not yet supported.",
+                self.kind.rust_name()
+            )),
+            TypeUsage::Direct(_) | TypeUsage::ResultInner(_) => Ok(()),
+        }
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let set = syn::Ident::new(self.kind.rust_name(), Span::call_site());
+        let ty = self.inner_ty(types).to_rust_type_path(types);
+        quote! { std::collections::#set<#ty> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class_name(types, swift_bridge_path)
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                unreachable!(
+                    "{} shared struct fields are rejected while parsing",
+                    self.kind.rust_name()
+                )
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! {
+            Box::into_raw(Box::new(#expression))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {expression}.intoRustSetKind({}); val.isOwned = false; return val.ptr }}()",
+            self.kind.swift_kind()
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! {
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let class_name = self.swift_class_name(types, swift_bridge_path);
+        let kind = self.kind.swift_kind();
+
+        match type_pos {
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{class_name}(ptr: {expression}!, kind: {kind})")
+            }
+            _ => format!("{class_name}(ptr: {expression}, kind: {kind})"),
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { *Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { *Box::from_raw(#result.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!(
+            "Option<{}<T>> is rejected while parsing",
+            self.kind.rust_name()
+        )
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        ["HashSet", "BTreeSet"].iter().any(|set| {
+            tokens.starts_with(&format!("{} < ", set))
+                || tokens.starts_with(&format!("std :: collections :: {} < ", set))
+        })
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse2(TokenStream::from_str(tokens).ok()?).ok()?;
+        let path = match ty {
+            Type::Path(path) => path,
+            _ => return None,
+        };
+
+        let last = path.path.segments.last()?;
+        let kind = if last.ident == "HashSet" {
+            SetKind::HashSet
+        } else if last.ident == "BTreeSet" {
+            SetKind::BTreeSet
+        } else {
+            return None;
+        };
+
+        let mut generics = match &last.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            }),
+            _ => return None,
+        };
+        let ty = generics.next()?;
+        if generics.next().is_some() {
+            return None;
+        }
+
+        // The `swift-bridge` crate only exposes sets of integers, bools and Strings.
+        match BridgedType::new_with_type(&ty, types)? {
+            BridgedType::StdLib(
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::Bool,
+            ) => {}
+            BridgedType::Bridgeable(_) if ty.to_token_stream().to_string() == "String" => {}
+            _ => return None,
+        };
+
+        Some(BuiltInSet { kind, ty })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}_{}",
+            self.kind.rust_name(),
+            self.inner_ty(types).to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
+
+impl Debug for BuiltInSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BuiltInSet")
+            .field("kind", &self.kind)
+            .field("ty", &self.ty.to_token_stream())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ParseError;
+    use crate::test_utils::parse_errors;
+
+    /// Verify that we can parse a `HashSet<T>` and a `BTreeSet<T>` with or without their full
+    /// path.
+    #[test]
+    fn parse_sets() {
+        let types = TypeDeclarations::default();
+
+        for (tokens, kind) in [
+            ("HashSet < String >", SetKind::HashSet),
+            ("std :: collections :: HashSet < u32 >", SetKind::HashSet),
+            ("BTreeSet < i64 >", SetKind::BTreeSet),
+            ("std :: collections :: BTreeSet < bool >", SetKind::BTreeSet),
+        ] {
+            assert!(BuiltInSet::can_parse_token_stream_str(tokens));
+            assert_eq!(
+                BuiltInSet::parse_token_stream_str(tokens, &types)
+                    .unwrap()
+                    .kind,
+                kind
+            );
+        }
+    }
+
+    /// Verify that we do not parse a set whose element type the `swift-bridge` crate does not
+    /// have functions for.
+    #[test]
+    fn does_not_parse_unsupported_element() {
+        let types = TypeDeclarations::default();
+
+        for tokens in ["HashSet < f32 >", "BTreeSet < Vec < u8 > >"] {
+            assert!(BuiltInSet::parse_token_stream_str(tokens, &types).is_none());
+        }
+    }
+
+    /// Verify that we get an error when a set is used in a position that we do not yet support.
+    #[test]
+    fn error_if_unsupported_position() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: HashSet<u32>,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Option<BTreeSet<u32>>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        match &errors[0] {
+            ParseError::UnsupportedType { message, .. } => {
+                assert_eq!(message, "Option<BTreeSet<T>> is not yet supported.");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::UnsupportedType { message, .. } => {
+                assert_eq!(
+                    message,
+                    "HashSet<T> shared struct fields are not yet supported."
+                );
+            }
+            _ => panic!(),
+        };
+    }
+}
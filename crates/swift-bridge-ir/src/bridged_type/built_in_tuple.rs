@@ -97,6 +97,14 @@ impl BridgeableType for BuiltInTuple {
         })
     }
 
+    fn generate_custom_swift_code(
+        &self,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> Option<String> {
+        None
+    }
+
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
         let rust_type_path = self.0.to_rust_type_path_tokens(types);
         quote! {
@@ -255,7 +263,11 @@ impl BridgeableType for BuiltInTuple {
         };
     }
 
-    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+    fn convert_ffi_option_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
         todo!()
     }
 
@@ -339,6 +351,10 @@ impl BridgeableType for BuiltInTuple {
         self.0.contains_owned_string_recursive(types)
     }
 
+    fn contains_vec_of_recursive(&self, ty_name: &str, types: &TypeDeclarations) -> bool {
+        self.0.contains_vec_of_recursive(ty_name, types)
+    }
+
     fn contains_ref_string_recursive(&self) -> bool {
         todo!()
     }
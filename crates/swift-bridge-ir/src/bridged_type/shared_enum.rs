@@ -12,6 +12,34 @@ use super::StructFields;
 #[derive(Default, Clone)]
 pub(crate) struct DeriveAttrs {
     pub debug: bool,
+    pub partial_eq: bool,
+    pub eq: bool,
+    pub hash: bool,
+    pub partial_ord: bool,
+    pub ord: bool,
+}
+
+impl DeriveAttrs {
+    /// The `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` derives.
+    pub(crate) fn comparison_derives(&self) -> Vec<TokenStream> {
+        let mut derives = vec![];
+        if self.partial_eq {
+            derives.push(quote! {PartialEq});
+        }
+        if self.eq {
+            derives.push(quote! {Eq});
+        }
+        if self.hash {
+            derives.push(quote! {Hash});
+        }
+        if self.partial_ord {
+            derives.push(quote! {PartialOrd});
+        }
+        if self.ord {
+            derives.push(quote! {Ord});
+        }
+        derives
+    }
 }
 
 #[derive(Clone)]
@@ -21,6 +49,10 @@ pub(crate) struct SharedEnum {
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
     pub derive: DeriveAttrs,
+    /// `#[swift_bridge(Codable)]`
+    pub codable: bool,
+    /// `#[swift_bridge(serde)]`
+    pub serde: bool,
 }
 
 impl SharedEnum {
@@ -1,12 +1,13 @@
+pub(crate) use self::struct_field::type_is_ident;
 pub(crate) use self::struct_field::StructField;
 pub(crate) use self::struct_field::StructFields;
 use self::struct_field::UnnamedStructField;
-use crate::bridged_type::{BridgedType, OnlyEncoding, TypePosition};
+use crate::bridged_type::{BridgedType, OnlyEncoding, TypePosition, TypeUsage};
 use crate::parse::TypeDeclarations;
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use quote::{format_ident, quote_spanned};
+use quote::quote_spanned;
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
 use syn::spanned::Spanned;
@@ -204,6 +205,13 @@ impl UnnamedStructFields {
             })
             .any(|ty| ty.contains_owned_string_recursive(types))
     }
+    pub fn contains_vec_of_recursive(&self, ty_name: &str, types: &TypeDeclarations) -> bool {
+        self.0.iter().any(|field| {
+            BridgedType::new_with_type(&field.ty, types)
+                .map(|ty| ty.contains_vec_of_recursive(ty_name, types))
+                .unwrap_or(false)
+        })
+    }
     pub fn to_rust_type_path_tokens(&self, types: &TypeDeclarations) -> Vec<TokenStream> {
         self.0
             .iter()
@@ -257,15 +265,110 @@ pub(crate) struct SharedStruct {
     pub swift_name: Option<LitStr>,
     pub already_declared: bool,
     pub derives: StructDerives,
+    pub generics: SharedStructGenerics,
+    /// `#[swift_bridge(Codable)]`
+    pub codable: bool,
+    /// `#[swift_bridge(serde)]`
+    pub serde: bool,
+}
+
+/// The generics of a shared struct.
+///
+/// `struct Point<T> { x: T, y: T }` declares a generic struct with the parameter `T`.
+/// `struct Point<f32>;` declares the concrete `Point<f32>` struct that can be passed over FFI.
+#[derive(Clone, Default, PartialEq)]
+pub(crate) struct SharedStructGenerics {
+    /// The generic parameters of the declaration. `[T]` for `struct Point<T> { .. }`.
+    pub params: Vec<Ident>,
+    /// The concrete types of an instantiation. `[f32]` for `struct Point<f32>;`.
+    /// Empty if this is the generic declaration itself.
+    pub concrete: Vec<Ident>,
+}
+
+impl SharedStructGenerics {
+    /// Whether or not this is a generic struct declaration such as `struct Point<T> { .. }`.
+    /// We don't generate FFI code for the declaration, only for its instantiations.
+    pub fn is_generic_declaration(&self) -> bool {
+        !self.params.is_empty() && self.concrete.is_empty()
+    }
+
+    /// "f32u8" for `Point<f32, u8>`
+    pub fn concrete_string(&self) -> String {
+        self.concrete.iter().map(|c| c.to_string()).collect()
+    }
+
+    /// "_f32_u8" for `Point<f32, u8>`
+    fn underscore_prefixed_concrete_string(&self) -> String {
+        self.concrete.iter().map(|c| format!("_{}", c)).collect()
+    }
+
+    /// "$f32$u8" for `Point<f32, u8>`
+    fn dollar_prefixed_concrete_string(&self) -> String {
+        self.concrete.iter().map(|c| format!("${}", c)).collect()
+    }
 }
 
 #[derive(Clone)]
 pub(crate) struct StructDerives {
     pub copy: bool,
     pub clone: bool,
+    pub partial_eq: bool,
+    pub eq: bool,
+    pub hash: bool,
+    pub partial_ord: bool,
+    pub ord: bool,
+}
+
+impl StructDerives {
+    /// The `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` derives.
+    pub(crate) fn comparison_derives(&self) -> Vec<TokenStream> {
+        let mut derives = vec![];
+        if self.partial_eq {
+            derives.push(quote! {PartialEq});
+        }
+        if self.eq {
+            derives.push(quote! {Eq});
+        }
+        if self.hash {
+            derives.push(quote! {Hash});
+        }
+        if self.partial_ord {
+            derives.push(quote! {PartialOrd});
+        }
+        if self.ord {
+            derives.push(quote! {Ord});
+        }
+        derives
+    }
 }
 
 impl SharedStruct {
+    /// Check that the struct can be used at the given position.
+    ///
+    /// Swift reads a copy of each element of a `Vec<T>`, so we only generate `Vec<T>` support for
+    /// structs that derive `Clone`.
+    /// An `already_declared` struct's derives live on its original declaration, so we trust it.
+    pub(crate) fn check_usage(&self, usage: TypeUsage) -> Result<(), String> {
+        if let TypeUsage::VecElement(_) = usage {
+            // Swift's single generic struct can't conform to `Vectorizable` once per instantiation.
+            if !self.generics.concrete.is_empty() {
+                return Err(format!(
+                    "Vec<{}> is not yet supported.",
+                    self.type_declaration_key()
+                ));
+            }
+
+            if !self.already_declared && !self.derives.clone {
+                return Err(format!(
+                    "Vec<{name}> requires `{name}` to #[derive(Clone)].",
+                    name = self.name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn swift_name_string(&self) -> String {
         match self.swift_name.as_ref() {
             Some(ty) => ty.value(),
@@ -273,15 +376,110 @@ impl SharedStruct {
         }
     }
 
+    /// The key that the struct is stored under in the module's type declarations.
+    ///
+    /// "SomeStruct", "Point<T>" for `struct Point<T> { .. }` or "Point<f32>" for
+    /// `struct Point<f32>;`
+    pub(crate) fn type_declaration_key(&self) -> String {
+        let generics = if self.generics.concrete.is_empty() {
+            &self.generics.params
+        } else {
+            &self.generics.concrete
+        };
+        if generics.is_empty() {
+            return self.name.to_string();
+        }
+
+        let generics: Vec<String> = generics.iter().map(|g| g.to_string()).collect();
+        format!("{}<{}>", self.name, generics.join(","))
+    }
+
+    /// Create the concrete `struct Point<f32>;` from the generic `struct Point<T> { .. }`.
+    pub(crate) fn instantiate(&self, concrete: Vec<Ident>, already_declared: bool) -> Self {
+        SharedStruct {
+            fields: self
+                .fields
+                .with_concrete_generics(&self.generics.params, &concrete),
+            already_declared: self.already_declared || already_declared,
+            generics: SharedStructGenerics {
+                params: self.generics.params.clone(),
+                concrete,
+            },
+            ..self.clone()
+        }
+    }
+
+    /// The Swift type of the struct.
+    ///
+    /// "SomeStruct", or "Point<Float>" for `struct Point<f32>;`
+    pub(crate) fn swift_type_string(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let name = self.swift_name_string();
+        if self.generics.concrete.is_empty() {
+            return name;
+        }
+
+        let concrete: Vec<String> = self
+            .generics
+            .concrete
+            .iter()
+            .map(|c| {
+                BridgedType::new_with_str(&c.to_string(), types)
+                    .unwrap()
+                    .to_swift_type(TypePosition::SharedStructField, types, swift_bridge_path)
+            })
+            .collect();
+
+        format!("{}<{}>", name, concrete.join(", "))
+    }
+
+    /// The Rust type of the struct.
+    ///
+    /// `SomeStruct`, or `Point<f32>` for `struct Point<f32>;`
+    pub(crate) fn rust_type_tokens(&self, types: &TypeDeclarations) -> TokenStream {
+        let name = &self.name;
+        if self.generics.concrete.is_empty() {
+            return quote! { #name };
+        }
+
+        let concrete: Vec<TokenStream> = self
+            .generics
+            .concrete
+            .iter()
+            .map(|c| {
+                BridgedType::new_with_str(&c.to_string(), types)
+                    .unwrap()
+                    .to_rust_type_path(types)
+            })
+            .collect();
+
+        quote! { #name<#(#concrete),*> }
+    }
+
+    /// __swift_bridge__$SomeStruct, or __swift_bridge__$Point$f32 for `struct Point<f32>;`
     pub(crate) fn ffi_name_string(&self) -> String {
         let name = self.swift_name_string();
 
-        format!("{}${}", SWIFT_BRIDGE_PREFIX, name)
+        format!(
+            "{}${}{}",
+            SWIFT_BRIDGE_PREFIX,
+            name,
+            self.generics.dollar_prefixed_concrete_string()
+        )
     }
 
+    /// __swift_bridge__SomeStruct, or __swift_bridge__Point_f32 for `struct Point<f32>;`
     pub(crate) fn ffi_name_tokens(&self) -> TokenStream {
         let name = Ident::new(
-            &format!("{}{}", SWIFT_BRIDGE_PREFIX, &self.name),
+            &format!(
+                "{}{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                &self.name,
+                self.generics.underscore_prefixed_concrete_string()
+            ),
             self.name.span(),
         );
 
@@ -293,7 +491,12 @@ impl SharedStruct {
     /// __swift_bridge__Option_SomeStruct
     pub fn ffi_option_name_tokens(&self) -> TokenStream {
         let name = Ident::new(
-            &format!("{}Option_{}", SWIFT_BRIDGE_PREFIX, self.name),
+            &format!(
+                "{}Option_{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                self.name,
+                self.generics.underscore_prefixed_concrete_string()
+            ),
             self.name.span(),
         );
         quote! { #name }
@@ -302,7 +505,12 @@ impl SharedStruct {
     /// __swift_bridge__$Option$SomeStruct
     pub fn ffi_option_name_string(&self) -> String {
         let name = self.swift_name_string();
-        format!("{}$Option${}", SWIFT_BRIDGE_PREFIX, name,)
+        format!(
+            "{}$Option${}{}",
+            SWIFT_BRIDGE_PREFIX,
+            name,
+            self.generics.dollar_prefixed_concrete_string()
+        )
     }
 
     /// Some if the struct has a single variant.
@@ -376,8 +584,7 @@ impl SharedStruct {
         swift_bridge_path: &Path,
         span: Span,
     ) -> TokenStream {
-        let struct_name = &self.name;
-        let struct_ffi_name = format_ident!("{}{}", SWIFT_BRIDGE_PREFIX, struct_name);
+        let struct_name = self.rust_type_tokens(types);
 
         let converted_fields: Vec<TokenStream> = self
             .fields
@@ -419,7 +626,7 @@ impl SharedStruct {
             impl #struct_name {
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn into_ffi_repr(self) -> #struct_ffi_name {
+                pub fn into_ffi_repr(self) -> #ffi_name {
                     #convert_rust_to_ffi
                 }
             }
@@ -531,21 +738,17 @@ impl SharedStruct {
         }
     }
 
-    pub fn type_name_with_swift_bridge_prefix(&self, swift_bridge_path: &Path) -> TokenStream {
-        let ty_name = &self.name;
-
-        let prefixed_ty_name = Ident::new(
-            &format!("{}{}", SWIFT_BRIDGE_PREFIX, ty_name),
-            ty_name.span(),
-        );
-
-        let prefixed_ty_name = if self.already_declared {
+    pub fn type_name_with_swift_bridge_prefix(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.already_declared {
+            let ty_name = self.rust_type_tokens(types);
             quote! { <super:: #ty_name as #swift_bridge_path::SharedStruct>::FfiRepr }
         } else {
-            quote! { #prefixed_ty_name }
-        };
-
-        prefixed_ty_name
+            self.ffi_name_tokens()
+        }
     }
 
     pub fn convert_ffi_expression_to_rust_type(
@@ -589,6 +792,7 @@ impl PartialEq for SharedStruct {
             && self.swift_name.as_ref().map(|l| l.value())
                 == other.swift_name.as_ref().map(|l| l.value())
             && self.already_declared == other.already_declared
+            && self.generics == other.generics
     }
 }
 
@@ -600,6 +804,15 @@ impl Debug for SharedStruct {
             .field("fields", &self.fields)
             .field("swift_name", &self.swift_name.as_ref().map(|l| l.value()))
             .field("already_declared", &self.already_declared)
+            .field(
+                "generics",
+                &self
+                    .generics
+                    .concrete
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
@@ -82,6 +82,43 @@ impl StructFields {
         }
     }
 
+    /// Replace the fields whose type is a generic parameter with the parameter's concrete type.
+    ///
+    /// `struct Point<T> { x: T }` becomes `struct Point<f32> { x: f32 }`
+    pub fn with_concrete_generics(&self, params: &[Ident], concrete: &[Ident]) -> Self {
+        let concrete_ty = |ty: &Type| -> Type {
+            match params.iter().position(|param| type_is_ident(ty, param)) {
+                Some(idx) => {
+                    let concrete = &concrete[idx];
+                    syn::parse_quote! { #concrete }
+                }
+                None => ty.clone(),
+            }
+        };
+
+        match self {
+            StructFields::Named(named) => StructFields::Named(
+                named
+                    .iter()
+                    .map(|field| NamedStructField {
+                        name: field.name.clone(),
+                        ty: concrete_ty(&field.ty),
+                    })
+                    .collect(),
+            ),
+            StructFields::Unnamed(unnamed) => StructFields::Unnamed(
+                unnamed
+                    .iter()
+                    .map(|field| UnnamedStructField {
+                        ty: concrete_ty(&field.ty),
+                        idx: field.idx,
+                    })
+                    .collect(),
+            ),
+            StructFields::Unit => StructFields::Unit,
+        }
+    }
+
     pub fn from_syn_fields(fields: Fields) -> Self {
         match fields {
             Fields::Named(f) => {
@@ -181,3 +218,11 @@ impl Debug for UnnamedStructField {
             .finish()
     }
 }
+
+/// Whether the type is exactly the given identifier, such as `T`.
+pub(crate) fn type_is_ident(ty: &Type, ident: &Ident) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident(ident),
+        _ => false,
+    }
+}
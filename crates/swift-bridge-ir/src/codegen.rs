@@ -1,4 +1,6 @@
 use crate::bridge_module_attributes::CfgAttr;
+use crate::bridged_type::{BridgeableType, BridgedType};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
 
 mod generate_c_header;
@@ -14,6 +16,24 @@ pub struct SwiftCodeAndCHeader {
     pub swift: String,
     /// The generated C header.
     pub c_header: String,
+    /// The code for the generic built-in types, such as `HashMap<String, u32>`, that the module
+    /// uses.
+    ///
+    /// More than one bridge module in a crate can use the same type, so only one instantiation
+    /// with a given name should be included in the crate's generated code.
+    pub generic_instantiations: Vec<GenericInstantiation>,
+}
+
+/// The Swift code and C header declarations for one instantiation of a generic built-in type,
+/// such as `HashMap<String, u32>`.
+#[derive(Debug)]
+pub struct GenericInstantiation {
+    /// A name that is unique to the instantiation, such as "HashMap_String_U32".
+    pub name: String,
+    /// The generated Swift code.
+    pub swift: String,
+    /// The generated C header declarations.
+    pub c_header: String,
 }
 
 /// Configuration for how we will generate our Swift code.
@@ -39,9 +59,49 @@ impl SwiftBridgeModule {
         SwiftCodeAndCHeader {
             swift: self.generate_swift(&config),
             c_header: self.generate_c_header(&config),
+            generic_instantiations: self.generate_generic_instantiations(&config),
         }
     }
 
+    /// Generate the code for each of the generic built-in types, such as `HashMap<String, u32>`,
+    /// that the module's functions use.
+    pub(crate) fn generate_generic_instantiations(
+        &self,
+        config: &CodegenConfig,
+    ) -> Vec<GenericInstantiation> {
+        let mut instantiations: Vec<GenericInstantiation> = vec![];
+
+        if !self.module_will_be_compiled(config) {
+            return instantiations;
+        }
+
+        for func in &self.functions {
+            let ret = BridgedType::new_with_return_type(&func.sig.output, &self.types);
+            let args = func
+                .sig
+                .inputs
+                .iter()
+                .map(|arg| BridgedType::new_with_fn_arg(arg, &self.types));
+
+            for ty in std::iter::once(ret).chain(args).flatten() {
+                let instantiation =
+                    match ty.generate_generic_instantiation(&self.types, &self.swift_bridge_path) {
+                        Some(instantiation) => instantiation,
+                        None => continue,
+                    };
+
+                if !instantiations
+                    .iter()
+                    .any(|existing| existing.name == instantiation.name)
+                {
+                    instantiations.push(instantiation);
+                }
+            }
+        }
+
+        instantiations
+    }
+
     /// Whether or not the module's conditional compilation flags willl lead it to being included
     /// in the final binary.
     /// If not, when we won't generate any C or Swift code for it.
@@ -58,4 +118,37 @@ impl SwiftBridgeModule {
 
         true
     }
+
+    /// Whether or not any of the module's functions or shared structs use a `Vec<T>` of the given
+    /// opaque Swift type.
+    ///
+    /// Most Swift types never end up in a `Vec`, so we only generate `Vec` support for the ones
+    /// that do.
+    fn vec_of_opaque_swift_type_is_used(&self, ty_name: &str) -> bool {
+        let used_by_function = self.functions.iter().any(|func| {
+            let ret = BridgedType::new_with_return_type(&func.sig.output, &self.types);
+            let args = func
+                .sig
+                .inputs
+                .iter()
+                .map(|arg| BridgedType::new_with_fn_arg(arg, &self.types));
+
+            std::iter::once(ret)
+                .chain(args)
+                .flatten()
+                .any(|ty| ty.contains_vec_of_recursive(ty_name, &self.types))
+        });
+
+        let used_by_shared_struct = self.types.types().into_iter().any(|ty| match ty {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => shared_struct
+                .fields
+                .normalized_fields()
+                .iter()
+                .filter_map(|field| BridgedType::new_with_type(&field.ty, &self.types))
+                .any(|ty| ty.contains_vec_of_recursive(ty_name, &self.types)),
+            _ => false,
+        });
+
+        used_by_function || used_by_shared_struct
+    }
 }
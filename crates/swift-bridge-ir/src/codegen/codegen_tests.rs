@@ -29,10 +29,13 @@ use crate::test_utils::{
 
 mod already_declared_attribute_codegen_tests;
 mod argument_label_codegen_tests;
+mod array_codegen_tests;
 mod async_function_codegen_tests;
+mod boxed_fn_codegen_tests;
 mod boxed_fnonce_codegen_tests;
 mod built_in_tuple_codegen_tests;
 mod c_header_declaration_order_codegen_tests;
+mod codable_codegen_tests;
 mod conditional_compilation_codegen_tests;
 mod derive_attribute_codegen_tests;
 mod derive_struct_attribute_codegen_tests;
@@ -42,13 +45,21 @@ mod extern_rust_method_swift_class_placement_codegen_tests;
 mod extern_swift_function_opaque_swift_type_return_codegen_tests;
 mod function_attribute_codegen_tests;
 mod generic_opaque_rust_type_codegen_tests;
+mod hash_map_codegen_tests;
 mod opaque_rust_type_codegen_tests;
 mod opaque_swift_type_codegen_tests;
+mod operator_codegen_tests;
 mod option_codegen_tests;
+mod primitive_codegen_tests;
+mod property_codegen_tests;
 mod result_codegen_tests;
 mod return_into_attribute_codegen_tests;
+mod rust_trait_object_codegen_tests;
+mod serde_codegen_tests;
+mod set_codegen_tests;
 mod single_representation_type_elision_codegen_tests;
 mod string_codegen_tests;
+mod swift_protocol_codegen_tests;
 mod transparent_enum_codegen_tests;
 mod transparent_struct_codegen_tests;
 mod vec_codegen_tests;
@@ -173,7 +184,15 @@ impl CodegenTest {
             crate_feature_lookup,
         };
 
-        let swift = module.generate_swift(&codegen_config);
+        // swift-bridge-build includes each generic instantiation once per crate, after the code
+        // for all of the crate's bridge modules.
+        let generic_instantiations = module.generate_generic_instantiations(&codegen_config);
+
+        let mut swift = module.generate_swift(&codegen_config);
+        for instantiation in &generic_instantiations {
+            swift += &instantiation.swift;
+            swift += "\n";
+        }
         match self.expected_swift_code {
             ExpectedSwiftCode::ExactAfterTrim(expected_swift) => {
                 assert_trimmed_generated_equals_trimmed_expected(&swift, expected_swift);
@@ -209,7 +228,11 @@ impl CodegenTest {
             ExpectedSwiftCode::SkipTest => {}
         };
 
-        let c_header = module.generate_c_header_inner(&codegen_config);
+        let mut c_header = module.generate_c_header_inner(&codegen_config);
+        for instantiation in &generic_instantiations {
+            c_header += &instantiation.c_header;
+            c_header += "\n";
+        }
         match self.expected_c_header {
             ExpectedCHeader::ExactAfterTrim(expected) => {
                 assert_trimmed_generated_equals_trimmed_expected(&c_header, expected);
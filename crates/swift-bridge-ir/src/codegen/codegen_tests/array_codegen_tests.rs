@@ -0,0 +1,240 @@
+//! See also: crates/swift-integration-tests/src/array.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can use a `[T; N]` of primitives as a Rust function arg and return type.
+mod extern_rust_fn_array_of_primitives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: [u8; 4]) -> [u8; 4];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(arg: __swift_bridge__Array_U8_4) -> __swift_bridge__Array_U8_4 {
+                    __swift_bridge__Array_U8_4(super::some_function(arg.0))
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Array_U8_4([u8; 4]);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: RustArray<UInt8>) -> RustArray<UInt8> {
+    __swift_bridge__$some_function(__swift_bridge__$Array_U8_4(arg)).intoSwiftRepr()
+}
+"#,
+            r#"
+extension __swift_bridge__$Array_U8_4 {
+    init(_ array: RustArray<UInt8>) {
+        precondition(array.count == 4, "Expected an array of 4 elements")
+        self.init()
+        withUnsafeMutableBytes(of: &self.elements) { buffer in
+            let elements = buffer.bindMemory(to: UInt8.self)
+            for (index, element) in array.enumerated() {
+                elements[index] = element
+            }
+        }
+    }
+
+    func intoSwiftRepr() -> RustArray<UInt8> {
+        withUnsafeBytes(of: self.elements) { buffer in
+            RustArray(buffer.bindMemory(to: UInt8.self).map { $0 })
+        }
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+"#,
+            r#"
+typedef struct __swift_bridge__$Array_U8_4 { uint8_t elements[4]; } __swift_bridge__$Array_U8_4;
+"#,
+            r#"
+struct __swift_bridge__$Array_U8_4 __swift_bridge__$some_function(struct __swift_bridge__$Array_U8_4 arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_array_of_primitives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a `[T; N]` of transparent structs as a Rust function arg and return
+/// type.
+mod extern_rust_fn_array_of_transparent_structs {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: [SomeStruct; 2]) -> [SomeStruct; 2];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(arg: __swift_bridge__Array_SomeStruct_2) -> __swift_bridge__Array_SomeStruct_2 {
+                    {
+                        let val = super::some_function(arg.0.map(|val| val.into_rust_repr()));
+                        __swift_bridge__Array_SomeStruct_2(val.map(|val| val.into_ffi_repr()))
+                    }
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Array_SomeStruct_2([__swift_bridge__SomeStruct; 2]);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: RustArray<SomeStruct>) -> RustArray<SomeStruct> {
+    __swift_bridge__$some_function(__swift_bridge__$Array_SomeStruct_2(arg)).intoSwiftRepr()
+}
+"#,
+            r#"
+extension __swift_bridge__$Array_SomeStruct_2 {
+    init(_ array: RustArray<SomeStruct>) {
+        precondition(array.count == 2, "Expected an array of 2 elements")
+        self.init()
+        withUnsafeMutableBytes(of: &self.elements) { buffer in
+            let elements = buffer.bindMemory(to: __swift_bridge__$SomeStruct.self)
+            for (index, element) in array.enumerated() {
+                elements[index] = element.intoFfiRepr()
+            }
+        }
+    }
+
+    func intoSwiftRepr() -> RustArray<SomeStruct> {
+        withUnsafeBytes(of: self.elements) { buffer in
+            RustArray(buffer.bindMemory(to: __swift_bridge__$SomeStruct.self).map { $0.intoSwiftRepr() })
+        }
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Array_SomeStruct_2 { struct __swift_bridge__$SomeStruct elements[2]; } __swift_bridge__$Array_SomeStruct_2;
+"#,
+            r#"
+struct __swift_bridge__$Array_SomeStruct_2 __swift_bridge__$some_function(struct __swift_bridge__$Array_SomeStruct_2 arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_array_of_transparent_structs() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a `[T; N]` as a Swift function arg and return type.
+mod extern_swift_fn_array_of_primitives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: [f32; 4]) -> [f32; 4];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: [f32; 4]) -> [f32; 4] {
+                    unsafe { __swift_bridge__some_function(__swift_bridge__Array_F32_4(arg)) }.0
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: __swift_bridge__Array_F32_4) -> __swift_bridge__Array_F32_4;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$Array_F32_4) -> __swift_bridge__$Array_F32_4 {
+    __swift_bridge__$Array_F32_4(some_function(arg: arg.intoSwiftRepr()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Array_F32_4 { float elements[4]; } __swift_bridge__$Array_F32_4;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_array_of_primitives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,327 @@
+//! Tests for passing Box<dyn Fn(A, B) -> C> and Box<dyn FnMut(A, B) -> C> between languages.
+//!
+//! Unlike a `Box<dyn FnOnce>`, these can be called many times, so the Swift wrapper class
+//! borrows the boxed function on every call and only frees it when the wrapper is deinitialized.
+//!
+//! More tests for boxed functions can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/boxed_fnonce_codegen_tests.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can pass a `Box<dyn Fn>` with a primitive arg and return value from Rust to
+/// Swift.
+mod test_swift_takes_fn_callback_primitive_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn Fn(u8) -> u16>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (callback: Box<dyn Fn(u8) -> u16>) {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            Box::into_raw(Box::new(callback)) as *mut Box<dyn Fn(u8) -> u16>
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function$param0"]
+                pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn Fn(u8) -> u16>, arg0: u8) -> u16 {
+                    unsafe { (*some_function_callback)(arg0) }
+                }
+
+                #[export_name = "__swift_bridge__$some_function$_free$param0"]
+                pub extern "C" fn free_some_function_param0(some_function_callback: *mut Box<dyn Fn(u8) -> u16>) {
+                    let _ = unsafe { Box::from_raw(some_function_callback) };
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(callback: *mut Box<dyn Fn(u8) -> u16>);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__RustFnCallback$some_function$param0 {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$some_function$_free$param0(ptr)
+    }
+
+    func call(_ arg0: UInt8) -> UInt16 {
+        return __swift_bridge__$some_function$param0(ptr, arg0)
+    }
+}
+            "#,
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: UnsafeMutableRawPointer) {
+    { let cb0 = __private__RustFnCallback$some_function$param0(ptr: callback); let _ = some_function(callback: { arg0 in cb0.call(arg0) }) }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint16_t __swift_bridge__$some_function$param0(void* some_function_callback, uint8_t arg0);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_fn_callback_primitive_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Box<dyn FnMut>` with an opaque Rust arg from Rust to Swift.
+/// The function is wrapped in a `RefCell` so that calling it while it is already running aborts.
+mod test_swift_takes_fn_mut_callback_opaque_rust_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn FnMut(ARustType)>);
+                }
+
+                extern "Rust" {
+                    type ARustType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function$param0"]
+                pub extern "C" fn some_function_param0(some_function_callback: *mut std::cell::RefCell<Box<dyn FnMut(super::ARustType) -> ()> >, arg0: *mut super::ARustType) {
+                    (*swift_bridge::boxed_fn_support::borrow_boxed_fn_mut(unsafe { &*some_function_callback }))(unsafe { *Box::from_raw(arg0) })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(callback: *mut std::cell::RefCell<Box<dyn FnMut(super::ARustType) -> ()> >);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__RustFnMutCallback$some_function$param0 {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$some_function$_free$param0(ptr)
+    }
+
+    func call(_ arg0: ARustType) {
+        return __swift_bridge__$some_function$param0(ptr, {arg0.isOwned = false; return arg0.ptr;}())
+    }
+}
+            "#,
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: UnsafeMutableRawPointer) {
+    { let cb0 = __private__RustFnMutCallback$some_function$param0(ptr: callback); let _ = some_function(callback: { arg0 in cb0.call(arg0) }) }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function$param0(void* some_function_callback, void* arg0);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_fn_mut_callback_opaque_rust_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Box<dyn FnMut()>` that has no args or return value uses the wrapper class
+/// from the core Swift support code.
+mod test_swift_takes_fn_mut_no_args_no_return_callback {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn FnMut()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (callback: Box<dyn FnMut() -> ()>) {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            Box::into_raw(Box::new(std::cell::RefCell::new(callback))) as *mut std::cell::RefCell<Box<dyn FnMut() -> ()> >
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(callback: *mut std::cell::RefCell<Box<dyn FnMut() -> ()> >);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: UnsafeMutableRawPointer) {
+    { let cb0 = __private__RustFnMutCallbackNoArgsNoRet(ptr: callback); let _ = some_function(callback: { cb0.call() }) }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn test_swift_takes_fn_mut_no_args_no_return_callback() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift can pass a closure to a Rust function that takes a
+/// `Box<dyn Fn(A) -> B + Send>`. Rust can call it from other threads, so the closure must be
+/// `@Sendable`.
+mod test_rust_takes_swift_closure {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(u32) -> bool + Send>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(callback: __swift_bridge__some_function_param0) {
+                    super::some_function(Box::new(move |arg0: u32| callback.call(arg0)))
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__some_function_param0 {
+                    context: *mut std::ffi::c_void,
+                    call: extern "C" fn(*mut std::ffi::c_void, u32) -> bool,
+                    release: extern "C" fn(*mut std::ffi::c_void),
+                }
+                impl __swift_bridge__some_function_param0 {
+                    fn call(&self, arg0: u32) -> bool {
+                        (self.call)(self.context, arg0)
+                    }
+                }
+                impl Drop for __swift_bridge__some_function_param0 {
+                    fn drop(&mut self) {
+                        (self.release)(self.context)
+                    }
+                }
+                unsafe impl Send for __swift_bridge__some_function_param0 {}
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ callback: @escaping @Sendable (UInt32) -> Bool) {
+    __swift_bridge__$some_function(__swift_bridge__$some_function$param0(context: Unmanaged.passRetained(__private__SwiftCallbackBox(callback)).toOpaque(), call: { context, arg0 in Unmanaged<__private__SwiftCallbackBox<@Sendable (UInt32) -> Bool>>.fromOpaque(context!).takeUnretainedValue().callback(arg0) }, release: { context in Unmanaged<AnyObject>.fromOpaque(context!).release() }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef bool (*__swift_bridge__$some_function$param0$call)(void* context, uint32_t arg0);
+typedef void (*__swift_bridge__$some_function$param0$release)(void* context);
+typedef struct __swift_bridge__$some_function$param0 { void* context; __swift_bridge__$some_function$param0$call call; __swift_bridge__$some_function$param0$release release; } __swift_bridge__$some_function$param0;
+void __swift_bridge__$some_function(__swift_bridge__$some_function$param0 callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_rust_takes_swift_closure() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
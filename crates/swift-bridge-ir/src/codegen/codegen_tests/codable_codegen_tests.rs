@@ -0,0 +1,194 @@
+//! Tests for `#[swift_bridge(Codable)]` shared structs and enums.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate coding keys that match the Rust field names of a struct.
+mod codable_struct_named_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeStruct {
+                    field_a: u8,
+                    field_b: u16,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Codable {
+    enum CodingKeys: String, CodingKey {
+        case field_a = "field_a"
+        case field_b = "field_b"
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn codable_struct_named_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a tuple struct is encoded as a single value or as an array, the same way that
+/// serde serializes it.
+mod codable_struct_unnamed_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct NewTypeStruct(u8);
+
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct TupleStruct(u8, u16);
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension NewTypeStruct: Codable {
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        self.init(_0: try container.decode(UInt8.self))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(self._0)
+    }
+}
+"#,
+            r#"
+extension TupleStruct: Codable {
+    public init(from decoder: Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.init(_0: try container.decode(UInt8.self), _1: try container.decode(UInt16.self))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(self._0)
+        try container.encode(self._1)
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn codable_struct_unnamed_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that an enum is encoded using serde's externally tagged representation.
+mod codable_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(Codable)]
+                enum SomeEnum {
+                    Unit,
+                    NewType(u8),
+                    Tuple(u8, u16),
+                    Named { a: u8 },
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Codable {
+    public init(from decoder: Decoder) throws {
+        if let variant = try? decoder.singleValueContainer().decode(String.self) {
+            switch variant {
+            case "Unit":
+                self = .Unit
+            default:
+                throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Unknown variant \(variant)"))
+            }
+            return
+        }
+
+        let container = try decoder.container(keyedBy: SwiftBridgeCodingKey.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected a single variant"))
+        }
+        switch key.stringValue {
+        case "NewType":
+            self = .NewType(try container.decode(UInt8.self, forKey: key))
+        case "Tuple":
+            var fields = try container.nestedUnkeyedContainer(forKey: key)
+            self = .Tuple(try fields.decode(UInt8.self), try fields.decode(UInt16.self))
+        case "Named":
+            let fields = try container.nestedContainer(keyedBy: SwiftBridgeCodingKey.self, forKey: key)
+            self = .Named(a: try fields.decode(UInt8.self, forKey: SwiftBridgeCodingKey("a")))
+        default:
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Unknown variant \(key.stringValue)"))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        switch self {
+        case .Unit:
+            var container = encoder.singleValueContainer()
+            try container.encode("Unit")
+        case .NewType(let _0):
+            var container = encoder.container(keyedBy: SwiftBridgeCodingKey.self)
+            try container.encode(_0, forKey: SwiftBridgeCodingKey("NewType"))
+        case .Tuple(let _0, let _1):
+            var container = encoder.container(keyedBy: SwiftBridgeCodingKey.self)
+            var fields = container.nestedUnkeyedContainer(forKey: SwiftBridgeCodingKey("Tuple"))
+            try fields.encode(_0)
+            try fields.encode(_1)
+        case .Named(let a):
+            var container = encoder.container(keyedBy: SwiftBridgeCodingKey.self)
+            var fields = container.nestedContainer(keyedBy: SwiftBridgeCodingKey.self, forKey: SwiftBridgeCodingKey("Named"))
+            try fields.encode(a, forKey: SwiftBridgeCodingKey("a"))
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn codable_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
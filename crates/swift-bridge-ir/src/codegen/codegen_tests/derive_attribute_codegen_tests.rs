@@ -66,3 +66,57 @@ void* __swift_bridge__$SomeEnum$Debug(__swift_bridge__$SomeEnum this);
         .test();
     }
 }
+
+/// Verify that deriving `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` on an enum generates the
+/// matching Swift `Equatable`, `Hashable` and `Comparable` conformances.
+mod derive_comparison_traits_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2(u8)
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+            pub enum SomeEnum {
+                Variant1,
+                Variant2(u8)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Equatable {}
+extension SomeEnum: Hashable {}
+extension SomeEnum: Comparable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_enum_conformances() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -93,3 +93,125 @@ mod derive_clone_struct {
         .test();
     }
 }
+
+/// Verify that deriving `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` on a transparent struct
+/// generates the matching Swift `Equatable`, `Hashable` and `Comparable` conformances.
+mod derive_comparison_traits_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+                struct SomeStruct {
+                    major: u8,
+                    minor: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+            pub struct SomeStruct {
+                pub major: u8,
+                pub minor: u8
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Equatable {}
+extension SomeStruct: Hashable {}
+extension SomeStruct: Comparable {
+    public static func < (lhs: SomeStruct, rhs: SomeStruct) -> Bool {
+        if lhs.major != rhs.major {
+            return lhs.major < rhs.major
+        }
+        return lhs.minor < rhs.minor
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic transparent struct only conforms to `Equatable`, `Hashable` and
+/// `Comparable` when its generic parameters do.
+mod derive_comparison_traits_generic_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(PartialEq, Hash, PartialOrd)]
+                struct Wrapper<T> {
+                    inner: T,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Wrapper<u8>;
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[derive(PartialEq, Hash, PartialOrd)]
+            pub struct Wrapper<T> {
+                pub inner: T
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Wrapper: Equatable where T: Equatable {}
+extension Wrapper: Hashable where T: Hashable {}
+extension Wrapper: Comparable where T: Comparable {
+    public static func < (lhs: Wrapper, rhs: Wrapper) -> Bool {
+        return lhs.inner < rhs.inner
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
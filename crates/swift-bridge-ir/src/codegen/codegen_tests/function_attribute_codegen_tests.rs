@@ -467,3 +467,87 @@ func __swift_bridge__call_swift_from_rust () -> UnsafeMutableRawPointer {
         .test();
     }
 }
+
+/// Verify that the monomorphize attribute generates a separate function for each instantiation
+/// of a generic function.
+mod function_attribute_monomorphize {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(monomorphize = (u32, f64))]
+                    fn parse<T>(s: &str) -> Option<T>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$parse$u32"]
+                pub extern "C" fn __swift_bridge__parse_u32(
+                    s: swift_bridge::string::RustStr
+                ) -> swift_bridge::option::OptionU32 {
+                    if let Some(val) = super::parse::<u32>(s.to_str()) {
+                        swift_bridge::option::OptionU32 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$parse$f64"]
+                pub extern "C" fn __swift_bridge__parse_f64(
+                    s: swift_bridge::string::RustStr
+                ) -> swift_bridge::option::OptionF64 {
+                    if let Some(val) = super::parse::<f64>(s.to_str()) {
+                        swift_bridge::option::OptionF64 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionF64 { val: 123.4, is_some: false }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func parse<GenericToRustStr: ToRustStr>(_ s: GenericToRustStr) -> Optional<UInt32> {
+    return s.toRustStr({ sAsRustStr in
+        __swift_bridge__$parse$u32(sAsRustStr).intoSwiftRepr()
+    })
+}
+"#,
+            r#"
+public func parse<GenericToRustStr: ToRustStr>(_ s: GenericToRustStr) -> Optional<Double> {
+    return s.toRustStr({ sAsRustStr in
+        __swift_bridge__$parse$f64(sAsRustStr).intoSwiftRepr()
+    })
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "struct __private__OptionU32 __swift_bridge__$parse$u32(struct RustStr s);",
+            "struct __private__OptionF64 __swift_bridge__$parse$f64(struct RustStr s);",
+        ])
+    }
+
+    #[test]
+    fn function_attribute_monomorphize() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
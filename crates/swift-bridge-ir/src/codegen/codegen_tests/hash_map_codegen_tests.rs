@@ -0,0 +1,219 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can use a HashMap<K, V> as an extern "Rust" function argument and return type.
+mod extern_rust_fn_hash_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashMap<String, u32>) -> HashMap<String, u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: *mut std::collections::HashMap<String, u32>
+                ) -> *mut std::collections::HashMap<String, u32> {
+                    Box::into_raw(Box::new(super::some_function(unsafe { *Box::from_raw(arg) })))
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$HashMap_String_U32$df7105364eb624b9$get"]
+                pub extern "C" fn _get(
+                    map: *const std::collections::HashMap<String, u32>,
+                    key: swift_bridge::string::RustStr
+                ) -> swift_bridge::option::OptionU32 {
+                    let map = unsafe { &*map };
+                    let val = map.get(key.to_str()).cloned();
+                    if let Some(val) = val {
+                        swift_bridge::option::OptionU32 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                    }
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$HashMap_String_U32$df7105364eb624b9$insert"]
+                pub extern "C" fn _insert(
+                    map: *mut std::collections::HashMap<String, u32>,
+                    key: *mut swift_bridge::string::RustString,
+                    value: u32
+                ) -> swift_bridge::option::OptionU32 {
+                    let map = unsafe { &mut *map };
+                    let val = map.insert(unsafe { Box::from_raw(key).0 }, value);
+                    if let Some(val) = val {
+                        swift_bridge::option::OptionU32 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                    }
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_key"]
+                pub extern "C" fn _iter_key(
+                    iter: *mut swift_bridge::rust_hash_map::HashMapIter<String, u32>
+                ) -> *mut swift_bridge::string::RustString {
+                    let key = unsafe { &mut *iter }.take_key();
+                    swift_bridge::string::RustString(key).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: RustHashMap<RustString, UInt32>) -> RustHashMap<RustString, UInt32> {
+    RustHashMap<RustString, UInt32>(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+            r#"
+extension RustHashMap where K == RustString, V == UInt32 {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$HashMap_String_U32$df7105364eb624b9$new())
+    }
+
+    convenience init(ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr, vtable: __private__RustHashMapVTable(
+            drop: { ptr in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$drop(ptr) },
+            len: { ptr in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$len(ptr) },
+            get: { ptr, key in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$get(ptr, key.as_str()).intoSwiftRepr() },
+            insert: { ptr, key, value in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$insert(ptr, { let rustString = key.intoRustString(); rustString.isOwned = false; return rustString.ptr }(), value).intoSwiftRepr() },
+            remove: { ptr, key in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$remove(ptr, key.as_str()).intoSwiftRepr() },
+            iter: { ptr in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter(ptr) },
+            iterNext: { iter in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_next(iter) },
+            iterKey: { iter in RustString(ptr: __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_key(iter)) },
+            iterValue: { iter in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_value(iter) },
+            iterDrop: { iter in __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_drop(iter) }
+        ))
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+"#,
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+            r#"
+void* __swift_bridge__$HashMap_String_U32$df7105364eb624b9$new(void);
+void __swift_bridge__$HashMap_String_U32$df7105364eb624b9$drop(void* map);
+uintptr_t __swift_bridge__$HashMap_String_U32$df7105364eb624b9$len(void* map);
+struct __private__OptionU32 __swift_bridge__$HashMap_String_U32$df7105364eb624b9$get(void* map, struct RustStr key);
+struct __private__OptionU32 __swift_bridge__$HashMap_String_U32$df7105364eb624b9$insert(void* map, void* key, uint32_t value);
+struct __private__OptionU32 __swift_bridge__$HashMap_String_U32$df7105364eb624b9$remove(void* map, struct RustStr key);
+void* __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter(void* map);
+bool __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_next(void* iter);
+void* __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_key(void* iter);
+uint32_t __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_value(void* iter);
+void __swift_bridge__$HashMap_String_U32$df7105364eb624b9$iter_drop(void* iter);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_hash_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a HashMap<K, V> as an extern "Swift" function argument and return type.
+mod extern_swift_fn_hash_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: HashMap<u8, String>) -> HashMap<u8, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    arg: std::collections::HashMap<u8, String>
+                ) -> std::collections::HashMap<u8, String> {
+                    unsafe { * Box::from_raw(unsafe { __swift_bridge__some_function(Box::into_raw(Box::new(arg))) }) }
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$HashMap_U8_String$df7105364eb624b9$remove"]
+                pub extern "C" fn _remove(
+                    map: *mut std::collections::HashMap<u8, String>,
+                    key: u8
+                ) -> *mut swift_bridge::string::RustString {
+                    let map = unsafe { &mut *map };
+                    let val = map.remove(&key);
+                    if let Some(val) = val {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let val = some_function(arg: RustHashMap<UInt8, RustString>(ptr: arg)); val.isOwned = false; return val.ptr }()
+}
+"#,
+            r#"
+extension RustHashMap where K == UInt8, V == RustString {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$HashMap_U8_String$df7105364eb624b9$get(void* map, uint8_t key);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_hash_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
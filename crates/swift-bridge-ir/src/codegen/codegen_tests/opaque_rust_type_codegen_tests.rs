@@ -208,6 +208,229 @@ bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
     }
 }
 
+/// Test code generation for an extern "Rust" type that implements Clone.
+mod extern_rust_clone_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type CloneType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$CloneType$_clone"]
+            pub extern "C" fn __swift_bridge__CloneType__clone (
+                this: *const super::CloneType,
+            ) -> *mut super::CloneType {
+                Box::into_raw(Box::new(
+                    std::clone::Clone::clone(unsafe { &*this })
+                ))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension CloneTypeRef {
+    public func clone() -> CloneType {
+        CloneType(ptr: __swift_bridge__$CloneType$_clone(self.ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$CloneType$_clone(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_clone_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Comparable.
+mod extern_rust_comparable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Comparable)]
+                    type ComparableType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$ComparableType$_partial_eq"]
+                pub extern "C" fn __swift_bridge__ComparableType__partial_eq (
+                    lhs: *const super::ComparableType,
+                    rhs: *const super::ComparableType
+                ) -> bool {
+                    unsafe { &*lhs == &*rhs }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ComparableType$_less_than"]
+                pub extern "C" fn __swift_bridge__ComparableType__less_than (
+                    lhs: *const super::ComparableType,
+                    rhs: *const super::ComparableType
+                ) -> bool {
+                    unsafe {
+                        std::cmp::PartialOrd::partial_cmp(&*lhs, &*rhs)
+                            == Some(std::cmp::Ordering::Less)
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension ComparableTypeRef: Equatable {
+    public static func == (lhs: ComparableTypeRef, rhs: ComparableTypeRef) -> Bool {
+        __swift_bridge__$ComparableType$_partial_eq(rhs.ptr, lhs.ptr)
+    }
+}
+"#,
+            r#"
+extension ComparableTypeRef: Comparable {
+    public static func < (lhs: ComparableTypeRef, rhs: ComparableTypeRef) -> Bool {
+        __swift_bridge__$ComparableType$_less_than(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+bool __swift_bridge__$ComparableType$_partial_eq(void* lhs, void* rhs);
+bool __swift_bridge__$ComparableType$_less_than(void* lhs, void* rhs);
+"#,
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_comparable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Display and Debug.
+mod extern_rust_display_and_debug_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Display, Debug)]
+                    type PrintableType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$PrintableType$_display"]
+                pub extern "C" fn __swift_bridge__PrintableType__display (
+                    this: *const super::PrintableType,
+                ) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString(
+                        format!("{}", unsafe { &*this })
+                    ).box_into_raw()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$PrintableType$_debug"]
+                pub extern "C" fn __swift_bridge__PrintableType__debug (
+                    this: *const super::PrintableType,
+                ) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString(
+                        format!("{:?}", unsafe { &*this })
+                    ).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension PrintableTypeRef: CustomStringConvertible {
+    public var description: String {
+        RustString(ptr: __swift_bridge__$PrintableType$_display(self.ptr)).toString()
+    }
+}
+extension PrintableTypeRef: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        RustString(ptr: __swift_bridge__$PrintableType$_debug(self.ptr)).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$PrintableType$_display(void* self);
+void* __swift_bridge__$PrintableType$_debug(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_display_and_debug_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for an extern "Rust" type that implements Copy.
 mod extern_rust_copy_type {
     use super::*;
@@ -0,0 +1,178 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate Swift operators and subscripts that call an opaque Rust type's
+/// methods.
+mod opaque_rust_type_operators {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "+")]
+                    fn add(&self, other: &Vector) -> Vector;
+
+                    #[swift_bridge(operator = "-")]
+                    fn neg(&self) -> Vector;
+
+                    #[swift_bridge(operator = "*")]
+                    fn scale(self, factor: f64) -> Vector;
+
+                    #[swift_bridge(operator = "[]")]
+                    fn get(&self, index: usize) -> f64;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension VectorRef {
+    public static func + (lhs: VectorRef, rhs: VectorRef) -> Vector {
+        lhs.add(rhs)
+    }
+}
+"#,
+            r#"
+extension VectorRef {
+    public static prefix func - (operand: VectorRef) -> Vector {
+        operand.neg()
+    }
+}
+"#,
+            r#"
+extension Vector {
+    public static func * (lhs: Vector, rhs: Double) -> Vector {
+        lhs.scale(rhs)
+    }
+}
+"#,
+            r#"
+extension VectorRef {
+    public subscript(index: UInt) -> Double {
+        self.get(index)
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_operators() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we combine a subscript getter and setter into a subscript that can be written to
+/// through a mutable reference.
+mod opaque_rust_type_subscript_setter {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "[]")]
+                    fn component(&self, index: usize) -> f64;
+
+                    #[swift_bridge(operator = "[]=")]
+                    fn set_component(&mut self, idx: usize, value: f64);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension VectorRefMut {
+    public subscript(index: UInt) -> Double {
+        get {
+            self.component(index)
+        }
+        set(value) {
+            self.set_component(index, value)
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_subscript_setter() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate Swift operators that call freestanding functions, such as operators
+/// on a transparent struct.
+mod freestanding_function_operators {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Money {
+                    cents: i64,
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(operator = "+")]
+                    fn add_money(lhs: Money, rhs: Money) -> Money;
+
+                    #[swift_bridge(operator = "*", swift_name = "multiplyMoney")]
+                    fn multiply_money(factor: i64, #[swift_bridge(label = "by")] money: Money) -> Money;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension Money {
+    public static func + (lhs: Money, rhs: Money) -> Money {
+        add_money(lhs, rhs)
+    }
+}
+"#,
+            r#"
+public func * (lhs: Int64, rhs: Money) -> Money {
+    multiplyMoney(lhs, by: rhs)
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn freestanding_function_operators() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
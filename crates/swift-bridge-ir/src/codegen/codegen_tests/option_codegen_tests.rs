@@ -594,6 +594,324 @@ func __swift_bridge__some_function (_ arg: RustStr) {
     }
 }
 
+/// Test code generation for Swift function that returns an Option<&str>.
+mod extern_swift_func_returns_option_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function (arg: Option<&str>) -> Option<&str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<&str>) -> Option<&str> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(val) = arg {
+                                    swift_bridge::string::RustStr::from_str(val)
+                                } else {
+                                    swift_bridge::string::RustStr { start: std::ptr::null::<u8>(), len: 0}
+                                }
+                            )
+                        };
+                        if val.start.is_null() {
+                            None
+                        } else {
+                            Some(val.to_str())
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: swift_bridge::string::RustStr) -> swift_bridge::string::RustStr;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: RustStr) -> RustStr {
+    { if let val = some_function(arg: { let val = arg; if val.start != nil { return val; } else { return nil; } }()) { return val } else { return RustStr(start: nil, len: 0) } }()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_func_returns_option_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that accepts and returns an Option<TransparentEnum>.
+mod extern_swift_fn_option_transparent_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum SomeEnum {
+                    Variant
+                }
+
+                extern "Swift" {
+                    fn some_function (arg: Option<SomeEnum>) -> Option<SomeEnum>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<SomeEnum>) -> Option<SomeEnum> {
+                    unsafe {
+                        __swift_bridge__some_function(__swift_bridge__Option_SomeEnum::from_rust_repr(arg))
+                    }.into_rust_repr()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: __swift_bridge__Option_SomeEnum) -> __swift_bridge__Option_SomeEnum;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$Option$SomeEnum) -> __swift_bridge__$Option$SomeEnum {
+    __swift_bridge__$Option$SomeEnum.fromSwiftRepr(some_function(arg: arg.intoSwiftRepr()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_transparent_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that accepts and returns an Option<OpaqueRustType>.
+mod extern_swift_fn_option_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeRustType;
+                }
+
+                extern "Swift" {
+                    fn some_function (arg: Option<SomeRustType>) -> Option<SomeRustType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<super::SomeRustType>) -> Option<super::SomeRustType> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(val) = arg {
+                                    Box::into_raw(Box::new(val))
+                                } else {
+                                    std::ptr::null_mut()
+                                }
+                            )
+                        };
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(unsafe { * Box::from_raw(val) } )
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *mut super::SomeRustType) -> *mut super::SomeRustType;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return SomeRustType(ptr: val!) } else { return nil } }()) { val.isOwned = false; return val.ptr } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that accepts and returns an Option<OpaqueSwiftType>.
+mod extern_swift_fn_option_opaque_swift_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeSwiftType;
+
+                    fn some_function (arg: Option<SomeSwiftType>) -> Option<SomeSwiftType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<SomeSwiftType>) -> Option<SomeSwiftType> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(val) = arg {
+                                    let val = std::mem::ManuallyDrop::new(val);
+                                    val.0
+                                } else {
+                                    std::ptr::null_mut()
+                                }
+                            )
+                        };
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(SomeSwiftType(val))
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *mut std::ffi::c_void) -> *mut std::ffi::c_void;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return Unmanaged<SomeSwiftType>.fromOpaque(val!).takeRetainedValue() } else { return nil } }()) { return Unmanaged.passRetained(val).toOpaque() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_opaque_swift_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that returns an Option<&str> with nothing to borrow
+/// from.
+mod extern_swift_func_returns_option_swift_owned_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function () -> Option<&str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function() -> Option<swift_bridge::string::SwiftStr> {
+                    unsafe { __swift_bridge__some_function() }.into_option_swift_str()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function() -> swift_bridge::string::SwiftStrFfi;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function () -> __private__SwiftStr {
+    optionalStringIntoSwiftStr(some_function())
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_func_returns_option_swift_owned_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns an Option<OpaqueRustType>
 mod extern_rust_fn_return_option_opaque_rust_type {
     use super::*;
@@ -730,10 +1048,13 @@ mod extern_rust_fn_arg_option_ref_opaque_rust_type {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function(arg: *const super::SomeType) {
                 super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { & * arg })
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some( unsafe { & * val })
+                        }
                     }
                 )
             }
@@ -792,10 +1113,13 @@ mod extern_rust_fn_with_option_opaque_rust_type_arg {
                 arg: *mut super::SomeType
             ) {
                 super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { * Box::from_raw(arg) } )
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some( unsafe { * Box::from_raw(val) } )
+                        }
                     }
                 )
             }
@@ -918,10 +1242,13 @@ mod extern_rust_fn_with_option_generic_opaque_rust_type_arg {
                 arg: *mut super::SomeType<u32>
             ) {
                 super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { * Box::from_raw(arg) } )
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some( unsafe { * Box::from_raw(val) } )
+                        }
                     }
                 )
             }
@@ -1046,10 +1373,13 @@ mod extern_rust_fn_with_option_opaque_copy_rust_type_arg {
                 arg: __swift_bridge__Option_SomeType
             ) {
                 super::some_function(
-                    if arg.is_some {
-                        Some( unsafe { arg.val.assume_init() }.into_rust_repr() )
-                    } else {
-                        None
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some( unsafe { val.val.assume_init() }.into_rust_repr() )
+                        } else {
+                            None
+                        }
                     }
                 )
             }
@@ -1182,10 +1512,13 @@ mod extern_rust_fn_with_option_generic_opaque_copy_rust_type_arg {
                 arg: __swift_bridge__Option_SomeType_u32
             ) {
                 super::some_function(
-                    if arg.is_some {
-                        Some( unsafe { arg.val.assume_init() }.into_rust_repr() )
-                    } else {
-                        None
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some( unsafe { val.val.assume_init() }.into_rust_repr() )
+                        } else {
+                            None
+                        }
                     }
                 )
             }
@@ -0,0 +1,69 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift computed property from a getter and setter pair, and a
+/// read-only computed property from a getter without a setter.
+///
+/// The getter of a property with a setter is also kept as a method on `PersonRef`.
+mod property_getter_and_setter {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Person;
+
+                    #[swift_bridge(property = "age")]
+                    fn age(&self) -> u8;
+
+                    #[swift_bridge(property = "name")]
+                    fn name(&self) -> String;
+
+                    #[swift_bridge(setter = "name")]
+                    fn set_name(&mut self, name: String);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension PersonRefMut {
+    public var name: RustString {
+        get {
+            RustString(ptr: __swift_bridge__$Person$name(ptr))
+        }
+        set(name) {
+            __swift_bridge__$Person$set_name(ptr, { let rustString = name.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+        }
+    }
+}
+"#,
+            r#"
+extension PersonRef {
+    public var age: UInt8 {
+        __swift_bridge__$Person$age(ptr)
+    }
+
+    public func name() -> RustString {
+        RustString(ptr: __swift_bridge__$Person$name(ptr))
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn property_getter_and_setter() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
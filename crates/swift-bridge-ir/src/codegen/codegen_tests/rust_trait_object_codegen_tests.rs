@@ -0,0 +1,234 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift protocol for a `#[swift_bridge(protocol)]` Rust trait, along
+/// with a Swift class that conforms to the protocol by calling into a `Box<dyn Trait>`.
+mod rust_trait_object {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(protocol)]
+                    type Storage;
+
+                    fn get(&self, key: u32) -> u32;
+                    fn set(&mut self, key: u32, value: u32);
+
+                    fn make_storage() -> Box<dyn Storage>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Storage$get"]
+                pub extern "C" fn __swift_bridge__Storage_get(
+                    this: *mut Box<dyn super::Storage>,
+                    key: u32
+                ) -> u32 {
+                    (unsafe { &*this }).get(key)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Storage$set"]
+                pub extern "C" fn __swift_bridge__Storage_set(
+                    this: *mut Box<dyn super::Storage>,
+                    key: u32,
+                    value: u32
+                ) {
+                    (unsafe { &mut *this }).set(key, value)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$make_storage"]
+                pub extern "C" fn __swift_bridge__make_storage() -> *mut Box<dyn super::Storage> {
+                    Box::into_raw(Box::new(super::make_storage()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Storage$_free"]
+                pub extern "C" fn __swift_bridge__Storage__free(this: *mut Box<dyn super::Storage>) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func make_storage() -> RustStorage {
+    RustStorage(ptr: __swift_bridge__$make_storage())
+}
+"#,
+            r#"
+public protocol Storage: AnyObject {
+    func get(_ key: UInt32) -> UInt32
+    func set(_ key: UInt32, _ value: UInt32)
+}
+
+public class RustStorage: Storage {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$Storage$_free(ptr)
+        }
+    }
+}
+extension RustStorage {
+    public func get(_ key: UInt32) -> UInt32 {
+        __swift_bridge__$Storage$get(ptr, key)
+    }
+
+    public func set(_ key: UInt32, _ value: UInt32) {
+        __swift_bridge__$Storage$set(ptr, key, value)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct Storage Storage;
+void __swift_bridge__$Storage$_free(void* self);
+"#,
+            r#"void* __swift_bridge__$make_storage(void);"#,
+            r#"uint32_t __swift_bridge__$Storage$get(void* self, uint32_t key);"#,
+            r#"void __swift_bridge__$Storage$set(void* self, uint32_t key, uint32_t value);"#,
+        ])
+    }
+
+    #[test]
+    fn rust_trait_object() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift can pass a Rust trait object to Rust as a `Box<dyn Trait>`, `&dyn Trait`,
+/// `&mut dyn Trait` or `Option<Box<dyn Trait>>`.
+mod extern_rust_fn_rust_trait_object_args {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(protocol)]
+                    type Storage;
+
+                    fn get(&self, key: u32) -> u32;
+
+                    fn consume_storage(storage: Box<dyn Storage>);
+                    fn read_storage(storage: &dyn Storage);
+                    fn write_storage(storage: &mut dyn Storage);
+                    fn maybe_consume_storage(storage: Option<Box<dyn Storage>>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$consume_storage"]
+                pub extern "C" fn __swift_bridge__consume_storage(
+                    storage: *mut Box<dyn super::Storage>
+                ) {
+                    super::consume_storage(unsafe { *Box::from_raw(storage) })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$read_storage"]
+                pub extern "C" fn __swift_bridge__read_storage(
+                    storage: *const Box<dyn super::Storage>
+                ) {
+                    super::read_storage(unsafe { &**storage })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$write_storage"]
+                pub extern "C" fn __swift_bridge__write_storage(
+                    storage: *mut Box<dyn super::Storage>
+                ) {
+                    super::write_storage(unsafe { &mut **storage })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$maybe_consume_storage"]
+                pub extern "C" fn __swift_bridge__maybe_consume_storage(
+                    storage: *mut Box<dyn super::Storage>
+                ) {
+                    super::maybe_consume_storage(
+                        {
+                            let val = storage;
+                            if val.is_null() {
+                                None
+                            } else {
+                                Some(unsafe { *Box::from_raw(val) })
+                            }
+                        }
+                    )
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func consume_storage(_ storage: RustStorage) {
+    __swift_bridge__$consume_storage({ let val = storage; val.isOwned = false; return val.ptr }())
+}
+public func read_storage(_ storage: RustStorage) {
+    __swift_bridge__$read_storage(storage.ptr)
+}
+public func write_storage(_ storage: RustStorage) {
+    __swift_bridge__$write_storage(storage.ptr)
+}
+public func maybe_consume_storage(_ storage: Optional<RustStorage>) {
+    __swift_bridge__$maybe_consume_storage({ if let val = storage { val.isOwned = false; return val.ptr } else { return nil } }())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"void __swift_bridge__$consume_storage(void* storage);"#,
+            r#"void __swift_bridge__$read_storage(void* storage);"#,
+            r#"void __swift_bridge__$write_storage(void* storage);"#,
+            r#"void __swift_bridge__$maybe_consume_storage(void* storage);"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_rust_trait_object_args() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
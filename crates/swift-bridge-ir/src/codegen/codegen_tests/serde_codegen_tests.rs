@@ -0,0 +1,269 @@
+//! Tests for `#[swift_bridge(serde)]` shared structs and enums.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a `#[swift_bridge(serde)]` struct derives serde's traits on the Rust side and
+/// becomes a `Codable` struct that uses Swift types on the Swift side.
+mod serde_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(serde)]
+                struct Config {
+                    name: String,
+                    tags: Vec<String>,
+                    limits: HashMap<String, u32>,
+                    parent: Option<Box<Config>>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[derive(swift_bridge::serde_support::serde::Serialize, swift_bridge::serde_support::serde::Deserialize)]
+            #[serde(crate = "swift_bridge::serde_support::serde")]
+            pub struct Config {
+                pub name: String,
+                pub tags: Vec<String>,
+                pub limits: HashMap<String, u32>,
+                pub parent: Option<Box<Config> >
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct Config {
+    public var name: String
+    public var tags: [String]
+    public var limits: [String: UInt32]
+    public var parent: Optional<Config>
+
+    public init(name: String,tags: [String],limits: [String: UInt32],parent: Optional<Config>) {
+        self.name = name
+        self.tags = tags
+        self.limits = limits
+        self.parent = parent
+    }
+}
+extension Config: Codable {
+    enum CodingKeys: String, CodingKey {
+        case name = "name"
+        case tags = "tags"
+        case limits = "limits"
+        case parent = "parent"
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__ResultPtrAndPtr __swift_bridge__$Config$_to_json(void* this);
+struct __private__ResultPtrAndPtr __swift_bridge__$Config$_from_json(void* json);
+"#,
+        )
+    }
+
+    #[test]
+    fn serde_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[swift_bridge(serde)]` enum derives serde's traits on the Rust side and
+/// becomes a `Codable` enum on the Swift side.
+mod serde_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(serde)]
+                enum Shape {
+                    Empty,
+                    Polygon(Vec<f64>),
+                    Circle { radius: f64 },
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[derive(swift_bridge::serde_support::serde::Serialize, swift_bridge::serde_support::serde::Deserialize)]
+            #[serde(crate = "swift_bridge::serde_support::serde")]
+            pub enum Shape {
+                Empty,
+                Polygon(Vec<f64>),
+                Circle { radius: f64 }
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Shape {
+    case Empty
+    case Polygon([Double])
+    case Circle(radius: Double)
+}
+extension Shape: Codable {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__ResultPtrAndPtr __swift_bridge__$Shape$_to_json(void* this);
+struct __private__ResultPtrAndPtr __swift_bridge__$Shape$_from_json(void* json);
+"#,
+        )
+    }
+
+    #[test]
+    fn serde_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `#[swift_bridge(serde)]` types are passed over FFI as boxed Rust values, and that
+/// the Swift functions throw if a value can not be converted to or from JSON.
+mod serde_type_function_args_and_returns {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(serde)]
+                struct Config {
+                    name: String,
+                }
+
+                extern "Rust" {
+                    fn load_config() -> Config;
+                    fn save_config(config: Config);
+                    fn maybe_config(config: Option<Config>) -> Option<Config>;
+                    fn rename_config(config: Config, name: &str) -> Config;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Config$_to_json"]
+                pub extern "C" fn __swift_bridge__Config__to_json(
+                    this: *mut Config
+                ) -> swift_bridge::result::ResultPtrAndPtr {
+                    unsafe { swift_bridge::serde_support::to_json(this) }
+                }
+
+                #[export_name = "__swift_bridge__$Config$_from_json"]
+                pub extern "C" fn __swift_bridge__Config__from_json(
+                    json: *mut swift_bridge::string::RustString
+                ) -> swift_bridge::result::ResultPtrAndPtr {
+                    unsafe { swift_bridge::serde_support::from_json::<Config>(json) }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$load_config"]
+                pub extern "C" fn __swift_bridge__load_config() -> *mut Config {
+                    Box::into_raw(Box::new(super::load_config()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$save_config"]
+                pub extern "C" fn __swift_bridge__save_config(config: *mut Config) {
+                    super::save_config(unsafe { * Box::from_raw(config) })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$maybe_config"]
+                pub extern "C" fn __swift_bridge__maybe_config(
+                    config: *mut Config
+                ) -> *mut Config {
+                    if let Some(val) = super::maybe_config(
+                        {
+                            let val = config;
+                            if val.is_null() {
+                                None
+                            } else {
+                                Some(unsafe { * Box::from_raw(val) })
+                            }
+                        }
+                    ) {
+                        Box::into_raw(Box::new(val))
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func load_config() throws -> Config {
+    try serdeFromRust(Config.self, __swift_bridge__$load_config(), __swift_bridge__$Config$_to_json)
+}
+public func save_config(_ config: Config) throws {
+    __swift_bridge__$save_config(try serdeIntoRust(config, __swift_bridge__$Config$_from_json))
+}
+public func maybe_config(_ config: Optional<Config>) throws -> Optional<Config> {
+    try optionalSerdeFromRust(Config.self, __swift_bridge__$maybe_config(try optionalSerdeIntoRust(config, __swift_bridge__$Config$_from_json)), __swift_bridge__$Config$_to_json)
+}
+public func rename_config<GenericToRustStr: ToRustStr>(_ config: Config, _ name: GenericToRustStr) throws -> Config {
+    return try name.toRustStr({ nameAsRustStr in
+        try serdeFromRust(Config.self, __swift_bridge__$rename_config(try serdeIntoRust(config, __swift_bridge__$Config$_from_json), nameAsRustStr), __swift_bridge__$Config$_to_json)
+    })
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"struct __private__ResultPtrAndPtr __swift_bridge__$Config$_to_json(void* this);"#,
+            r#"struct __private__ResultPtrAndPtr __swift_bridge__$Config$_from_json(void* json);"#,
+            r#"void* __swift_bridge__$load_config(void);"#,
+            r#"void __swift_bridge__$save_config(void* config);"#,
+            r#"void* __swift_bridge__$maybe_config(void* config);"#,
+        ])
+    }
+
+    #[test]
+    fn serde_type_function_args_and_returns() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -263,6 +263,113 @@ func __swift_bridge__some_function () -> UnsafeMutableRawPointer {
     }
 }
 
+/// Test code generation for Swift function that returns a &str.
+/// The returned `RustStr` borrows from Rust owned memory, such as the `&str` argument, so the
+/// returned `&str` shares the lifetime of the arguments.
+mod extern_swift_func_returns_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: &str) -> &str;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (arg: &str) -> &str {
+                    unsafe {
+                        __swift_bridge__some_function(swift_bridge::string::RustStr::from_str(arg))
+                    }.to_str()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: swift_bridge::string::RustStr) -> swift_bridge::string::RustStr;
+            },
+        ])
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: RustStr) -> RustStr {
+    some_function(arg: arg)
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_func_returns_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that returns a &str with nothing to borrow from.
+/// Swift returns a `String` whose bytes it keeps alive until Rust drops the `SwiftStr`.
+mod extern_swift_func_returns_swift_owned_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function () -> &str;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function () -> swift_bridge::string::SwiftStr {
+                    unsafe { __swift_bridge__some_function() }.into_swift_str()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function() -> swift_bridge::string::SwiftStrFfi;
+            },
+        ])
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function () -> __private__SwiftStr {
+    some_function().intoSwiftStr()
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_func_returns_swift_owned_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Swift function that takes an owned String argument.
 mod extern_swift_func_takes_string_arg {
     use super::*;
@@ -0,0 +1,250 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Rust trait and a Swift protocol for a `#[swift_bridge(protocol)]`
+/// Swift type, along with a Rust struct that implements the trait by calling into Swift.
+mod swift_protocol {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type Renderer;
+
+                    fn render(&self, width: u32) -> String;
+                    fn resize(&mut self, scale: f32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub trait Renderer {
+                fn render(&self, width: u32) -> String;
+                fn resize(&mut self, scale: f32);
+            }
+
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct __swift_bridge__Renderer(*mut std::ffi::c_void);
+
+            impl Renderer for __swift_bridge__Renderer {
+                fn render(&self, width: u32) -> String {
+                    unsafe {
+                        Box::from_raw(unsafe {
+                            __swift_bridge__Renderer_render(swift_bridge::PointerToSwiftType(self.0), width)
+                        })
+                        .0
+                    }
+                }
+
+                fn resize(&mut self, scale: f32) {
+                    unsafe { __swift_bridge__Renderer_resize(swift_bridge::PointerToSwiftType(self.0), scale) }
+                }
+            }
+
+            impl Drop for __swift_bridge__Renderer {
+                fn drop (&mut self) {
+                    unsafe { __swift_bridge__Renderer__free(self.0) }
+                }
+            }
+
+            #[allow(improper_ctypes)]
+            extern "C" {
+                #[link_name = "__swift_bridge__$Renderer$render"]
+                fn __swift_bridge__Renderer_render(this: swift_bridge::PointerToSwiftType, width: u32) -> *mut swift_bridge::string::RustString;
+
+                #[link_name = "__swift_bridge__$Renderer$resize"]
+                fn __swift_bridge__Renderer_resize(this: swift_bridge::PointerToSwiftType, scale: f32);
+
+                #[link_name = "__swift_bridge__$Renderer$_free"]
+                fn __swift_bridge__Renderer__free (this: *mut std::ffi::c_void);
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$Renderer$render")
+func __swift_bridge__Renderer_render (_ this: UnsafeMutableRawPointer, _ width: UInt32) -> UnsafeMutableRawPointer {
+    { let rustString = (Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! Renderer).render(width: width).intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+
+@_cdecl("__swift_bridge__$Renderer$resize")
+func __swift_bridge__Renderer_resize (_ this: UnsafeMutableRawPointer, _ scale: Float) {
+    (Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! Renderer).resize(scale: scale)
+}
+"#,
+            r#"
+public protocol Renderer: AnyObject {
+    func render(width: UInt32) -> String
+    func resize(scale: Float)
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$Renderer$_free")
+func __swift_bridge__Renderer__free (ptr: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<AnyObject>.fromOpaque(ptr).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn swift_protocol() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift can pass an object that conforms to a Swift protocol to Rust as a
+/// `Box<dyn Trait>`.
+mod extern_rust_fn_boxed_swift_protocol_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn set_renderer(renderer: Box<dyn Renderer>);
+                    fn maybe_set_renderer(renderer: Option<Box<dyn Renderer>>);
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type Renderer;
+
+                    fn render(&self) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$set_renderer"]
+                pub extern "C" fn __swift_bridge__set_renderer(renderer: *mut std::ffi::c_void) {
+                    super::set_renderer(Box::new(__swift_bridge__Renderer(renderer)) as Box<dyn Renderer>)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$maybe_set_renderer"]
+                pub extern "C" fn __swift_bridge__maybe_set_renderer(renderer: *mut std::ffi::c_void) {
+                    super::maybe_set_renderer(
+                        {
+                            let val = renderer;
+                            if val.is_null() {
+                                None
+                            } else {
+                                Some(Box::new(__swift_bridge__Renderer(val)) as Box<dyn Renderer>)
+                            }
+                        }
+                    )
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func set_renderer(_ renderer: Renderer) {
+    __swift_bridge__$set_renderer(Unmanaged.passRetained(renderer as AnyObject).toOpaque())
+}
+"#,
+            r#"
+public func maybe_set_renderer(_ renderer: Optional<Renderer>) {
+    __swift_bridge__$maybe_set_renderer({ if let val = renderer { return Unmanaged.passRetained(val as AnyObject).toOpaque() } else { return nil } }())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"void __swift_bridge__$set_renderer(void* renderer);"#,
+            r#"void __swift_bridge__$maybe_set_renderer(void* renderer);"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_boxed_swift_protocol_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Swift" function can return an object that conforms to a Swift protocol
+/// to Rust as a `Box<dyn Trait>`.
+mod extern_swift_fn_returns_boxed_swift_protocol {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type Renderer;
+
+                    fn render(&self) -> u32;
+
+                    fn make_renderer() -> Box<dyn Renderer>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn make_renderer() -> Box<dyn Renderer> {
+                    Box::new(__swift_bridge__Renderer(unsafe { __swift_bridge__make_renderer() })) as Box<dyn Renderer>
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$make_renderer"]
+                fn __swift_bridge__make_renderer() -> *mut std::ffi::c_void;
+            },
+        ])
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$make_renderer")
+func __swift_bridge__make_renderer () -> UnsafeMutableRawPointer {
+    Unmanaged.passRetained(make_renderer() as AnyObject).toOpaque()
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_fn_returns_boxed_swift_protocol() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
@@ -702,3 +702,161 @@ struct __swift_bridge__$Option$SomeStruct __swift_bridge__$some_function(struct
         .test();
     }
 }
+
+/// Verify that we generate a separate FFI representation for each instantiation of a generic
+/// struct, and that Swift gets a single generic struct.
+mod generic_struct_instantiations {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point<T> {
+                    x: T,
+                    y: T,
+                    label: u8
+                }
+                struct Point<f32>;
+                struct Point<f64>;
+
+                extern "Rust" {
+                    fn scale_point(point: Point<f32>) -> Point<f64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct Point<T> {
+                    pub x: T,
+                    pub y: T,
+                    pub label: u8
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Point_f32 {
+                    x: f32,
+                    y: f32,
+                    label: u8
+                }
+
+                impl swift_bridge::SharedStruct for Point<f32> {
+                    type FfiRepr = __swift_bridge__Point_f32;
+                }
+
+                impl Point<f32> {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_ffi_repr(self) -> __swift_bridge__Point_f32 {
+                        { let val = self; __swift_bridge__Point_f32 { x: val.x, y: val.y, label: val.label } }
+                    }
+                }
+
+                impl __swift_bridge__Point_f32 {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> Point<f32> {
+                        { let val = self; Point { x: val.x, y: val.y, label: val.label } }
+                    }
+                }
+
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Option_Point_f32 {
+                    is_some: bool,
+                    val: std::mem::MaybeUninit<__swift_bridge__Point_f32>,
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Point_f64 {
+                    x: f64,
+                    y: f64,
+                    label: u8
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$scale_point"]
+                pub extern "C" fn __swift_bridge__scale_point(
+                    point: __swift_bridge__Point_f32
+                ) -> __swift_bridge__Point_f64 {
+                    super::scale_point(point.into_rust_repr()).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Point<T> {
+    public var x: T
+    public var y: T
+    public var label: UInt8
+
+    public init(x: T,y: T,label: UInt8) {
+        self.x = x
+        self.y = y
+        self.label = label
+    }
+}
+"#,
+            r#"
+extension Point where T == Float {
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$Point$f32 {
+        { let val = self; return __swift_bridge__$Point$f32(x: val.x, y: val.y, label: val.label); }()
+    }
+}
+extension __swift_bridge__$Point$f32 {
+    @inline(__always)
+    func intoSwiftRepr() -> Point<Float> {
+        { let val = self; return Point(x: val.x, y: val.y, label: val.label); }()
+    }
+}
+extension __swift_bridge__$Option$Point$f32 {
+    @inline(__always)
+    func intoSwiftRepr() -> Optional<Point<Float>> {
+"#,
+            r#"
+extension Point where T == Double {
+"#,
+            r#"
+public func scale_point(_ point: Point<Float>) -> Point<Double> {
+    __swift_bridge__$scale_point(point.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Point$f32 { float x; float y; uint8_t label; } __swift_bridge__$Point$f32;
+typedef struct __swift_bridge__$Option$Point$f32 { bool is_some; __swift_bridge__$Point$f32 val; } __swift_bridge__$Option$Point$f32;
+typedef struct __swift_bridge__$Point$f64 { double x; double y; uint8_t label; } __swift_bridge__$Point$f64;
+"#,
+            r#"
+struct __swift_bridge__$Point$f64 __swift_bridge__$scale_point(struct __swift_bridge__$Point$f32 point);
+"#,
+        ])
+    }
+
+    #[test]
+    fn generic_struct_instantiations() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -283,6 +283,590 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Verify that we emit Rust, Swift and C header code that allows an opaque Rust Copy type be used
+/// within a Vec<T>.
+mod extern_rust_copy_type_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4))]
+                    type SomeCopyType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$new"]
+                pub extern "C" fn _new() -> *mut Vec<super::SomeCopyType> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$drop"]
+                pub extern "C" fn _drop(vec: *mut Vec<super::SomeCopyType>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$len"]
+                pub extern "C" fn _len(vec: *const Vec<super::SomeCopyType>) -> usize {
+                    unsafe { &*vec }.len()
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$get"]
+                pub extern "C" fn _get(vec: *const Vec<super::SomeCopyType>, index: usize) -> __swift_bridge__Option_SomeCopyType {
+                    let vec = unsafe { &*vec };
+                    if let Some(val) = vec.get(index) {
+                        __swift_bridge__Option_SomeCopyType {
+                            is_some: true,
+                            val: std::mem::MaybeUninit::new(__swift_bridge__SomeCopyType::from_rust_repr(*val))
+                        }
+                    } else {
+                        __swift_bridge__Option_SomeCopyType {
+                            is_some: false,
+                            val: std::mem::MaybeUninit::uninit()
+                        }
+                    }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$get_mut"]
+                pub extern "C" fn _get_mut(vec: *mut Vec<super::SomeCopyType>, index: usize) -> __swift_bridge__Option_SomeCopyType {
+                    let vec = unsafe { &mut *vec };
+                    if let Some(val) = vec.get_mut(index) {
+                        __swift_bridge__Option_SomeCopyType {
+                            is_some: true,
+                            val: std::mem::MaybeUninit::new(__swift_bridge__SomeCopyType::from_rust_repr(*val))
+                        }
+                    } else {
+                        __swift_bridge__Option_SomeCopyType {
+                            is_some: false,
+                            val: std::mem::MaybeUninit::uninit()
+                        }
+                    }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$push"]
+                pub extern "C" fn _push(vec: *mut Vec<super::SomeCopyType>, val: __swift_bridge__SomeCopyType) {
+                    unsafe { &mut *vec }.push(val.into_rust_repr())
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$pop"]
+                pub extern "C" fn _pop(vec: *mut Vec<super::SomeCopyType>) -> __swift_bridge__Option_SomeCopyType {
+                    let vec = unsafe { &mut *vec };
+                    if let Some(val) = vec.pop() {
+                        __swift_bridge__Option_SomeCopyType {
+                            is_some: true,
+                            val: std::mem::MaybeUninit::new(__swift_bridge__SomeCopyType::from_rust_repr(val))
+                        }
+                    } else {
+                        __swift_bridge__Option_SomeCopyType {
+                            is_some: false,
+                            val: std::mem::MaybeUninit::uninit()
+                        }
+                    }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeCopyType$as_ptr"]
+                pub extern "C" fn _as_ptr(vec: *const Vec<super::SomeCopyType>) -> *const super::SomeCopyType {
+                    unsafe { & *vec }.as_ptr()
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeCopyType: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_SomeCopyType$new()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_SomeCopyType$drop(vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: SomeCopyType) {
+        __swift_bridge__$Vec_SomeCopyType$push(vecPtr, value.intoFfiRepr())
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let val = __swift_bridge__$Vec_SomeCopyType$pop(vecPtr)
+        if val.is_some {
+            return val.val.intoSwiftRepr()
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<SomeCopyType> {
+        let val = __swift_bridge__$Vec_SomeCopyType$get(vecPtr, index)
+        if val.is_some {
+            return val.val.intoSwiftRepr()
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<SomeCopyType> {
+        let val = __swift_bridge__$Vec_SomeCopyType$get_mut(vecPtr, index)
+        if val.is_some {
+            return val.val.intoSwiftRepr()
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<SomeCopyType> {
+        UnsafePointer<SomeCopyType>(OpaquePointer(__swift_bridge__$Vec_SomeCopyType$as_ptr(vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_SomeCopyType$len(vecPtr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Vec_SomeCopyType$new(void);
+void __swift_bridge__$Vec_SomeCopyType$drop(void* vec_ptr);
+void __swift_bridge__$Vec_SomeCopyType$push(void* vec_ptr, __swift_bridge__$SomeCopyType item);
+__swift_bridge__$Option$SomeCopyType __swift_bridge__$Vec_SomeCopyType$pop(void* vec_ptr);
+__swift_bridge__$Option$SomeCopyType __swift_bridge__$Vec_SomeCopyType$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option$SomeCopyType __swift_bridge__$Vec_SomeCopyType$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_SomeCopyType$len(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeCopyType$as_ptr(void* vec_ptr);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_copy_type_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we emit Rust, Swift and C header code that allows a concrete instantiation of a
+/// generic opaque Rust type be used within a Vec<T>.
+mod extern_rust_generic_type_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A>;
+                    type SomeType<u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$u32$new"]
+                pub extern "C" fn _new() -> *mut Vec<super::SomeType<u32> > {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$u32$push"]
+                pub extern "C" fn _push(vec: *mut Vec<super::SomeType<u32> >, val: *mut super::SomeType<u32>) {
+                    unsafe { &mut *vec }.push(unsafe { *Box::from_raw(val) })
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$u32$as_ptr"]
+                pub extern "C" fn _as_ptr(vec: *const Vec<super::SomeType<u32> >) -> *const super::SomeType<u32> {
+                    unsafe { & *vec }.as_ptr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        if A.self == UInt32.self {
+            return __swift_bridge__$Vec_SomeType$u32$new()
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        if A.self == UInt32.self {
+            return __swift_bridge__$Vec_SomeType$u32$drop(vecPtr)
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: SomeType<A>) {
+        if A.self == UInt32.self {
+            return __swift_bridge__$Vec_SomeType$u32$push(vecPtr, {value.isOwned = false; return value.ptr;}())
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        if A.self == UInt32.self {
+            let pointer = __swift_bridge__$Vec_SomeType$u32$pop(vecPtr)
+            if pointer == nil {
+                return nil
+            } else {
+                return (SomeType<A>(ptr: pointer!) as! Self)
+            }
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<SomeTypeRef<A>> {
+        if A.self == UInt32.self {
+            let pointer = __swift_bridge__$Vec_SomeType$u32$get(vecPtr, index)
+            if pointer == nil {
+                return nil
+            } else {
+                return SomeTypeRef<A>(ptr: pointer!)
+            }
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<SomeTypeRefMut<A>> {
+        if A.self == UInt32.self {
+            let pointer = __swift_bridge__$Vec_SomeType$u32$get_mut(vecPtr, index)
+            if pointer == nil {
+                return nil
+            } else {
+                return SomeTypeRefMut<A>(ptr: pointer!)
+            }
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<SomeTypeRef<A>> {
+        if A.self == UInt32.self {
+            return UnsafePointer<SomeTypeRef<A>>(OpaquePointer(__swift_bridge__$Vec_SomeType$u32$as_ptr(vecPtr)))
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        if A.self == UInt32.self {
+            return __swift_bridge__$Vec_SomeType$u32$len(vecPtr)
+        }
+        fatalError("RustVec<SomeType<A>> is not supported for this generic instantiation")
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Vec_SomeType$u32$new(void);
+void __swift_bridge__$Vec_SomeType$u32$drop(void* vec_ptr);
+void __swift_bridge__$Vec_SomeType$u32$push(void* vec_ptr, void* item_ptr);
+void* __swift_bridge__$Vec_SomeType$u32$pop(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeType$u32$get(void* vec_ptr, uintptr_t index);
+void* __swift_bridge__$Vec_SomeType$u32$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_SomeType$u32$len(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeType$u32$as_ptr(void* vec_ptr);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_generic_type_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that all of a module's instantiations of a generic opaque Rust type share a single
+/// Vectorizable conformance, since Swift rejects conflicting conformances such as
+/// `extension SomeType: Vectorizable where A == UInt32` and
+/// `extension SomeType: Vectorizable where A == UInt64`.
+mod extern_rust_generic_type_many_instantiations_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A, B>;
+                    type SomeType<u32, u8>;
+                    type SomeType<u64, u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Vec_SomeType$u32$u8$new"]
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Vec_SomeType$u64$u8$new"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        if A.self == UInt32.self && B.self == UInt8.self {
+            return __swift_bridge__$Vec_SomeType$u32$u8$new()
+        }
+        if A.self == UInt64.self && B.self == UInt8.self {
+            return __swift_bridge__$Vec_SomeType$u64$u8$new()
+        }
+        fatalError("RustVec<SomeType<A, B>> is not supported for this generic instantiation")
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$Vec_SomeType$u32$u8$new(void);",
+            "void* __swift_bridge__$Vec_SomeType$u64$u8$new(void);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_generic_type_many_instantiations_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we emit Rust, Swift and C header code that allows an extern "Swift" type be used
+/// within a Vec<T>, as long as a Vec<T> of the type is used somewhere in the module.
+mod extern_swift_type_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Vec<SomeSwiftType>) -> Vec<SomeSwiftType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeSwiftType$new"]
+                pub extern "C" fn _new() -> *mut Vec<SomeSwiftType> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeSwiftType$push"]
+                pub extern "C" fn _push(vec: *mut Vec<SomeSwiftType>, val: *mut std::ffi::c_void) {
+                    unsafe { &mut *vec }.push(SomeSwiftType(val))
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: *mut Vec<SomeSwiftType>
+                ) -> *mut Vec<SomeSwiftType> {
+                    Box::into_raw(Box::new(super::some_function(unsafe { * Box::from_raw(arg) })))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeSwiftType: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_SomeSwiftType$new()
+    }
+"#,
+            r#"
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: SomeSwiftType) {
+        __swift_bridge__$Vec_SomeSwiftType$push(vecPtr, Unmanaged.passRetained(value).toOpaque())
+    }
+"#,
+            r#"
+func some_function(_ arg: RustVec<SomeSwiftType>) -> RustVec<SomeSwiftType> {
+    RustVec(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$Vec_SomeSwiftType$new(void);
+void __swift_bridge__$Vec_SomeSwiftType$drop(void* vec_ptr);
+void __swift_bridge__$Vec_SomeSwiftType$push(void* vec_ptr, void* item_ptr);
+void* __swift_bridge__$Vec_SomeSwiftType$pop(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeSwiftType$get(void* vec_ptr, uintptr_t index);
+void* __swift_bridge__$Vec_SomeSwiftType$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_SomeSwiftType$len(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeSwiftType$as_ptr(void* vec_ptr);
+"#,
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_swift_type_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not emit Vec<T> support for an extern "Swift" type that is never used
+/// within a Vec<T>.
+mod extern_swift_type_without_vec_usage_no_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            "__swift_bridge__$Vec_SomeSwiftType$new"
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Vectorizable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_type_without_vec_usage_no_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we emit Vec<T> support for an extern "Swift" type that is only used within a
+/// Vec<T> that is nested inside of another type.
+mod extern_swift_type_nested_vec_usage {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Option<Vec<SomeSwiftType>>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Vec_SomeSwiftType$new"]
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim("extension SomeSwiftType: Vectorizable {")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim("void* __swift_bridge__$Vec_SomeSwiftType$new(void);")
+    }
+
+    #[test]
+    fn extern_swift_type_nested_vec_usage() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we emit Rust, Swift and C header code that allows a transparent enum be used
 /// within a Vec<T>.
 mod transparent_enum_vec_support {
@@ -545,6 +1129,329 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Verify that we emit Rust, Swift and C header code that allows a transparent struct be used
+/// within a Vec<T>.
+mod transparent_struct_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct SomeStruct {
+                    field: u8
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeStruct$get"]
+                pub extern "C" fn _get(vec: *const Vec<SomeStruct>, index: usize) -> __swift_bridge__Option_SomeStruct {
+                    let vec = unsafe { &*vec };
+                    let val = vec.get(index).cloned();
+                    __swift_bridge__Option_SomeStruct::from_rust_repr(val)
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeStruct$push"]
+                pub extern "C" fn _push(vec: *mut Vec<SomeStruct>, val: __swift_bridge__SomeStruct) {
+                    unsafe { &mut *vec }.push(val.into_rust_repr())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_SomeStruct$new()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_SomeStruct$drop(vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {
+        __swift_bridge__$Vec_SomeStruct$push(vecPtr, value.intoFfiRepr())
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_SomeStruct$pop(vecPtr)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_SomeStruct$get(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_SomeStruct$get_mut(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {
+        fatalError("RustVec<SomeStruct>.as_ptr() is unsupported since the Rust struct's layout differs from the Swift struct's layout")
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_SomeStruct$len(vecPtr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Vec_SomeStruct$new(void);
+void __swift_bridge__$Vec_SomeStruct$drop(void* vec_ptr);
+void __swift_bridge__$Vec_SomeStruct$push(void* vec_ptr, __swift_bridge__$SomeStruct item);
+__swift_bridge__$Option$SomeStruct __swift_bridge__$Vec_SomeStruct$pop(void* vec_ptr);
+__swift_bridge__$Option$SomeStruct __swift_bridge__$Vec_SomeStruct$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option$SomeStruct __swift_bridge__$Vec_SomeStruct$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_SomeStruct$len(void* vec_ptr);
+"#,
+        )
+    }
+
+    #[test]
+    fn transparent_struct_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not emit Vec<T> support for a transparent struct that does not implement
+/// Clone, since Swift gets a copy of the struct when reading from the Vec.
+mod transparent_struct_without_clone_no_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            "__swift_bridge__$Vec_SomeStruct$new"
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Vectorizable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("__swift_bridge__$Vec_SomeStruct")
+    }
+
+    #[test]
+    fn transparent_struct_without_clone_no_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a Vec<T> where T is a
+/// transparent struct.
+mod extern_rust_fn_vec_of_transparent_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct SomeStruct {
+                    field: u8
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Vec<SomeStruct>) -> Vec<SomeStruct>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<SomeStruct>
+            ) -> *mut Vec<SomeStruct> {
+                Box::into_raw(Box::new(super::some_function(unsafe { * Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustVec<SomeStruct>) -> RustVec<SomeStruct> {
+    RustVec(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_transparent_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a Vec<String>.
+mod extern_rust_fn_vec_of_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<String>) -> Vec<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<String>
+            ) -> *mut Vec<String> {
+                Box::into_raw(Box::new(super::some_function(unsafe { * Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustVec<RustString>) -> RustVec<RustString> {
+    RustVec(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a Vec<&str>.
+/// Swift can't borrow Rust's strings, so they get copied into a `RustVec<RustString>`.
+mod extern_rust_fn_vec_of_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<&str>) -> Vec<&'static str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<String>
+            ) -> *mut Vec<String> {
+                Box::into_raw(Box::new(
+                    super::some_function(
+                        unsafe { Box::from_raw(arg) }.iter().map(|s| s.as_str()).collect::<Vec<&str>>()
+                    ).into_iter().map(|s| s.to_string()).collect::<Vec<String>>()
+                ))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustVec<RustString>) -> RustVec<RustString> {
+    RustVec(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns a Vec<T> where T is a primitive Rust type.
 mod extern_swift_fn_return_vec_of_primitive_rust_type {
     use super::*;
@@ -5,7 +5,7 @@ use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, StdLibType, S
 use crate::codegen::CodegenConfig;
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::SwiftBridgeModule;
 use std::collections::{BTreeSet, HashSet};
 use syn::{FnArg, ReturnType};
 
@@ -54,6 +54,19 @@ impl SwiftBridgeModule {
         };
 
         for ty in self.types.types() {
+            // A `#[swift_bridge(serde)]` type is passed over FFI as a pointer to the boxed value.
+            if let Some(serde_ty) = ty.to_serde_type() {
+                header += &format!(
+                    "struct __private__ResultPtrAndPtr {}(void* this);\n",
+                    serde_ty.ffi_to_json_fn_name()
+                );
+                header += &format!(
+                    "struct __private__ResultPtrAndPtr {}(void* json);\n",
+                    serde_ty.ffi_from_json_fn_name()
+                );
+                continue;
+            }
+
             match ty {
                 TypeDeclaration::Shared(ty) => match ty {
                     SharedTypeDeclaration::Struct(ty_struct) => {
@@ -61,6 +74,11 @@ impl SwiftBridgeModule {
                             continue;
                         }
 
+                        // Each instantiation of a generic struct gets its own C struct.
+                        if ty_struct.generics.is_generic_declaration() {
+                            continue;
+                        }
+
                         let name = ty_struct.swift_name_string();
                         let ffi_name = ty_struct.ffi_name_string();
                         let option_ffi_name = ty_struct.ffi_option_name_string();
@@ -128,13 +146,19 @@ impl SwiftBridgeModule {
                             "".to_string()
                         };
 
+                        let maybe_vec_support =
+                            if ty_struct.derives.clone && ty_struct.generics.concrete.is_empty() {
+                                bookkeeping.includes.insert("stdint.h");
+                                vec_transparent_struct_c_support(&name)
+                            } else {
+                                "".to_string()
+                            };
+
                         let ty_decl = format!(
-                            r#"typedef struct {prefix}${name} {{{maybe_fields}}} {prefix}${name};
-typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};"#,
-                            prefix = SWIFT_BRIDGE_PREFIX,
+                            r#"typedef struct {ffi_name} {{{maybe_fields}}} {ffi_name};
+typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};{maybe_vec_support}"#,
                             ffi_name = ffi_name,
                             option_ffi_name = option_ffi_name,
-                            name = name,
                             maybe_fields = maybe_fields
                         );
 
@@ -260,6 +284,13 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                 },
                 TypeDeclaration::Opaque(ty) => {
                     if ty.host_lang.is_swift() {
+                        // Swift calls into Rust to work with a `Vec<SomeSwiftType>`. The
+                        // functions have the same signatures as the ones for opaque Rust types.
+                        if self.vec_of_opaque_swift_type_is_used(&ty.to_string()) {
+                            bookkeeping.includes.insert("stdint.h");
+                            header += &vec_opaque_rust_type_c_support(&ty.to_string());
+                            header += "\n";
+                        }
                         continue;
                     }
 
@@ -287,6 +318,29 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += &equal_ty;
                         header += "\n";
                     }
+                    if ty.attributes.clone {
+                        let ty_name = ty.ty_name_ident();
+                        header +=
+                            &format!("void* __swift_bridge__${}$_clone(void* self);\n", ty_name);
+                    }
+                    if ty.attributes.comparable {
+                        let ty_name = ty.ty_name_ident();
+                        bookkeeping.includes.insert("stdbool.h");
+                        header += &format!(
+                            "bool __swift_bridge__${}$_less_than(void* lhs, void* rhs);\n",
+                            ty_name
+                        );
+                    }
+                    if ty.attributes.display {
+                        let ty_name = ty.ty_name_ident();
+                        header +=
+                            &format!("void* __swift_bridge__${}$_display(void* self);\n", ty_name);
+                    }
+                    if ty.attributes.debug {
+                        let ty_name = ty.ty_name_ident();
+                        header +=
+                            &format!("void* __swift_bridge__${}$_debug(void* self);\n", ty_name);
+                    }
                     let ty_name = ty.to_string();
 
                     if let Some(copy) = ty.attributes.copy {
@@ -327,15 +381,28 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += "\n";
                     }
 
-                    // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
-                    //  make them pass.
-                    // TODO: Support Vec<GenericOpaqueRustType
-                    if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
-                        let vec_functions = vec_opaque_rust_type_c_support(&ty_name);
-
-                        header += &vec_functions;
-                        header += "\n";
+                    // There is no `Vec<dyn SomeTrait>`.
+                    if ty.attributes.protocol {
+                        continue;
                     }
+
+                    let vec_ty_name = format!(
+                        "{}{}",
+                        ty_name,
+                        ty.generics.dollar_prefixed_generics_string()
+                    );
+                    let vec_functions = if ty.attributes.copy.is_some() {
+                        vec_opaque_copy_type_c_support(
+                            &vec_ty_name,
+                            &ty.ffi_copy_repr_string(),
+                            &ty.ffi_option_copy_repr_string(),
+                        )
+                    } else {
+                        vec_opaque_rust_type_c_support(&vec_ty_name)
+                    };
+
+                    header += &vec_functions;
+                    header += "\n";
                 }
             }
         }
@@ -359,6 +426,11 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                 continue;
             }
 
+            for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(&self.types) {
+                let ffi_struct_name = func.call_boxed_fn_link_name(idx);
+                header += &boxed_fn.swift_closure_c_typedefs(&ffi_struct_name, &self.types);
+            }
+
             header += &declare_func(&func, &mut bookkeeping, &self.types);
         }
 
@@ -404,6 +476,24 @@ void* __swift_bridge__$Vec_{ty_name}$as_ptr(void* vec_ptr);
     )
 }
 
+fn vec_opaque_copy_type_c_support(ty_name: &str, ffi_repr: &str, ffi_option_repr: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_{ty_name}$new(void);
+void __swift_bridge__$Vec_{ty_name}$drop(void* vec_ptr);
+void __swift_bridge__$Vec_{ty_name}$push(void* vec_ptr, {ffi_repr} item);
+{ffi_option_repr} __swift_bridge__$Vec_{ty_name}$pop(void* vec_ptr);
+{ffi_option_repr} __swift_bridge__$Vec_{ty_name}$get(void* vec_ptr, uintptr_t index);
+{ffi_option_repr} __swift_bridge__$Vec_{ty_name}$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_{ty_name}$len(void* vec_ptr);
+void* __swift_bridge__$Vec_{ty_name}$as_ptr(void* vec_ptr);
+"#,
+        ty_name = ty_name,
+        ffi_repr = ffi_repr,
+        ffi_option_repr = ffi_option_repr
+    )
+}
+
 fn vec_transparent_enum_c_support(enum_name: &str) -> String {
     format!(
         r#"
@@ -420,6 +510,21 @@ void* __swift_bridge__$Vec_{enum_name}$as_ptr(void* vec_ptr);
     )
 }
 
+fn vec_transparent_struct_c_support(struct_name: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_{struct_name}$new(void);
+void __swift_bridge__$Vec_{struct_name}$drop(void* vec_ptr);
+void __swift_bridge__$Vec_{struct_name}$push(void* vec_ptr, __swift_bridge__${struct_name} item);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$pop(void* vec_ptr);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_{struct_name}$len(void* vec_ptr);
+"#,
+        struct_name = struct_name
+    )
+}
+
 fn push_custom_type_declarations(
     custom_type_declaration: &CFfiStruct,
     c_ffi_struct_bookkeeping: &mut CFfiStructDeclarationBookkeeping,
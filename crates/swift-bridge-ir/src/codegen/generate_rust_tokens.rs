@@ -6,13 +6,17 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
 
+use self::vec::vec_of_opaque_copy_type::generate_vec_of_opaque_copy_type_functions;
 use self::vec::vec_of_opaque_rust_type::generate_vec_of_opaque_rust_type_functions;
+use self::vec::vec_of_opaque_swift_type::generate_vec_of_opaque_swift_type_functions;
 use crate::bridge_module_attributes::CfgAttr;
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
 
+mod serde_type;
 mod shared_enum;
 mod shared_struct;
+mod swift_protocol;
 mod vec;
 
 impl ToTokens for SwiftBridgeModule {
@@ -42,8 +46,22 @@ impl ToTokens for SwiftBridgeModule {
                     ));
                 }
                 HostLang::Swift => {
-                    let tokens = func
-                        .to_rust_fn_that_calls_a_swift_extern(&self.swift_bridge_path, &self.types);
+                    let is_protocol_method = matches!(
+                        func.associated_type.as_ref(),
+                        Some(TypeDeclaration::Opaque(ty)) if ty.attributes.protocol
+                    );
+
+                    let tokens = if is_protocol_method {
+                        func.to_rust_trait_method_that_calls_a_swift_extern(
+                            &self.swift_bridge_path,
+                            &self.types,
+                        )
+                    } else {
+                        func.to_rust_fn_that_calls_a_swift_extern(
+                            &self.swift_bridge_path,
+                            &self.types,
+                        )
+                    };
                     callbacks_support
                         .push(func.callbacks_support(&self.swift_bridge_path, &self.types));
 
@@ -99,6 +117,18 @@ impl ToTokens for SwiftBridgeModule {
                     let ty_name = &ty.ty;
 
                     match ty.host_lang {
+                        HostLang::Rust if ty.attributes.protocol => {
+                            let ffi_repr = ty.ffi_repr_type_tokens();
+
+                            let free = quote! {
+                                #[export_name = #link_name]
+                                pub extern "C" fn #free_mem_func_name (this: #ffi_repr) {
+                                    let this = unsafe { Box::from_raw(this) };
+                                    drop(this);
+                                }
+                            };
+                            extern_rust_fn_tokens.push(free);
+                        }
                         HostLang::Rust => {
                             if ty.attributes.hashable {
                                 let export_name = format!("__swift_bridge__${}$_hash", ty_name);
@@ -138,6 +168,81 @@ impl ToTokens for SwiftBridgeModule {
                                 };
                                 extern_rust_fn_tokens.push(tokens);
                             }
+                            if ty.attributes.clone {
+                                let export_name = format!("__swift_bridge__${}$_clone", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__clone", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut super::#ty_name {
+                                        Box::into_raw(Box::new(
+                                            std::clone::Clone::clone(unsafe { &*this })
+                                        ))
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.comparable {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_less_than", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__less_than", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        lhs: *const super::#ty_name,
+                                        rhs: *const super::#ty_name
+                                    ) -> bool {
+                                        unsafe {
+                                            std::cmp::PartialOrd::partial_cmp(&*lhs, &*rhs)
+                                                == Some(std::cmp::Ordering::Less)
+                                        }
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.display {
+                                let export_name = format!("__swift_bridge__${}$_display", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__display", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #swift_bridge_path::string::RustString(
+                                            format!("{}", unsafe { &*this })
+                                        ).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.debug {
+                                let export_name = format!("__swift_bridge__${}$_debug", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__debug", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #swift_bridge_path::string::RustString(
+                                            format!("{:?}", unsafe { &*this })
+                                        ).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
                             if let Some(copy) = ty.attributes.copy {
                                 let size = copy.size_bytes;
 
@@ -206,17 +311,31 @@ impl ToTokens for SwiftBridgeModule {
 
                                     extern_rust_fn_tokens.push(free);
 
-                                    // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
-                                    //  make them pass.
-                                    // TODO: Support Vec<GenericOpaqueRustType
-                                    if ty.generics.len() == 0 {
-                                        let vec_functions =
-                                            generate_vec_of_opaque_rust_type_functions(ty_name);
-                                        extern_rust_fn_tokens.push(vec_functions);
-                                    }
+                                    let vec_functions = generate_vec_of_opaque_rust_type_functions(
+                                        ty_name,
+                                        &ty.generics,
+                                        &self.types,
+                                    );
+                                    extern_rust_fn_tokens.push(vec_functions);
+                                } else {
+                                    let vec_functions =
+                                        generate_vec_of_opaque_copy_type_functions(ty, &self.types);
+                                    extern_rust_fn_tokens.push(vec_functions);
                                 }
                             }
                         }
+                        HostLang::Swift if ty.attributes.protocol => {
+                            structs_for_swift_classes.push(self.generate_swift_protocol_tokens(
+                                ty,
+                                impl_fn_tokens.get(&ty_name.to_string()),
+                            ));
+
+                            let free = quote! {
+                                #[link_name = #link_name]
+                                fn #free_mem_func_name (this: *mut std::ffi::c_void);
+                            };
+                            extern_swift_fn_tokens.push(free);
+                        }
                         HostLang::Swift => {
                             let ty_name = &ty.ty;
 
@@ -247,6 +366,12 @@ impl ToTokens for SwiftBridgeModule {
                             };
                             structs_for_swift_classes.push(struct_tokens);
 
+                            if self.vec_of_opaque_swift_type_is_used(&ty_name.to_string()) {
+                                let vec_functions =
+                                    generate_vec_of_opaque_swift_type_functions(ty_name);
+                                extern_rust_fn_tokens.push(vec_functions);
+                            }
+
                             let free = quote! {
                                 #[link_name = #link_name]
                                 fn #free_mem_func_name (this: *mut std::ffi::c_void);
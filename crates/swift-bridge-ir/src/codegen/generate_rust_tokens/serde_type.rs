@@ -0,0 +1,157 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/serde_codegen_tests.rs
+
+use crate::bridged_type::{SharedEnum, SharedStruct, StructFields};
+use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+impl SwiftBridgeModule {
+    /// Generate the definition of a `#[swift_bridge(serde)]` struct.
+    ///
+    /// The struct is passed over FFI as JSON, so unlike other shared structs it does not need an
+    /// FFI representation.
+    pub(super) fn generate_serde_struct_tokens(&self, shared_struct: &SharedStruct) -> TokenStream {
+        let struct_name = &shared_struct.name;
+
+        let mut derives = self.serde_derives();
+        if shared_struct.derives.copy {
+            derives.push(quote! {Copy});
+        }
+        if shared_struct.derives.clone {
+            derives.push(quote! {Clone});
+        }
+        derives.extend(shared_struct.derives.comparison_derives());
+
+        let struct_fields: Vec<TokenStream> = shared_struct
+            .fields
+            .normalized_fields()
+            .iter()
+            .map(|norm_field| {
+                let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+                let ty = &norm_field.ty;
+
+                quote! {
+                    pub #maybe_name_and_colon #ty
+                }
+            })
+            .collect();
+        let struct_fields = shared_struct.fields.wrap_declaration_fields(&struct_fields);
+
+        let serde_crate = self.serde_crate_path();
+        let json_functions = self.generate_serde_json_functions(struct_name);
+
+        quote! {
+            #[derive(#(#derives),*)]
+            #[serde(crate = #serde_crate)]
+            pub struct #struct_name #struct_fields
+
+            #json_functions
+        }
+    }
+
+    /// Generate the definition of a `#[swift_bridge(serde)]` enum.
+    ///
+    /// The enum is passed over FFI as JSON, so unlike other shared enums it does not need an
+    /// FFI representation.
+    pub(super) fn generate_serde_enum_tokens(&self, shared_enum: &SharedEnum) -> TokenStream {
+        let enum_name = &shared_enum.name;
+
+        let mut derives = self.serde_derives();
+        if shared_enum.derive.debug {
+            derives.push(quote! {Debug});
+        }
+        derives.extend(shared_enum.derive.comparison_derives());
+
+        let variants: Vec<TokenStream> = shared_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.name;
+
+                match &variant.fields {
+                    StructFields::Named(named) => {
+                        let fields = named.iter().map(|field| {
+                            let name = &field.name;
+                            let ty = &field.ty;
+                            quote! { #name: #ty }
+                        });
+                        quote! { #variant_name { #(#fields),* } }
+                    }
+                    StructFields::Unnamed(unnamed) => {
+                        let fields = unnamed.iter().map(|field| &field.ty);
+                        quote! { #variant_name ( #(#fields),* ) }
+                    }
+                    StructFields::Unit => {
+                        quote! { #variant_name }
+                    }
+                }
+            })
+            .collect();
+
+        let serde_crate = self.serde_crate_path();
+        let json_functions = self.generate_serde_json_functions(enum_name);
+
+        quote! {
+            #[derive(#(#derives),*)]
+            #[serde(crate = #serde_crate)]
+            pub enum #enum_name {
+                #(#variants),*
+            }
+
+            #json_functions
+        }
+    }
+
+    /// Generate the functions that Swift uses to convert between a boxed Rust value and JSON.
+    ///
+    /// These return a `ResultPtrAndPtr` so that serde errors can be thrown in Swift instead of
+    /// panicking in Rust.
+    fn generate_serde_json_functions(&self, ty_name: &Ident) -> TokenStream {
+        let swift_bridge_path = &self.swift_bridge_path;
+
+        // __swift_bridge__$SomeType$_to_json
+        let to_json_export_name = format!("{}${}$_to_json", SWIFT_BRIDGE_PREFIX, ty_name);
+        let to_json_fn_name = format_ident!("{}{}__to_json", SWIFT_BRIDGE_PREFIX, ty_name);
+
+        // __swift_bridge__$SomeType$_from_json
+        let from_json_export_name = format!("{}${}$_from_json", SWIFT_BRIDGE_PREFIX, ty_name);
+        let from_json_fn_name = format_ident!("{}{}__from_json", SWIFT_BRIDGE_PREFIX, ty_name);
+
+        quote! {
+            #[export_name = #to_json_export_name]
+            pub extern "C" fn #to_json_fn_name (
+                this: *mut #ty_name
+            ) -> #swift_bridge_path::result::ResultPtrAndPtr {
+                unsafe { #swift_bridge_path::serde_support::to_json(this) }
+            }
+
+            #[export_name = #from_json_export_name]
+            pub extern "C" fn #from_json_fn_name (
+                json: *mut #swift_bridge_path::string::RustString
+            ) -> #swift_bridge_path::result::ResultPtrAndPtr {
+                unsafe { #swift_bridge_path::serde_support::from_json::<#ty_name>(json) }
+            }
+        }
+    }
+
+    /// The serde derive macros that are re-exported by swift-bridge.
+    fn serde_derives(&self) -> Vec<TokenStream> {
+        let swift_bridge_path = &self.swift_bridge_path;
+
+        vec![
+            quote! { #swift_bridge_path::serde_support::serde::Serialize },
+            quote! { #swift_bridge_path::serde_support::serde::Deserialize },
+        ]
+    }
+
+    /// The path to serde, for use in a `#[serde(crate = "...")]` attribute.
+    ///
+    /// swift_bridge::serde_support::serde
+    fn serde_crate_path(&self) -> String {
+        let swift_bridge_path = &self.swift_bridge_path;
+        quote! { #swift_bridge_path::serde_support::serde }
+            .to_string()
+            .replace(' ', "")
+    }
+}
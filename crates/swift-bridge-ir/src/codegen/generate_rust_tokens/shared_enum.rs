@@ -20,6 +20,10 @@ impl SwiftBridgeModule {
             return None;
         }
 
+        if shared_enum.serde {
+            return Some(self.generate_serde_enum_tokens(shared_enum));
+        }
+
         let enum_name = &shared_enum.name;
         let swift_bridge_path = &self.swift_bridge_path;
 
@@ -158,6 +162,8 @@ impl SwiftBridgeModule {
             });
         }
 
+        derives.extend(shared_enum.derive.comparison_derives());
+
         let vec_support = if shared_enum.has_one_or_more_variants_with_data() {
             // Enums with variants that contain data are not yet supported.
             quote! {}
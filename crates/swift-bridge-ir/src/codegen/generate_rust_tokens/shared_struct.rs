@@ -2,10 +2,10 @@
 //! crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
 
 use crate::bridged_type::{BridgedType, SharedStruct};
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::codegen::generate_rust_tokens::vec::vec_of_transparent_struct::generate_vec_of_transparent_struct_functions;
+use crate::SwiftBridgeModule;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::Ident;
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared struct.
@@ -17,13 +17,20 @@ impl SwiftBridgeModule {
             return None;
         }
 
-        let struct_name = &shared_struct.name;
-        let swift_bridge_path = &self.swift_bridge_path;
+        if shared_struct.serde {
+            return Some(self.generate_serde_struct_tokens(shared_struct));
+        }
 
-        let struct_ffi_name = format!("{}{}", SWIFT_BRIDGE_PREFIX, struct_name);
-        let struct_ffi_name = Ident::new(&struct_ffi_name, struct_name.span());
+        let swift_bridge_path = &self.swift_bridge_path;
 
-        let option_struct = shared_struct.ffi_option_name_tokens();
+        let mut derives: Vec<TokenStream> = vec![];
+        if shared_struct.derives.copy {
+            derives.push(quote! {Copy});
+        }
+        if shared_struct.derives.clone {
+            derives.push(quote! {Clone});
+        }
+        derives.extend(shared_struct.derives.comparison_derives());
 
         let struct_fields: Vec<TokenStream> = shared_struct
             .fields
@@ -40,6 +47,23 @@ impl SwiftBridgeModule {
             .collect();
         let struct_fields = shared_struct.fields.wrap_declaration_fields(&struct_fields);
 
+        // The generic `struct Point<T> { .. }` only gets its Rust definition. Each
+        // `struct Point<f32>;` instantiation gets its own FFI representation below.
+        if shared_struct.generics.is_generic_declaration() {
+            let struct_name = &shared_struct.name;
+            let params = &shared_struct.generics.params;
+
+            return Some(quote! {
+                #[derive(#(#derives),*)]
+                pub struct #struct_name<#(#params),*> #struct_fields
+            });
+        }
+
+        let struct_name = shared_struct.rust_type_tokens(&self.types);
+        let struct_ffi_name = shared_struct.ffi_name_tokens();
+
+        let option_struct = shared_struct.ffi_option_name_tokens();
+
         let repr_c_struct_fields: Vec<TokenStream> = shared_struct
             .fields
             .normalized_fields()
@@ -91,17 +115,30 @@ impl SwiftBridgeModule {
             }
         };
 
-        let mut derives: Vec<TokenStream> = vec![];
-        if shared_struct.derives.copy {
-            derives.push(quote! {Copy});
-        }
-        if shared_struct.derives.clone {
-            derives.push(quote! {Clone});
-        }
+        let is_generic_instantiation = !shared_struct.generics.concrete.is_empty();
+
+        let vec_support = if shared_struct.derives.clone && !is_generic_instantiation {
+            generate_vec_of_transparent_struct_functions(shared_struct)
+        } else {
+            // Swift gets a copy of the struct when reading from a Vec, so we can only support
+            // Vec<T> for structs that implement Clone.
+            // A Swift generic struct can only conform to `Vectorizable` once, so we don't
+            // support `Vec<Point<f32>>` for generic struct instantiations.
+            quote! {}
+        };
+
+        let struct_definition = if is_generic_instantiation {
+            // The generic struct's definition is generated from its declaration.
+            quote! {}
+        } else {
+            quote! {
+                #[derive(#(#derives),*)]
+                pub struct #struct_name #struct_fields
+            }
+        };
 
         let definition = quote! {
-            #[derive(#(#derives),*)]
-            pub struct #struct_name #struct_fields
+            #struct_definition
 
             #struct_ffi_repr
 
@@ -153,6 +190,8 @@ impl SwiftBridgeModule {
                     }
                 }
             }
+
+            #vec_support
         };
 
         Some(definition)
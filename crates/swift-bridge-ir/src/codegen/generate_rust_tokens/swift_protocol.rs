@@ -0,0 +1,55 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/swift_protocol_codegen_tests.rs
+
+use crate::parse::{OpaqueForeignTypeDeclaration, TypeDeclaration};
+use crate::SwiftBridgeModule;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+impl SwiftBridgeModule {
+    /// Generate the Rust trait for a `#[swift_bridge(protocol)]` Swift type, along with the
+    /// struct that implements the trait by calling into a Swift object.
+    pub(super) fn generate_swift_protocol_tokens(
+        &self,
+        ty: &OpaqueForeignTypeDeclaration,
+        impls: Option<&Vec<TokenStream>>,
+    ) -> TokenStream {
+        let trait_name = &ty.ty;
+        let adapter = ty
+            .to_trait_object(false, false)
+            .unwrap()
+            .swift_protocol_adapter_ident();
+        let free_mem_func_name = ty.free_rust_opaque_type_ident();
+
+        let method_declarations: Vec<TokenStream> = self
+            .functions
+            .iter()
+            .filter(|func| match func.associated_type.as_ref() {
+                Some(TypeDeclaration::Opaque(associated)) => associated.ty == ty.ty,
+                _ => false,
+            })
+            .map(|func| func.to_rust_trait_method_declaration(&self.swift_bridge_path, &self.types))
+            .collect();
+        let impls = impls.map(|impls| impls.as_slice()).unwrap_or_default();
+
+        quote! {
+            pub trait #trait_name {
+                #(#method_declarations)*
+            }
+
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #adapter(*mut std::ffi::c_void);
+
+            impl #trait_name for #adapter {
+                #(#impls)*
+            }
+
+            impl Drop for #adapter {
+                fn drop (&mut self) {
+                    unsafe { #free_mem_func_name(self.0) }
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,5 @@
+pub(super) mod vec_of_opaque_copy_type;
 pub(super) mod vec_of_opaque_rust_type;
+pub(super) mod vec_of_opaque_swift_type;
 pub(super) mod vec_of_transparent_enum;
+pub(super) mod vec_of_transparent_struct;
@@ -0,0 +1,128 @@
+use crate::parse::{OpaqueForeignTypeDeclaration, TypeDeclarations};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generate the functions that Swift calls uses inside of the corresponding struct for an opaque
+/// Copy type's Vectorizable implementation.
+///
+/// So inside of `extension SomeCopyType: Vectorizable {}` on the Swift side.
+///
+/// Elements are passed over FFI using the type's `#[repr(C)]` byte array representation, so Swift
+/// always receives its own copy of the element.
+pub(in super::super) fn generate_vec_of_opaque_copy_type_functions(
+    ty: &OpaqueForeignTypeDeclaration,
+    types: &TypeDeclarations,
+) -> TokenStream {
+    // examples:
+    // "__swift_bridge__$Vec_SomeCopyType$new"
+    // "__swift_bridge__$Vec_SomeGenericCopyType$u32$drop"
+    let make_export_name = |fn_name| {
+        format!(
+            "__swift_bridge__$Vec_{}{}${}",
+            ty.ty,
+            ty.generics.dollar_prefixed_generics_string(),
+            fn_name
+        )
+    };
+    let export_name_new = make_export_name("new");
+    let export_name_drop = make_export_name("drop");
+    let export_name_len = make_export_name("len");
+    let export_name_get = make_export_name("get");
+    let export_name_get_mut = make_export_name("get_mut");
+    let export_name_push = make_export_name("push");
+    let export_name_pop = make_export_name("pop");
+    let export_name_as_ptr = make_export_name("as_ptr");
+
+    let ty_name = &ty.ty;
+    let generics = ty.generics.angle_bracketed_concrete_generics_tokens(types);
+    let rust_ty = quote! { super::#ty_name #generics };
+
+    let ffi_repr = ty.ffi_copy_repr_ident();
+    let ffi_option_repr = ty.ffi_option_copy_repr_ident();
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #[export_name = #export_name_new]
+            pub extern "C" fn _new() -> *mut Vec<#rust_ty> {
+                Box::into_raw(Box::new(Vec::new()))
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_drop]
+            pub extern "C" fn _drop(vec: *mut Vec<#rust_ty>) {
+                let vec = unsafe { Box::from_raw(vec) };
+                drop(vec)
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_len]
+            pub extern "C" fn _len(vec: *const Vec<#rust_ty>) -> usize {
+                unsafe { &*vec }.len()
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_get]
+            pub extern "C" fn _get(vec: *const Vec<#rust_ty>, index: usize) -> #ffi_option_repr {
+                let vec = unsafe { &*vec };
+                if let Some(val) = vec.get(index) {
+                    #ffi_option_repr {
+                        is_some: true,
+                        val: std::mem::MaybeUninit::new(#ffi_repr::from_rust_repr(*val))
+                    }
+                } else {
+                    #ffi_option_repr {
+                        is_some: false,
+                        val: std::mem::MaybeUninit::uninit()
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_get_mut]
+            pub extern "C" fn _get_mut(vec: *mut Vec<#rust_ty>, index: usize) -> #ffi_option_repr {
+                let vec = unsafe { &mut *vec };
+                if let Some(val) = vec.get_mut(index) {
+                    #ffi_option_repr {
+                        is_some: true,
+                        val: std::mem::MaybeUninit::new(#ffi_repr::from_rust_repr(*val))
+                    }
+                } else {
+                    #ffi_option_repr {
+                        is_some: false,
+                        val: std::mem::MaybeUninit::uninit()
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_push]
+            pub extern "C" fn _push(vec: *mut Vec<#rust_ty>, val: #ffi_repr) {
+                unsafe { &mut *vec }.push( val.into_rust_repr() )
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_pop]
+            pub extern "C" fn _pop(vec: *mut Vec<#rust_ty>) -> #ffi_option_repr {
+                let vec = unsafe { &mut *vec };
+                if let Some(val) = vec.pop() {
+                    #ffi_option_repr {
+                        is_some: true,
+                        val: std::mem::MaybeUninit::new(#ffi_repr::from_rust_repr(val))
+                    }
+                } else {
+                    #ffi_option_repr {
+                        is_some: false,
+                        val: std::mem::MaybeUninit::uninit()
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_as_ptr]
+            pub extern "C" fn _as_ptr(vec: *const Vec<#rust_ty>) -> *const #rust_ty {
+                unsafe { & *vec }.as_ptr()
+            }
+        };
+    }
+}
@@ -1,3 +1,4 @@
+use crate::parse::{OpaqueRustTypeGenerics, TypeDeclarations};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -5,11 +6,25 @@ use quote::quote;
 /// Rust type's Vectorizable implementation.
 ///
 /// So inside of `extension MyRustType: Vectorizable {}` on the Swift side.
-pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -> TokenStream {
+///
+/// For a generic type such as `SomeType<u32>` the functions are generated for that concrete
+/// instantiation.
+pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(
+    ty: &Ident,
+    generics: &OpaqueRustTypeGenerics,
+    types: &TypeDeclarations,
+) -> TokenStream {
     // examples:
     // "__swift_bridge__$Vec_MyRustType$new"
-    // "__swift_bridge__$Vec_MyRustType$drop"
-    let make_export_name = |fn_name| format!("__swift_bridge__$Vec_{}${}", ty, fn_name);
+    // "__swift_bridge__$Vec_MyGenericRustType$u32$drop"
+    let make_export_name = |fn_name| {
+        format!(
+            "__swift_bridge__$Vec_{}{}${}",
+            ty,
+            generics.dollar_prefixed_generics_string(),
+            fn_name
+        )
+    };
     let export_name_new = make_export_name("new");
     let export_name_drop = make_export_name("drop");
     let export_name_len = make_export_name("len");
@@ -19,6 +34,9 @@ pub(in super::super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -
     let export_name_pop = make_export_name("pop");
     let export_name_as_ptr = make_export_name("as_ptr");
 
+    let generics = generics.angle_bracketed_concrete_generics_tokens(types);
+    let ty = quote! { #ty #generics };
+
     quote! {
         const _: () = {
             #[doc(hidden)]
@@ -168,10 +186,11 @@ mod tests {
         };
 
         assert_tokens_eq(
-            &generate_vec_of_opaque_rust_type_functions(&Ident::new(
-                "ARustType",
-                Span::call_site(),
-            )),
+            &generate_vec_of_opaque_rust_type_functions(
+                &Ident::new("ARustType", Span::call_site()),
+                &OpaqueRustTypeGenerics::new(),
+                &TypeDeclarations::default(),
+            ),
             &expected,
         );
     }
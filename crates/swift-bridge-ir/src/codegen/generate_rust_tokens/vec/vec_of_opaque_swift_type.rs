@@ -0,0 +1,186 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Generate the functions that Swift calls uses inside of the corresponding class for an opaque
+/// Swift type's Vectorizable implementation.
+///
+/// So inside of `extension MySwiftType: Vectorizable {}` on the Swift side.
+///
+/// Each element of the `Vec` holds a retained reference to a Swift class instance, just like a
+/// single `MySwiftType` does.
+pub(in super::super) fn generate_vec_of_opaque_swift_type_functions(ty: &Ident) -> TokenStream {
+    // examples:
+    // "__swift_bridge__$Vec_MySwiftType$new"
+    // "__swift_bridge__$Vec_MySwiftType$drop"
+    let make_export_name = |fn_name| format!("__swift_bridge__$Vec_{}${}", ty, fn_name);
+    let export_name_new = make_export_name("new");
+    let export_name_drop = make_export_name("drop");
+    let export_name_len = make_export_name("len");
+    let export_name_get = make_export_name("get");
+    let export_name_get_mut = make_export_name("get_mut");
+    let export_name_push = make_export_name("push");
+    let export_name_pop = make_export_name("pop");
+    let export_name_as_ptr = make_export_name("as_ptr");
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #[export_name = #export_name_new]
+            pub extern "C" fn _new() -> *mut Vec<#ty> {
+                Box::into_raw(Box::new(Vec::new()))
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_drop]
+            pub extern "C" fn _drop(vec: *mut Vec<#ty>) {
+                let vec = unsafe { Box::from_raw(vec) };
+                drop(vec)
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_len]
+            pub extern "C" fn _len(vec: *const Vec<#ty>) -> usize {
+                unsafe { &*vec }.len()
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_get]
+            pub extern "C" fn _get(vec: *const Vec<#ty>, index: usize) -> *mut std::ffi::c_void {
+                let vec = unsafe { & *vec };
+                if let Some(val) = vec.get(index) {
+                    val.0
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_get_mut]
+            pub extern "C" fn _get_mut(vec: *mut Vec<#ty>, index: usize) -> *mut std::ffi::c_void {
+                let vec = unsafe { &mut *vec };
+                if let Some(val) = vec.get_mut(index) {
+                    val.0
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_push]
+            pub extern "C" fn _push(vec: *mut Vec<#ty>, val: *mut std::ffi::c_void) {
+                unsafe { &mut *vec }.push(#ty(val))
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_pop]
+            pub extern "C" fn _pop(vec: *mut Vec<#ty>) -> *mut std::ffi::c_void {
+                let vec = unsafe { &mut *vec };
+                if let Some(val) = vec.pop() {
+                    // Swift takes over the retained reference, so we don't release it here.
+                    let ptr = val.0;
+                    std::mem::forget(val);
+                    ptr
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_as_ptr]
+            pub extern "C" fn _as_ptr(vec: *const Vec<#ty>) -> *const #ty {
+                unsafe { & *vec }.as_ptr()
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_tokens_eq;
+    use proc_macro2::Span;
+
+    /// Verify that we can generate the functions for an opaque Swift type that get exposed to
+    /// Swift in order to power the `extension MySwiftType: Vectorizable { }` implementation on the
+    /// Swift side.
+    #[test]
+    fn generates_vectorizable_impl_for_opaque_swift_type() {
+        let expected = quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$new"]
+                pub extern "C" fn _new() -> *mut Vec<ASwiftType> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$drop"]
+                pub extern "C" fn _drop(vec: *mut Vec<ASwiftType>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$len"]
+                pub extern "C" fn _len(vec: *const Vec<ASwiftType>) -> usize {
+                    unsafe { &*vec }.len()
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$get"]
+                pub extern "C" fn _get(vec: *const Vec<ASwiftType>, index: usize) -> *mut std::ffi::c_void {
+                    let vec = unsafe { & *vec };
+                    if let Some(val) = vec.get(index) {
+                        val.0
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$get_mut"]
+                pub extern "C" fn _get_mut(vec: *mut Vec<ASwiftType>, index: usize) -> *mut std::ffi::c_void {
+                    let vec = unsafe { &mut *vec };
+                    if let Some(val) = vec.get_mut(index) {
+                        val.0
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$push"]
+                pub extern "C" fn _push(vec: *mut Vec<ASwiftType>, val: *mut std::ffi::c_void) {
+                    unsafe { &mut *vec }.push(ASwiftType(val))
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$pop"]
+                pub extern "C" fn _pop(vec: *mut Vec<ASwiftType>) -> *mut std::ffi::c_void {
+                    let vec = unsafe { &mut *vec };
+                    if let Some(val) = vec.pop() {
+                        let ptr = val.0;
+                        std::mem::forget(val);
+                        ptr
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_ASwiftType$as_ptr"]
+                pub extern "C" fn _as_ptr(vec: *const Vec<ASwiftType>) -> *const ASwiftType {
+                    unsafe { & *vec }.as_ptr()
+                }
+            };
+        };
+
+        assert_tokens_eq(
+            &generate_vec_of_opaque_swift_type_functions(&Ident::new(
+                "ASwiftType",
+                Span::call_site(),
+            )),
+            &expected,
+        );
+    }
+}
@@ -169,6 +169,8 @@ mod tests {
             already_declared: false,
             swift_name: None,
             derive: DeriveAttrs::default(),
+            codable: false,
+            serde: false,
         };
         assert_tokens_eq(
             &generate_vec_of_transparent_enum_functions(&shared_enum),
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use syn::Path;
 
@@ -6,13 +6,17 @@ use crate::bridged_type::{BridgeableType, BridgedType, TypePosition};
 use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
 use crate::codegen::generate_swift::opaque_copy_type::generate_opaque_copy_struct;
 use crate::codegen::generate_swift::swift_class::generate_swift_class;
-use crate::codegen::generate_swift::vec::generate_vectorizable_extension;
+use crate::codegen::generate_swift::swift_property::generate_swift_property;
+use crate::codegen::generate_swift::vec::{
+    generate_generic_vectorizable_extension, generate_vectorizable_extension,
+    generate_vectorizable_extension_for_copy_type, generate_vectorizable_extension_for_swift_type,
+};
 use crate::codegen::CodegenConfig;
 use crate::parse::{
     HostLang, OpaqueForeignTypeDeclaration, SharedTypeDeclaration, TypeDeclaration,
     TypeDeclarations,
 };
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parsed_extern_fn::{ParsedExternFn, SwiftOwnedStr};
 use crate::SwiftBridgeModule;
 
 mod vec;
@@ -22,6 +26,9 @@ mod opaque_copy_type;
 mod shared_enum;
 mod shared_struct;
 mod swift_class;
+mod swift_operator;
+mod swift_property;
+mod swift_protocol;
 
 impl SwiftBridgeModule {
     /// Generate the corresponding Swift code for the bridging module.
@@ -35,8 +42,16 @@ impl SwiftBridgeModule {
         let mut associated_funcs_and_methods: HashMap<String, Vec<&ParsedExternFn>> =
             HashMap::new();
         let mut class_protocols: HashMap<String, ClassProtocols> = HashMap::new();
+        let mut custom_type_definitions: Vec<String> = vec![];
 
         for function in &self.functions {
+            declare_custom_swift_code(
+                function,
+                &self.types,
+                &self.swift_bridge_path,
+                &mut custom_type_definitions,
+            );
+
             if function.host_lang.is_rust() {
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
@@ -88,6 +103,8 @@ impl SwiftBridgeModule {
             swift += "\n";
         }
 
+        let mut vectorizable_generic_types: HashSet<String> = HashSet::new();
+
         for ty in self.types.types() {
             match ty {
                 TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
@@ -103,6 +120,11 @@ impl SwiftBridgeModule {
                     }
                 }
                 TypeDeclaration::Opaque(ty) => match ty.host_lang {
+                    HostLang::Rust if ty.attributes.protocol => {
+                        swift += &self
+                            .generate_rust_trait_object_class(ty, &associated_funcs_and_methods);
+                        swift += "\n";
+                    }
                     HostLang::Rust => {
                         if let Some(_copy) = ty.attributes.copy {
                             swift += &generate_opaque_copy_struct(
@@ -127,28 +149,96 @@ impl SwiftBridgeModule {
 
                         swift += "\n";
 
-                        if !ty.attributes.already_declared {
-                            // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
-                            //  make them pass.
-                            // TODO: Support Vec<GenericOpaqueRustType
-                            if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
-                                swift += &generate_vectorizable_extension(&ty);
+                        if !ty.attributes.already_declared && !ty.attributes.declare_generic {
+                            if !ty.generics.is_empty() {
+                                // All of the module's instantiations of a generic type share one
+                                // conformance, which we emit alongside the first of them.
+                                if vectorizable_generic_types.insert(ty.ty.to_string()) {
+                                    let instantiations: Vec<&OpaqueForeignTypeDeclaration> = self
+                                        .types
+                                        .types()
+                                        .into_iter()
+                                        .filter_map(|t| t.as_opaque())
+                                        .filter(|t| {
+                                            t.host_lang.is_rust()
+                                                && t.ty == ty.ty
+                                                && !t.generics.is_empty()
+                                                && !t.attributes.already_declared
+                                                && !t.attributes.declare_generic
+                                        })
+                                        .collect();
+
+                                    swift += &generate_generic_vectorizable_extension(
+                                        &instantiations,
+                                        &self.types,
+                                        &self.swift_bridge_path,
+                                    );
+                                    swift += "\n";
+                                }
+                            } else if ty.attributes.copy.is_some() {
+                                swift += &generate_vectorizable_extension_for_copy_type(&ty.ty);
+                                swift += "\n";
+                            } else {
+                                swift += &generate_vectorizable_extension(&ty.ty);
                                 swift += "\n";
                             }
                         }
                     }
+                    HostLang::Swift if ty.attributes.protocol => {
+                        swift += &self.generate_swift_protocol(ty);
+                        swift += "\n";
+
+                        swift += &generate_drop_swift_instance_reference_count(ty);
+                        swift += "\n";
+                    }
                     HostLang::Swift => {
                         swift += &generate_drop_swift_instance_reference_count(ty);
                         swift += "\n";
+
+                        if self.vec_of_opaque_swift_type_is_used(&ty.to_string()) {
+                            swift += &generate_vectorizable_extension_for_swift_type(&ty);
+                            swift += "\n";
+                        }
                     }
                 },
             };
         }
 
+        swift += &self.generate_swift_operators();
+
+        for custom_type_definition in custom_type_definitions {
+            swift += &custom_type_definition;
+            swift += "\n";
+        }
+
         swift
     }
 }
 
+// Collect the Swift code that types such as `[T; N]` need, deduplicating types that are used by
+// more than one function.
+fn declare_custom_swift_code(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+    custom_type_definitions: &mut Vec<String>,
+) {
+    let ret = BridgedType::new_with_return_type(&func.sig.output, types);
+    let args = func
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| BridgedType::new_with_fn_arg(arg, types));
+
+    for ty in std::iter::once(ret).chain(args).flatten() {
+        if let Some(definition) = ty.generate_custom_swift_code(types, swift_bridge_path) {
+            if !custom_type_definitions.contains(&definition) {
+                custom_type_definitions.push(definition);
+            }
+        }
+    }
+}
+
 #[derive(Default)]
 struct ClassProtocols {
     // The name of the function to use for the Identifiable protocol implementation.
@@ -172,6 +262,11 @@ struct IdentifiableProtocol {
 fn generate_drop_swift_instance_reference_count(ty: &OpaqueForeignTypeDeclaration) -> String {
     let link_name = ty.free_swift_class_link_name();
     let fn_name = ty.free_swift_class_func_name();
+    let ty_name = if ty.attributes.protocol {
+        "AnyObject".to_string()
+    } else {
+        ty.ty_name_ident().to_string()
+    };
 
     format!(
         r##"
@@ -182,7 +277,7 @@ func {fn_name} (ptr: UnsafeMutableRawPointer) {{
 "##,
         link_name = link_name,
         fn_name = fn_name,
-        ty_name = ty.ty_name_ident()
+        ty_name = ty_name
     )
 }
 
@@ -206,20 +301,24 @@ fn gen_function_exposes_swift_to_rust(
     let mut call_fn = format!("{}({})", fn_name, args);
     if let Some(built_in) = BridgedType::new_with_return_type(&func.sig.output, types) {
         if let Some(associated_type) = func.associated_type.as_ref() {
-            let ty_name = match associated_type {
+            let associated_type = match associated_type {
                 TypeDeclaration::Shared(_) => {
                     //
                     todo!()
                 }
-                TypeDeclaration::Opaque(associated_type) => associated_type.to_string(),
+                TypeDeclaration::Opaque(associated_type) => associated_type,
             };
+            let ty_name = associated_type.to_string();
 
             if func.is_method() {
-                call_fn = format!(
-                    "Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{call_fn}",
-                    ty_name = ty_name,
-                    call_fn = call_fn
-                );
+                // A protocol existential can't be used as `Unmanaged`'s generic parameter, so we
+                // cast the object to the protocol instead.
+                let this = if associated_type.attributes.protocol {
+                    format!("(Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! {ty_name})")
+                } else {
+                    format!("Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue()")
+                };
+                call_fn = format!("{this}.{call_fn}", this = this, call_fn = call_fn);
                 call_fn = built_in.convert_swift_expression_to_ffi_type(
                     &call_fn,
                     types,
@@ -230,6 +329,12 @@ fn gen_function_exposes_swift_to_rust(
             } else {
                 call_fn = format!("{}::{}", ty_name, call_fn);
             }
+        } else if let Some(swift_owned_str) = func.swift_owned_str_return(types) {
+            // Swift returns a `String`, and keeps its bytes alive until Rust drops its `SwiftStr`.
+            call_fn = match swift_owned_str {
+                SwiftOwnedStr::Str => format!("{call_fn}.intoSwiftStr()"),
+                SwiftOwnedStr::OptionStr => format!("optionalStringIntoSwiftStr({call_fn})"),
+            };
         } else {
             call_fn = built_in.convert_swift_expression_to_ffi_type(
                 &call_fn,
@@ -280,9 +385,12 @@ fn gen_function_exposes_swift_to_rust(
 
         let maybe_generics = boxed_fn.maybe_swift_generics(types);
 
-        rust_fn_once_callback_classes += &format!(
-            r#"
-class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
+        let class_prefix = boxed_fn.kind.swift_callback_class_prefix();
+
+        rust_fn_once_callback_classes += &if boxed_fn.kind.is_fn_once() {
+            format!(
+                r#"
+class {class_prefix}{maybe_associated_ty}${fn_name}$param{idx} {{
     var ptr: UnsafeMutableRawPointer
     var called = false
 
@@ -304,7 +412,27 @@ class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
         return {ret_value}
     }}
 }}"#
-        );
+            )
+        } else {
+            format!(
+                r#"
+class {class_prefix}{maybe_associated_ty}${fn_name}$param{idx} {{
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {{
+        self.ptr = ptr
+    }}
+
+    deinit {{
+        __swift_bridge__{maybe_associated_ty}${fn_name}$_free$param{idx}(ptr)
+    }}
+
+    func call{maybe_generics}({params_as_swift}){maybe_ret} {{
+        return {ret_value}
+    }}
+}}"#
+            )
+        };
     }
 
     let callback_initializers =
@@ -355,6 +483,40 @@ fn generate_swift_class_methods(
 
     if let Some(methods) = associated_funcs_and_methods.get(type_name) {
         for type_method in methods {
+            // Setters are generated along with their property's getter.
+            if type_method.setter.is_some() {
+                continue;
+            }
+
+            if let Some(property) = type_method.property.as_ref() {
+                let setter = methods
+                    .iter()
+                    .find(|m| m.setter.as_ref().map(|s| s.value()) == Some(property.value()))
+                    .copied();
+                let property_definition = generate_swift_property(
+                    property,
+                    type_method,
+                    setter,
+                    types,
+                    swift_bridge_path,
+                );
+
+                // A property with a setter needs a mutable reference to the Rust type. Swift
+                // extensions can not override the property, so `{Type}Ref` keeps read access
+                // through the getter method.
+                if setter.is_some() {
+                    ref_mut_self_methods.push(property_definition);
+                    ref_self_methods.push(gen_func_swift_calls_rust(
+                        type_method,
+                        types,
+                        swift_bridge_path,
+                    ));
+                } else {
+                    ref_self_methods.push(property_definition);
+                }
+                continue;
+            }
+
             let func_definition = gen_func_swift_calls_rust(type_method, types, swift_bridge_path);
 
             let is_class_func = type_method.func.sig.inputs.is_empty();
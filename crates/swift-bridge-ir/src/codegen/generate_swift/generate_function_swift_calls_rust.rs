@@ -11,6 +11,11 @@ pub(super) fn gen_func_swift_calls_rust(
     swift_bridge_path: &Path,
 ) -> String {
     let fn_name = function.sig.ident.to_string();
+    let ffi_fn_name = format!(
+        "{}{}",
+        fn_name,
+        function.dollar_prefixed_monomorphized_generics()
+    );
     let params = function.to_swift_param_names_and_types(false, types, swift_bridge_path);
     let call_args = function.to_swift_call_args(true, false, types, swift_bridge_path);
     let call_fn = if function.sig.asyncness.is_some() {
@@ -20,24 +25,12 @@ pub(super) fn gen_func_swift_calls_rust(
             format!(", {}", call_args)
         };
 
-        format!("{}(wrapperPtr, onComplete{})", fn_name, maybe_args)
+        format!("{}(wrapperPtr, onComplete{})", ffi_fn_name, maybe_args)
     } else {
-        format!("{}({})", fn_name, call_args)
+        format!("{}({})", ffi_fn_name, call_args)
     };
 
-    let maybe_type_name_segment = if let Some(ty) = function.associated_type.as_ref() {
-        match ty {
-            TypeDeclaration::Shared(_) => {
-                //
-                todo!()
-            }
-            TypeDeclaration::Opaque(ty) => {
-                format!("${}", ty.to_string())
-            }
-        }
-    } else {
-        "".to_string()
-    };
+    let maybe_type_name_segment = maybe_type_name_segment(function);
 
     let maybe_static_class_func = if function.associated_type.is_some()
         && (!function.is_method() && !function.is_swift_initializer)
@@ -71,109 +64,7 @@ pub(super) fn gen_func_swift_calls_rust(
         ""
     };
 
-    let call_rust = format!(
-        "{prefix}{type_name_segment}${call_fn}",
-        prefix = SWIFT_BRIDGE_PREFIX,
-        type_name_segment = maybe_type_name_segment,
-        call_fn = call_fn
-    );
-    let mut call_rust = if function.sig.asyncness.is_some() {
-        call_rust
-    } else if function.is_swift_initializer {
-        call_rust
-    } else if let Some(built_in) = function.return_ty_built_in(types) {
-        built_in.convert_ffi_value_to_swift_value(
-            &call_rust,
-            TypePosition::FnReturn(function.host_lang),
-            types,
-            swift_bridge_path,
-        )
-    } else {
-        if function.host_lang.is_swift() {
-            call_rust
-        } else {
-            match &function.sig.output {
-                ReturnType::Default => {
-                    // () is a built in type so this would have been handled in the previous block.
-                    unreachable!()
-                }
-                ReturnType::Type(_, ty) => {
-                    let ty_name = match ty.deref() {
-                        Type::Reference(reference) => reference.elem.to_token_stream().to_string(),
-                        Type::Path(path) => path.path.segments.to_token_stream().to_string(),
-                        _ => todo!(),
-                    };
-
-                    match types.get(&ty_name).unwrap() {
-                        TypeDeclaration::Shared(_) => call_rust,
-                        TypeDeclaration::Opaque(opaque) => {
-                            if opaque.host_lang.is_rust() {
-                                let (is_owned, ty) = match ty.deref() {
-                                    Type::Reference(reference) => ("false", &reference.elem),
-                                    _ => ("true", ty),
-                                };
-
-                                let ty = ty.to_token_stream().to_string();
-                                format!("{}(ptr: {}, isOwned: {})", ty, call_rust, is_owned)
-                            } else {
-                                let ty = ty.to_token_stream().to_string();
-                                format!(
-                                    "Unmanaged<{}>.fromOpaque({}).takeRetainedValue()",
-                                    ty, call_rust
-                                )
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    };
-    let returns_null = BridgedType::new_with_return_type(&function.func.sig.output, types)
-        .map(|b| b.is_null())
-        .unwrap_or(false);
-
-    let maybe_return = if returns_null || function.is_swift_initializer {
-        ""
-    } else {
-        "return "
-    };
-
-    for arg in function.func.sig.inputs.iter() {
-        let bridged_arg = BridgedType::new_with_fn_arg(arg, types);
-        if bridged_arg.is_none() {
-            continue;
-        }
-        let bridged_arg = bridged_arg.unwrap();
-
-        let arg_name = fn_arg_name(arg).unwrap().to_string();
-
-        // TODO: Refactor to make less duplicative
-        match bridged_arg {
-            BridgedType::StdLib(StdLibType::Str) => {
-                call_rust = format!(
-                    r#"{maybe_return}{arg}.toRustStr({{ {arg}AsRustStr in
-{indentation}        {call_rust}
-{indentation}    }})"#,
-                    maybe_return = maybe_return,
-                    indentation = indentation,
-                    arg = arg_name,
-                    call_rust = call_rust
-                );
-            }
-            BridgedType::StdLib(StdLibType::Option(briged_opt)) if briged_opt.ty.is_str() => {
-                call_rust = format!(
-                    r#"{maybe_return}optionalRustStrToRustStr({arg}, {{ {arg}AsRustStr in
-{indentation}        {call_rust}
-{indentation}    }})"#,
-                    maybe_return = maybe_return,
-                    indentation = indentation,
-                    arg = arg_name,
-                    call_rust = call_rust
-                );
-            }
-            _ => {}
-        }
-    }
+    let mut call_rust = gen_call_rust(function, &call_fn, indentation, types, swift_bridge_path);
 
     if function.is_swift_initializer {
         if function.is_copy_method_on_opaque_type() {
@@ -184,7 +75,11 @@ pub(super) fn gen_func_swift_calls_rust(
     }
 
     let maybe_return = if function.is_swift_initializer {
-        "".to_string()
+        if function.contains_serde_type(types) {
+            " throws".to_string()
+        } else {
+            "".to_string()
+        }
     } else {
         function.to_swift_return_type(types, swift_bridge_path)
     };
@@ -318,3 +213,162 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
     };
     func_definition
 }
+
+/// Generate the body of a `#[swift_bridge(property = "...")]` getter or
+/// `#[swift_bridge(setter = "...")]` setter, which calls the Rust function directly.
+pub(super) fn gen_property_accessor_swift_calls_rust(
+    function: &ParsedExternFn,
+    indentation: &str,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let call_fn = format!(
+        "{}{}({})",
+        function.sig.ident,
+        function.dollar_prefixed_monomorphized_generics(),
+        function.to_swift_call_args(true, false, types, swift_bridge_path)
+    );
+
+    gen_call_rust(function, &call_fn, indentation, types, swift_bridge_path)
+}
+
+/// Generate the expression that calls the Rust function and converts its return value into a
+/// Swift value.
+fn gen_call_rust(
+    function: &ParsedExternFn,
+    call_fn: &str,
+    indentation: &str,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let call_rust = format!(
+        "{prefix}{type_name_segment}${call_fn}",
+        prefix = SWIFT_BRIDGE_PREFIX,
+        type_name_segment = maybe_type_name_segment(function),
+        call_fn = call_fn
+    );
+    let mut call_rust = if function.sig.asyncness.is_some() {
+        call_rust
+    } else if function.is_swift_initializer {
+        call_rust
+    } else if let Some(built_in) = function.return_ty_built_in(types) {
+        built_in.convert_ffi_value_to_swift_value(
+            &call_rust,
+            TypePosition::FnReturn(function.host_lang),
+            types,
+            swift_bridge_path,
+        )
+    } else {
+        if function.host_lang.is_swift() {
+            call_rust
+        } else {
+            match &function.sig.output {
+                ReturnType::Default => {
+                    // () is a built in type so this would have been handled in the previous block.
+                    unreachable!()
+                }
+                ReturnType::Type(_, ty) => {
+                    let ty_name = match ty.deref() {
+                        Type::Reference(reference) => reference.elem.to_token_stream().to_string(),
+                        Type::Path(path) => path.path.segments.to_token_stream().to_string(),
+                        _ => todo!(),
+                    };
+
+                    match types.get(&ty_name).unwrap() {
+                        TypeDeclaration::Shared(_) => call_rust,
+                        TypeDeclaration::Opaque(opaque) => {
+                            if opaque.host_lang.is_rust() {
+                                let (is_owned, ty) = match ty.deref() {
+                                    Type::Reference(reference) => ("false", &reference.elem),
+                                    _ => ("true", ty),
+                                };
+
+                                let ty = ty.to_token_stream().to_string();
+                                format!("{}(ptr: {}, isOwned: {})", ty, call_rust, is_owned)
+                            } else {
+                                let ty = ty.to_token_stream().to_string();
+                                format!(
+                                    "Unmanaged<{}>.fromOpaque({}).takeRetainedValue()",
+                                    ty, call_rust
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+    let returns_null = BridgedType::new_with_return_type(&function.func.sig.output, types)
+        .map(|b| b.is_null())
+        .unwrap_or(false);
+
+    let maybe_return = if returns_null || function.is_swift_initializer {
+        ""
+    } else {
+        "return "
+    };
+    // The call within the `&str` closure throws if it passes a `#[swift_bridge(serde)]` value.
+    let maybe_try = if function.host_lang.is_rust() && function.contains_serde_type(types) {
+        "try "
+    } else {
+        ""
+    };
+
+    for arg in function.func.sig.inputs.iter() {
+        let bridged_arg = BridgedType::new_with_fn_arg(arg, types);
+        if bridged_arg.is_none() {
+            continue;
+        }
+        let bridged_arg = bridged_arg.unwrap();
+
+        let arg_name = fn_arg_name(arg).unwrap().to_string();
+
+        // TODO: Refactor to make less duplicative
+        match bridged_arg {
+            BridgedType::StdLib(StdLibType::Str) => {
+                call_rust = format!(
+                    r#"{maybe_return}{maybe_try}{arg}.toRustStr({{ {arg}AsRustStr in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    maybe_try = maybe_try,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::StdLib(StdLibType::Option(briged_opt)) if briged_opt.ty.is_str() => {
+                call_rust = format!(
+                    r#"{maybe_return}{maybe_try}optionalRustStrToRustStr({arg}, {{ {arg}AsRustStr in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    maybe_try = maybe_try,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            _ => {}
+        }
+    }
+
+    call_rust
+}
+
+/// `$SomeType` for functions that are associated to a type, otherwise an empty string.
+fn maybe_type_name_segment(function: &ParsedExternFn) -> String {
+    if let Some(ty) = function.associated_type.as_ref() {
+        match ty {
+            TypeDeclaration::Shared(_) => {
+                //
+                todo!()
+            }
+            TypeDeclaration::Opaque(ty) => {
+                format!("${}", ty.to_string())
+            }
+        }
+    } else {
+        "".to_string()
+    }
+}
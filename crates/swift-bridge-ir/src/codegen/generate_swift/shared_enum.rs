@@ -1,5 +1,7 @@
+use crate::bridged_type::bridgeable_serde_type::serde_field_swift_type;
 use crate::bridged_type::{BridgedType, SharedEnum, StructFields, TypePosition};
 use crate::SwiftBridgeModule;
+use syn::Type;
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared enum.
@@ -21,13 +23,7 @@ impl SwiftBridgeModule {
                 StructFields::Named(named_fields) => {
                     let mut params = vec![];
                     for named_field in named_fields {
-                        let ty = BridgedType::new_with_type(&named_field.ty, &self.types)
-                            .unwrap()
-                            .to_swift_type(
-                                TypePosition::SharedStructField,
-                                &self.types,
-                                &self.swift_bridge_path,
-                            );
+                        let ty = self.variant_field_swift_type(&named_field.ty, shared_enum);
                         params.push(format!("{}: {}", named_field.name, ty))
                     }
                     let params = params.join(", ");
@@ -41,13 +37,7 @@ impl SwiftBridgeModule {
                 StructFields::Unnamed(unnamed_fields) => {
                     let mut params = vec![];
                     for unnamed_field in unnamed_fields {
-                        let ty = BridgedType::new_with_type(&unnamed_field.ty, &self.types)
-                            .unwrap()
-                            .to_swift_type(
-                                TypePosition::SharedStructField,
-                                &self.types,
-                                &self.swift_bridge_path,
-                            );
+                        let ty = self.variant_field_swift_type(&unnamed_field.ty, shared_enum);
                         params.push(ty);
                     }
                     let params = params.join(", ");
@@ -72,6 +62,16 @@ impl SwiftBridgeModule {
             variants += "\n";
         }
 
+        // A `#[swift_bridge(serde)]` enum is decoded from JSON, so it doesn't need any
+        // FFI conversions.
+        if shared_enum.serde {
+            let codable_impl = self.generate_enum_codable_conformance(shared_enum);
+            let derive_impls = self.generate_enum_derive_conformances(shared_enum);
+            return Some(format!(
+                r#"public enum {enum_name} {{{variants}}}{codable_impl}{derive_impls}"#
+            ));
+        }
+
         for variant in shared_enum.variants.iter() {
             let convert_swift_variant_to_ffi_repr = variant.convert_swift_to_ffi_repr(
                 &self.types,
@@ -158,6 +158,9 @@ extension {enum_name}: CustomDebugStringConvertible {{
             "".to_string()
         };
 
+        let codable_impl = self.generate_enum_codable_conformance(shared_enum);
+        let derive_impls = self.generate_enum_derive_conformances(shared_enum);
+
         let swift_enum = format!(
             r#"public enum {enum_name} {{{variants}}}
 extension {enum_name} {{
@@ -187,7 +190,7 @@ extension {option_ffi_name} {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}{vectorizable_impl}{derive_debug_impl}"#,
+}}{vectorizable_impl}{derive_debug_impl}{codable_impl}{derive_impls}"#,
             enum_name = enum_name,
             enum_ffi_name = enum_ffi_name,
             option_ffi_name = option_ffi_name,
@@ -199,4 +202,220 @@ extension {option_ffi_name} {{
 
         Some(swift_enum)
     }
+
+    /// Generate the `Equatable`, `Hashable` and `Comparable` conformances for an enum that
+    /// derives `PartialEq`, `Hash` or `PartialOrd`.
+    ///
+    /// Swift synthesizes all three. Like Rust, the synthesized `<` orders cases by their
+    /// declaration order and then by their associated values.
+    fn generate_enum_derive_conformances(&self, shared_enum: &SharedEnum) -> String {
+        let enum_name = shared_enum.swift_name_string();
+
+        let mut impls = "".to_string();
+        if shared_enum.derive.partial_eq {
+            impls += &format!("\nextension {enum_name}: Equatable {{}}");
+        }
+        if shared_enum.derive.hash {
+            impls += &format!("\nextension {enum_name}: Hashable {{}}");
+        }
+        if shared_enum.derive.partial_ord {
+            impls += &format!("\nextension {enum_name}: Comparable {{}}");
+        }
+
+        impls
+    }
+
+    /// Generate the `Codable` conformance for a `#[swift_bridge(Codable)]` enum.
+    ///
+    /// Values are encoded the same way that serde serializes the Rust enum by default. A unit
+    /// variant is encoded as its name, while a variant with data is encoded as an object with
+    /// the variant's name as its only key.
+    fn generate_enum_codable_conformance(&self, shared_enum: &SharedEnum) -> String {
+        if !shared_enum.codable && !shared_enum.serde {
+            return "".to_string();
+        }
+
+        let enum_name = shared_enum.swift_name_string();
+        let field_swift_type = |ty: &Type| self.variant_field_swift_type(ty, shared_enum);
+
+        let mut decode_unit_variants = "".to_string();
+        let mut decode_data_variants = "".to_string();
+        let mut encode_variants = "".to_string();
+
+        for variant in shared_enum.variants.iter() {
+            let name = &variant.name;
+
+            match &variant.fields {
+                StructFields::Unit => {
+                    decode_unit_variants += &format!(
+                        r#"
+            case "{name}":
+                self = .{name}"#
+                    );
+                    encode_variants += &format!(
+                        r#"
+        case .{name}:
+            var container = encoder.singleValueContainer()
+            try container.encode("{name}")"#
+                    );
+                }
+                StructFields::Unnamed(unnamed) if unnamed.len() == 1 => {
+                    let ty = field_swift_type(&unnamed[0].ty);
+                    decode_data_variants += &format!(
+                        r#"
+        case "{name}":
+            self = .{name}(try container.decode({ty}.self, forKey: key))"#
+                    );
+                    encode_variants += &format!(
+                        r#"
+        case .{name}(let _0):
+            var container = encoder.container(keyedBy: SwiftBridgeCodingKey.self)
+            try container.encode(_0, forKey: SwiftBridgeCodingKey("{name}"))"#
+                    );
+                }
+                StructFields::Unnamed(unnamed) => {
+                    let decode_fields: Vec<String> = unnamed
+                        .iter()
+                        .map(|field| {
+                            format!("try fields.decode({}.self)", field_swift_type(&field.ty))
+                        })
+                        .collect();
+                    let bindings: Vec<String> = unnamed
+                        .iter()
+                        .map(|field| format!("let _{}", field.idx))
+                        .collect();
+                    let encode_fields: String = unnamed
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                r#"
+            try fields.encode(_{})"#,
+                                field.idx
+                            )
+                        })
+                        .collect();
+
+                    decode_data_variants += &format!(
+                        r#"
+        case "{name}":
+            var fields = try container.nestedUnkeyedContainer(forKey: key)
+            self = .{name}({decode_fields})"#,
+                        decode_fields = decode_fields.join(", ")
+                    );
+                    encode_variants += &format!(
+                        r#"
+        case .{name}({bindings}):
+            var container = encoder.container(keyedBy: SwiftBridgeCodingKey.self)
+            var fields = container.nestedUnkeyedContainer(forKey: SwiftBridgeCodingKey("{name}")){encode_fields}"#,
+                        bindings = bindings.join(", ")
+                    );
+                }
+                StructFields::Named(named) => {
+                    let decode_fields: Vec<String> = named
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "{field_name}: try fields.decode({ty}.self, forKey: SwiftBridgeCodingKey(\"{field_name}\"))",
+                                field_name = field.name,
+                                ty = field_swift_type(&field.ty)
+                            )
+                        })
+                        .collect();
+                    let bindings: Vec<String> = named
+                        .iter()
+                        .map(|field| format!("let {}", field.name))
+                        .collect();
+                    let encode_fields: String = named
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                r#"
+            try fields.encode({field_name}, forKey: SwiftBridgeCodingKey("{field_name}"))"#,
+                                field_name = field.name
+                            )
+                        })
+                        .collect();
+
+                    decode_data_variants += &format!(
+                        r#"
+        case "{name}":
+            let fields = try container.nestedContainer(keyedBy: SwiftBridgeCodingKey.self, forKey: key)
+            self = .{name}({decode_fields})"#,
+                        decode_fields = decode_fields.join(", ")
+                    );
+                    encode_variants += &format!(
+                        r#"
+        case .{name}({bindings}):
+            var container = encoder.container(keyedBy: SwiftBridgeCodingKey.self)
+            var fields = container.nestedContainer(keyedBy: SwiftBridgeCodingKey.self, forKey: SwiftBridgeCodingKey("{name}")){encode_fields}"#,
+                        bindings = bindings.join(", ")
+                    );
+                }
+            };
+        }
+
+        let decode_unit_variants = if decode_unit_variants.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                r#"
+        if let variant = try? decoder.singleValueContainer().decode(String.self) {{
+            switch variant {{{decode_unit_variants}
+            default:
+                throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Unknown variant \(variant)"))
+            }}
+            return
+        }}
+"#
+            )
+        };
+
+        let decode_data_variants = if decode_data_variants.is_empty() {
+            r#"
+        throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected a variant name"))"#
+                .to_string()
+        } else {
+            format!(
+                r#"
+        let container = try decoder.container(keyedBy: SwiftBridgeCodingKey.self)
+        guard container.allKeys.count == 1, let key = container.allKeys.first else {{
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected a single variant"))
+        }}
+        switch key.stringValue {{{decode_data_variants}
+        default:
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Unknown variant \(key.stringValue)"))
+        }}"#
+            )
+        };
+
+        format!(
+            r#"
+extension {enum_name}: Codable {{
+    public init(from decoder: Decoder) throws {{{decode_unit_variants}{decode_data_variants}
+    }}
+
+    public func encode(to encoder: Encoder) throws {{
+        switch self {{{encode_variants}
+        }}
+    }}
+}}"#
+        )
+    }
+
+    /// The Swift type of a variant's field.
+    fn variant_field_swift_type(&self, ty: &Type, shared_enum: &SharedEnum) -> String {
+        if shared_enum.serde {
+            return serde_field_swift_type(ty, &self.types).unwrap_or_else(|_| {
+                unreachable!("Unsupported serde field types are rejected while parsing")
+            });
+        }
+
+        BridgedType::new_with_type(ty, &self.types)
+            .unwrap()
+            .to_swift_type(
+                TypePosition::SharedStructField,
+                &self.types,
+                &self.swift_bridge_path,
+            )
+    }
 }
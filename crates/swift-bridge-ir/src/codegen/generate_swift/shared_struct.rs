@@ -1,6 +1,8 @@
-use crate::bridged_type::shared_struct::StructField;
+use crate::bridged_type::bridgeable_serde_type::serde_field_swift_type;
+use crate::bridged_type::shared_struct::{type_is_ident, StructField};
 use crate::bridged_type::{BridgedType, SharedStruct, StructFields, StructSwiftRepr, TypePosition};
 use crate::SwiftBridgeModule;
+use syn::Type;
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared struct.
@@ -14,6 +16,7 @@ impl SwiftBridgeModule {
 
         let struct_name = &shared_struct.swift_name_string();
         let option_ffi_name = shared_struct.ffi_option_name_string();
+        let generic_params = &shared_struct.generics.params;
 
         match shared_struct.swift_repr {
             StructSwiftRepr::Class => {
@@ -21,9 +24,11 @@ impl SwiftBridgeModule {
             }
             StructSwiftRepr::Structure => {
                 let initializer_params = match &shared_struct.fields {
-                    StructFields::Named(named) => self.convert_fields_to_initializer_params(named),
+                    StructFields::Named(named) => {
+                        self.convert_fields_to_initializer_params(named, shared_struct)
+                    }
                     StructFields::Unnamed(unnamed) => {
-                        self.convert_fields_to_initializer_params(unnamed)
+                        self.convert_fields_to_initializer_params(unnamed, shared_struct)
                     }
                     StructFields::Unit => "".to_string(),
                 };
@@ -37,11 +42,41 @@ impl SwiftBridgeModule {
                 };
 
                 let fields = match &shared_struct.fields {
-                    StructFields::Named(named) => self.declare_fields(named),
-                    StructFields::Unnamed(unnamed) => self.declare_fields(unnamed),
+                    StructFields::Named(named) => self.declare_fields(named, shared_struct),
+                    StructFields::Unnamed(unnamed) => self.declare_fields(unnamed, shared_struct),
                     StructFields::Unit => "".to_string(),
                 };
 
+                // A `#[swift_bridge(serde)]` struct is decoded from JSON, so it doesn't need any
+                // FFI conversions.
+                if shared_struct.serde {
+                    let codable_impl = self.generate_struct_codable_conformance(shared_struct);
+                    let derive_impls = self.generate_struct_derive_conformances(shared_struct);
+
+                    return Some(format!(
+                        r#"public struct {struct_name} {{{fields}
+    public init({initializer_params}) {{{initializer_body}}}
+}}{codable_impl}{derive_impls}"#
+                    ));
+                }
+
+                // The generic `struct Point<T> { .. }` becomes a generic Swift struct. Each
+                // `struct Point<f32>;` instantiation then gets its FFI conversions in a
+                // constrained extension.
+                if shared_struct.generics.is_generic_declaration() {
+                    let params: Vec<String> =
+                        generic_params.iter().map(|p| p.to_string()).collect();
+                    let codable_impl = self.generate_struct_codable_conformance(shared_struct);
+                    let derive_impls = self.generate_struct_derive_conformances(shared_struct);
+
+                    return Some(format!(
+                        r#"public struct {struct_name}<{params}> {{{fields}
+    public init({initializer_params}) {{{initializer_body}}}
+}}{codable_impl}{derive_impls}"#,
+                        params = params.join(", "),
+                    ));
+                }
+
                 let convert_swift_to_ffi_repr =
                     shared_struct.convert_swift_to_ffi_repr("self", &self.types);
                 let convert_ffi_repr_to_swift = shared_struct.convert_ffi_expression_to_swift(
@@ -50,12 +85,98 @@ impl SwiftBridgeModule {
                     &self.swift_bridge_path,
                 );
 
+                let is_generic_instantiation = !shared_struct.generics.concrete.is_empty();
+
+                let vectorizable_impl = if shared_struct.derives.clone && !is_generic_instantiation
+                {
+                    format!(
+                        r#"
+extension {struct_name}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{struct_name}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{struct_name}$drop(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {{
+        __swift_bridge__$Vec_{struct_name}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$pop(vecPtr)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get_mut(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {{
+        fatalError("RustVec<{struct_name}>.as_ptr() is unsupported since the Rust struct's layout differs from the Swift struct's layout")
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{struct_name}$len(vecPtr)
+    }}
+}}"#
+                    )
+                } else {
+                    "".to_string()
+                };
+
+                // The generic declaration already conforms to `Codable`, `Equatable`, etc.
+                let (codable_impl, derive_impls) = if is_generic_instantiation {
+                    ("".to_string(), "".to_string())
+                } else {
+                    (
+                        self.generate_struct_codable_conformance(shared_struct),
+                        self.generate_struct_derive_conformances(shared_struct),
+                    )
+                };
+
+                let struct_declaration = if is_generic_instantiation {
+                    let constraints: Vec<String> = generic_params
+                        .iter()
+                        .zip(shared_struct.generics.concrete.iter())
+                        .map(|(param, concrete)| {
+                            let concrete =
+                                BridgedType::new_with_str(&concrete.to_string(), &self.types)
+                                    .unwrap()
+                                    .to_swift_type(
+                                        TypePosition::SharedStructField,
+                                        &self.types,
+                                        &self.swift_bridge_path,
+                                    );
+                            format!("{} == {}", param, concrete)
+                        })
+                        .collect();
+
+                    format!(
+                        "extension {struct_name} where {constraints} {{",
+                        constraints = constraints.join(", ")
+                    )
+                } else {
+                    format!(
+                        r#"public struct {struct_name} {{{fields}
+    public init({initializer_params}) {{{initializer_body}}}
+"#
+                    )
+                };
+                let swift_type =
+                    shared_struct.swift_type_string(&self.types, &self.swift_bridge_path);
+
                 // No need to generate any code. Swift will automatically generate a
                 //  struct from our C header typedef that we generate for this struct.
                 let swift_struct = format!(
-                    r#"public struct {struct_name} {{{fields}
-    public init({initializer_params}) {{{initializer_body}}}
-
+                    r#"{struct_declaration}
     @inline(__always)
     func intoFfiRepr() -> {ffi_repr_name} {{
         {convert_swift_to_ffi_repr}
@@ -63,13 +184,13 @@ impl SwiftBridgeModule {
 }}
 extension {ffi_repr_name} {{
     @inline(__always)
-    func intoSwiftRepr() -> {struct_name} {{
+    func intoSwiftRepr() -> {swift_type} {{
         {convert_ffi_repr_to_swift}
     }}
 }}
 extension {option_ffi_name} {{
     @inline(__always)
-    func intoSwiftRepr() -> Optional<{struct_name}> {{
+    func intoSwiftRepr() -> Optional<{swift_type}> {{
         if self.is_some {{
             return self.val.intoSwiftRepr()
         }} else {{
@@ -78,18 +199,16 @@ extension {option_ffi_name} {{
     }}
 
     @inline(__always)
-    static func fromSwiftRepr(_ val: Optional<{struct_name}>) -> {option_ffi_name} {{
+    static func fromSwiftRepr(_ val: Optional<{swift_type}>) -> {option_ffi_name} {{
         if let v = val {{
             return {option_ffi_name}(is_some: true, val: v.intoFfiRepr())
         }} else {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}"#,
-                    struct_name = struct_name,
-                    initializer_params = initializer_params,
-                    initializer_body = initializer_body,
-                    fields = fields,
+}}{vectorizable_impl}{codable_impl}{derive_impls}"#,
+                    struct_declaration = struct_declaration,
+                    swift_type = swift_type,
                     ffi_repr_name = shared_struct.ffi_name_string(),
                     option_ffi_name = option_ffi_name,
                     convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
@@ -101,9 +220,203 @@ extension {option_ffi_name} {{
         }
     }
 
+    /// Generate the `Codable` conformance for a `#[swift_bridge(Codable)]` struct.
+    ///
+    /// Values are encoded the same way that serde serializes the Rust struct, so a struct with
+    /// named fields uses the Rust field names as its keys, a tuple struct with one field is
+    /// encoded as that field and a tuple struct with many fields is encoded as an array.
+    fn generate_struct_codable_conformance(&self, shared_struct: &SharedStruct) -> String {
+        if !shared_struct.codable && !shared_struct.serde {
+            return "".to_string();
+        }
+
+        let struct_name = shared_struct.swift_name_string();
+        let generic_params = &shared_struct.generics.params;
+
+        let conformance = if generic_params.is_empty() {
+            "Codable".to_string()
+        } else {
+            let bounds: Vec<String> = generic_params
+                .iter()
+                .map(|param| format!("{}: Codable", param))
+                .collect();
+            format!("Codable where {}", bounds.join(", "))
+        };
+
+        let body = match &shared_struct.fields {
+            StructFields::Named(named) => {
+                // Swift synthesizes the encoding and decoding using our coding keys.
+                let coding_keys: Vec<String> = named
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "        case {} = \"{}\"\n",
+                            field.swift_name_string(),
+                            field.name
+                        )
+                    })
+                    .collect();
+
+                format!(
+                    r#"
+    enum CodingKeys: String, CodingKey {{
+{coding_keys}    }}
+"#,
+                    coding_keys = coding_keys.join("")
+                )
+            }
+            StructFields::Unnamed(unnamed) if unnamed.len() == 1 => {
+                let field = &unnamed[0];
+                format!(
+                    r#"
+    public init(from decoder: Decoder) throws {{
+        let container = try decoder.singleValueContainer()
+        self.init({name}: try container.decode({ty}.self))
+    }}
+
+    public func encode(to encoder: Encoder) throws {{
+        var container = encoder.singleValueContainer()
+        try container.encode(self.{name})
+    }}
+"#,
+                    name = field.swift_name_string(),
+                    ty = self.field_swift_type(&field.ty, shared_struct)
+                )
+            }
+            StructFields::Unnamed(unnamed) => {
+                let decode_fields: Vec<String> = unnamed
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "{}: try container.decode({}.self)",
+                            field.swift_name_string(),
+                            self.field_swift_type(&field.ty, shared_struct)
+                        )
+                    })
+                    .collect();
+                let encode_fields: Vec<String> = unnamed
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "        try container.encode(self.{})\n",
+                            field.swift_name_string()
+                        )
+                    })
+                    .collect();
+
+                format!(
+                    r#"
+    public init(from decoder: Decoder) throws {{
+        var container = try decoder.unkeyedContainer()
+        self.init({decode_fields})
+    }}
+
+    public func encode(to encoder: Encoder) throws {{
+        var container = encoder.unkeyedContainer()
+{encode_fields}    }}
+"#,
+                    decode_fields = decode_fields.join(", "),
+                    encode_fields = encode_fields.join("")
+                )
+            }
+            StructFields::Unit => r#"
+    public init(from decoder: Decoder) throws {
+        self.init()
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encodeNil()
+    }
+"#
+            .to_string(),
+        };
+
+        format!(
+            r#"
+extension {struct_name}: {conformance} {{{body}}}"#
+        )
+    }
+
+    /// Generate the `Equatable`, `Hashable` and `Comparable` conformances for a struct that
+    /// derives `PartialEq`, `Hash` or `PartialOrd`.
+    ///
+    /// Swift synthesizes `==` and `hash(into:)` member-wise. `<` compares the fields in
+    /// declaration order, the same way that Rust's derived `PartialOrd` does.
+    fn generate_struct_derive_conformances(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+        let generic_params = &shared_struct.generics.params;
+
+        let conformance = |protocol: &str| {
+            if generic_params.is_empty() {
+                protocol.to_string()
+            } else {
+                let bounds: Vec<String> = generic_params
+                    .iter()
+                    .map(|param| format!("{}: {}", param, protocol))
+                    .collect();
+                format!("{} where {}", protocol, bounds.join(", "))
+            }
+        };
+
+        let mut impls = "".to_string();
+
+        if shared_struct.derives.partial_eq {
+            impls += &format!(
+                "\nextension {struct_name}: {} {{}}",
+                conformance("Equatable")
+            );
+        }
+        if shared_struct.derives.hash {
+            impls += &format!(
+                "\nextension {struct_name}: {} {{}}",
+                conformance("Hashable")
+            );
+        }
+        if shared_struct.derives.partial_ord {
+            let field_names: Vec<String> = match &shared_struct.fields {
+                StructFields::Named(named) => named.iter().map(|f| f.swift_name_string()).collect(),
+                StructFields::Unnamed(unnamed) => {
+                    unnamed.iter().map(|f| f.swift_name_string()).collect()
+                }
+                StructFields::Unit => vec![],
+            };
+
+            let body = match field_names.split_last() {
+                Some((last, rest)) => {
+                    let mut body = "".to_string();
+                    for name in rest {
+                        body += &format!(
+                            r#"        if lhs.{name} != rhs.{name} {{
+            return lhs.{name} < rhs.{name}
+        }}
+"#
+                        );
+                    }
+                    body += &format!("        return lhs.{last} < rhs.{last}");
+                    body
+                }
+                None => "        return false".to_string(),
+            };
+
+            impls += &format!(
+                r#"
+extension {struct_name}: {conformance} {{
+    public static func < (lhs: {struct_name}, rhs: {struct_name}) -> Bool {{
+{body}
+    }}
+}}"#,
+                conformance = conformance("Comparable")
+            );
+        }
+
+        impls
+    }
+
     fn convert_fields_to_initializer_params<'a, T>(
         &self,
         struct_fields: impl IntoIterator<Item = &'a T>,
+        shared_struct: &SharedStruct,
     ) -> String
     where
         T: StructField + 'a,
@@ -111,16 +424,10 @@ extension {option_ffi_name} {{
         let mut params = "".to_string();
 
         for field in struct_fields.into_iter() {
-            let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
-
             params += &format!(
                 "{}: {},",
                 field.swift_name_string(),
-                bridged_ty.to_swift_type(
-                    TypePosition::SharedStructField,
-                    &self.types,
-                    &self.swift_bridge_path
-                )
+                self.field_swift_type(field.field_type(), shared_struct)
             );
         }
 
@@ -155,23 +462,21 @@ extension {option_ffi_name} {{
         body
     }
 
-    fn declare_fields<'a, T>(&self, struct_fields: impl IntoIterator<Item = &'a T>) -> String
+    fn declare_fields<'a, T>(
+        &self,
+        struct_fields: impl IntoIterator<Item = &'a T>,
+        shared_struct: &SharedStruct,
+    ) -> String
     where
         T: StructField + 'a,
     {
         let mut fields = "".to_string();
 
         for field in struct_fields.into_iter() {
-            let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
-
             fields += &format!(
                 "    public var {}: {}\n",
                 field.swift_name_string(),
-                bridged_ty.to_swift_type(
-                    TypePosition::SharedStructField,
-                    &self.types,
-                    &self.swift_bridge_path
-                )
+                self.field_swift_type(field.field_type(), shared_struct)
             );
         }
 
@@ -181,4 +486,27 @@ extension {option_ffi_name} {{
 
         fields
     }
+
+    /// The Swift type of a struct field. Fields of a generic struct that are typed as a generic
+    /// parameter, such as `x: T`, keep the parameter as their type.
+    fn field_swift_type(&self, ty: &Type, shared_struct: &SharedStruct) -> String {
+        if shared_struct.serde {
+            return serde_field_swift_type(ty, &self.types).unwrap_or_else(|_| {
+                unreachable!("Unsupported serde field types are rejected while parsing")
+            });
+        }
+
+        let generic_params = &shared_struct.generics.params;
+        if let Some(param) = generic_params.iter().find(|p| type_is_ident(ty, p)) {
+            return param.to_string();
+        }
+
+        BridgedType::new_with_type(ty, &self.types)
+            .unwrap()
+            .to_swift_type(
+                TypePosition::SharedStructField,
+                &self.types,
+                &self.swift_bridge_path,
+            )
+    }
 }
@@ -233,9 +233,69 @@ extension {ty_name}Ref: Hashable{{
             "".to_string()
         }
     };
+    let clone_method: String = {
+        if ty.attributes.clone {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref {{
+    public func clone() -> {ty_name} {{
+        {ty_name}(ptr: __swift_bridge__${ty_name}$_clone(self.ptr))
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let comparable_method: String = {
+        if ty.attributes.comparable {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref: Comparable {{
+    public static func < (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
+        __swift_bridge__${ty_name}$_less_than(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let display_method: String = {
+        if ty.attributes.display {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref: CustomStringConvertible {{
+    public var description: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_display(self.ptr)).toString()
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let debug_method: String = {
+        if ty.attributes.debug {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref: CustomDebugStringConvertible {{
+    public var debugDescription: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_debug(self.ptr)).toString()
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
     let class = format!(
         r#"
-{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}"#,
+{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}{clone_method}{comparable_method}{display_method}{debug_method}"#,
         class_decl = class_decl,
         class_ref_decl = class_ref_mut_decl,
         class_ref_mut_decl = class_ref_decl,
@@ -245,6 +305,10 @@ extension {ty_name}Ref: Hashable{{
         ref_instance_methods = ref_instance_methods,
         equatable_method = equatable_method,
         hashable_method = hashable_method,
+        clone_method = clone_method,
+        comparable_method = comparable_method,
+        display_method = display_method,
+        debug_method = debug_method,
     );
 
     return class;
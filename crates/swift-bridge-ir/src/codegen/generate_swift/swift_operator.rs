@@ -0,0 +1,297 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/operator_codegen_tests.rs
+
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
+use crate::parsed_extern_fn::SwiftOperator;
+use crate::{ParsedExternFn, SwiftBridgeModule};
+use quote::{format_ident, ToTokens};
+use std::ops::Deref;
+use syn::{FnArg, Type};
+
+/// One of the operands that is passed to an operator function.
+struct Operand {
+    /// The operand's Swift type, such as `VectorRef` or `Double`.
+    swift_ty: String,
+    /// The argument label to use when calling the function, such as `"at: "`.
+    label: String,
+    /// The name of the Rust argument.
+    arg_name: String,
+    /// Whether or not the operand's type was declared in a bridge module, in which case we can
+    /// extend it.
+    is_declared_type: bool,
+}
+
+impl SwiftBridgeModule {
+    /// Generate the Swift operators and subscripts for functions that use the
+    /// `#[swift_bridge(operator = "...")]` attribute.
+    ///
+    /// ```swift
+    /// extension VectorRef {
+    ///     public static func + (lhs: VectorRef, rhs: VectorRef) -> Vector {
+    ///         lhs.add(rhs)
+    ///     }
+    /// }
+    /// ```
+    pub(super) fn generate_swift_operators(&self) -> String {
+        let mut swift = "".to_string();
+
+        for func in self.functions.iter() {
+            let operator = match func.operator {
+                // Subscript setters are generated along with their subscript's getter.
+                Some(SwiftOperator::IndexSet) => continue,
+                Some(operator) if func.host_lang.is_rust() => operator,
+                _ => continue,
+            };
+
+            swift += &self.generate_swift_operator(func, operator);
+            swift += "\n";
+        }
+
+        swift
+    }
+
+    fn generate_swift_operator(&self, func: &ParsedExternFn, operator: SwiftOperator) -> String {
+        let operands = self.operator_operands(func);
+        let fn_name = match func.swift_name_override.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => func.sig.ident.to_string(),
+        };
+        let ret = func.to_swift_return_type(&self.types, &self.swift_bridge_path);
+        let maybe_try = if ret.contains("throws") { "try " } else { "" };
+
+        // Methods are called on their first operand, while freestanding functions are passed
+        // all of their operands.
+        let call = |names: &[&str]| {
+            if func.is_method() {
+                let args: Vec<String> = operands[1..]
+                    .iter()
+                    .zip(names[1..].iter())
+                    .map(|(operand, name)| format!("{}{}", operand.label, name))
+                    .collect();
+                format!("{}{}.{}({})", maybe_try, names[0], fn_name, args.join(", "))
+            } else {
+                let args: Vec<String> = operands
+                    .iter()
+                    .zip(names.iter())
+                    .map(|(operand, name)| format!("{}{}", operand.label, name))
+                    .collect();
+                format!("{}{}({})", maybe_try, fn_name, args.join(", "))
+            }
+        };
+
+        let extended_ty = &operands[0];
+
+        if operator == SwiftOperator::Index {
+            if let Some(setter) = self.subscript_setter(func) {
+                return self.generate_swift_subscript_with_setter(
+                    &operands[1],
+                    &ret,
+                    &call(&["self", &operands[1].arg_name]),
+                    setter,
+                );
+            }
+        }
+
+        let (maybe_static, signature, call) = match operator {
+            SwiftOperator::Index => {
+                let index = &operands[1];
+                (
+                    "",
+                    format!("subscript({}: {}){}", index.arg_name, index.swift_ty, ret),
+                    call(&["self", &index.arg_name]),
+                )
+            }
+            _ if operands.len() == 1 => (
+                "static ",
+                format!(
+                    "prefix func {} (operand: {}){}",
+                    operator.symbol(),
+                    extended_ty.swift_ty,
+                    ret
+                ),
+                call(&["operand"]),
+            ),
+            _ => (
+                "static ",
+                format!(
+                    "func {} (lhs: {}, rhs: {}){}",
+                    operator.symbol(),
+                    extended_ty.swift_ty,
+                    operands[1].swift_ty,
+                    ret
+                ),
+                call(&["lhs", "rhs"]),
+            ),
+        };
+
+        // An operator whose first operand is a built in type, such as `Double * Vector`, can't
+        // be declared in an extension of one of our types, so we declare it globally instead.
+        if extended_ty.is_declared_type || operator == SwiftOperator::Index {
+            format!(
+                r#"extension {ty} {{
+    public {maybe_static}{signature} {{
+        {call}
+    }}
+}}"#,
+                ty = extended_ty.swift_ty,
+            )
+        } else {
+            format!(
+                r#"public {signature} {{
+    {call}
+}}"#
+            )
+        }
+    }
+
+    /// Generate a subscript that can be both read and written.
+    ///
+    /// The subscript is declared on the setter's `SomeTypeRefMut` class, which inherits the
+    /// getter's method from `SomeTypeRef`.
+    ///
+    /// ```swift
+    /// extension VectorRefMut {
+    ///     public subscript(index: UInt) -> Double {
+    ///         get {
+    ///             self.get(index)
+    ///         }
+    ///         set(value) {
+    ///             self.set(index, value)
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn generate_swift_subscript_with_setter(
+        &self,
+        index: &Operand,
+        ret: &str,
+        get: &str,
+        setter: &ParsedExternFn,
+    ) -> String {
+        let operands = self.operator_operands(setter);
+        let fn_name = match setter.swift_name_override.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => setter.sig.ident.to_string(),
+        };
+        let new_value = &operands[2];
+
+        let set = format!(
+            "self.{fn_name}({index_label}{index_name}, {value_label}{value_name})",
+            index_label = operands[1].label,
+            index_name = index.arg_name,
+            value_label = new_value.label,
+            value_name = new_value.arg_name,
+        );
+
+        format!(
+            r#"extension {ty} {{
+    public subscript({index_name}: {index_ty}){ret} {{
+        get {{
+            {get}
+        }}
+        set({value_name}) {{
+            {set}
+        }}
+    }}
+}}"#,
+            ty = operands[0].swift_ty,
+            index_name = index.arg_name,
+            index_ty = index.swift_ty,
+            value_name = new_value.arg_name,
+        )
+    }
+
+    /// The `[]=` subscript setter on the same type, and with the same index type, as a `[]`
+    /// subscript getter.
+    fn subscript_setter(&self, getter: &ParsedExternFn) -> Option<&ParsedExternFn> {
+        let associated_type_name = |func: &ParsedExternFn| match func.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => Some(ty.ty.to_string()),
+            _ => None,
+        };
+        let index_ty = |func: &ParsedExternFn| {
+            func.subscript_index_ty()
+                .map(|ty| ty.to_token_stream().to_string())
+        };
+
+        self.functions.iter().find(|setter| {
+            setter.operator == Some(SwiftOperator::IndexSet)
+                && associated_type_name(setter) == associated_type_name(getter)
+                && index_ty(setter) == index_ty(getter)
+        })
+    }
+
+    fn operator_operands(&self, func: &ParsedExternFn) -> Vec<Operand> {
+        let mut operands = vec![];
+
+        for (arg_idx, arg) in func.sig.inputs.iter().enumerate() {
+            let pat_ty = match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+                _ => {
+                    operands.push(self.receiver_operand(func));
+                    continue;
+                }
+            };
+
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+            let swift_ty = BridgedType::new_with_type(&pat_ty.ty, &self.types)
+                .unwrap()
+                .to_swift_type(
+                    TypePosition::FnArg(func.host_lang, arg_idx),
+                    &self.types,
+                    &self.swift_bridge_path,
+                );
+            let label = match func.argument_labels.get(&format_ident!("{}", arg_name)) {
+                Some(label) => format!("{}: ", label.value()),
+                None => "".to_string(),
+            };
+
+            let ty = match pat_ty.ty.deref() {
+                Type::Reference(reference) => reference.elem.deref(),
+                ty => ty,
+            };
+            let is_declared_type = self.types.get(&ty.to_token_stream().to_string()).is_some();
+
+            operands.push(Operand {
+                swift_ty,
+                label,
+                arg_name,
+                is_declared_type,
+            });
+        }
+
+        operands
+    }
+
+    /// The class or struct that a method's `self` is passed as.
+    ///
+    /// `&self` -> `SomeTypeRef`, `&mut self` -> `SomeTypeRefMut`, `self` -> `SomeType`
+    fn receiver_operand(&self, func: &ParsedExternFn) -> Operand {
+        let swift_ty = match func.associated_type.as_ref().unwrap() {
+            TypeDeclaration::Opaque(ty) => {
+                let ty_name = ty.ty_name_ident().to_string();
+
+                if ty.attributes.copy.is_some() || func.self_reference().is_none() {
+                    ty_name
+                } else if func.self_mutability().is_some() {
+                    format!("{}RefMut", ty_name)
+                } else {
+                    format!("{}Ref", ty_name)
+                }
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                shared_struct.swift_name_string()
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                shared_enum.swift_name_string()
+            }
+        };
+
+        Operand {
+            swift_ty,
+            label: "".to_string(),
+            arg_name: "self".to_string(),
+            is_declared_type: true,
+        }
+    }
+}
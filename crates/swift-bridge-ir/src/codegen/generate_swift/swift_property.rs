@@ -0,0 +1,68 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/property_codegen_tests.rs
+
+use crate::bridged_type::{fn_arg_name, BridgedType, TypePosition};
+use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_property_accessor_swift_calls_rust;
+use crate::parse::HostLang;
+use crate::{ParsedExternFn, TypeDeclarations};
+use syn::{LitStr, Path};
+
+/// Generate a Swift computed property from a `#[swift_bridge(property = "...")]` getter and its
+/// optional `#[swift_bridge(setter = "...")]` setter.
+///
+/// ```swift
+/// public var name: RustString {
+///     get {
+///         RustString(ptr: __swift_bridge__$Person$name(ptr))
+///     }
+///     set(name) {
+///         __swift_bridge__$Person$set_name(ptr, { let rustString = name.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+///     }
+/// }
+/// ```
+pub(super) fn generate_swift_property(
+    name: &LitStr,
+    getter: &ParsedExternFn,
+    setter: Option<&ParsedExternFn>,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let ty = BridgedType::new_with_return_type(&getter.sig.output, types)
+        .unwrap()
+        .to_swift_type(
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+
+    let setter = match setter {
+        Some(setter) => setter,
+        None => {
+            let get =
+                gen_property_accessor_swift_calls_rust(getter, "    ", types, swift_bridge_path);
+
+            return format!(
+                r#"    public var {name}: {ty} {{
+        {get}
+    }}"#,
+                name = name.value(),
+            );
+        }
+    };
+
+    let get = gen_property_accessor_swift_calls_rust(getter, "        ", types, swift_bridge_path);
+    let set = gen_property_accessor_swift_calls_rust(setter, "        ", types, swift_bridge_path);
+    let new_value = fn_arg_name(setter.sig.inputs.iter().nth(1).unwrap()).unwrap();
+
+    format!(
+        r#"    public var {name}: {ty} {{
+        get {{
+            {get}
+        }}
+        set({new_value}) {{
+            {set}
+        }}
+    }}"#,
+        name = name.value(),
+    )
+}
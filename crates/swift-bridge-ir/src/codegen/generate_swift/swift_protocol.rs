@@ -0,0 +1,111 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/swift_protocol_codegen_tests.rs
+
+use crate::bridged_type::bridgeable_trait_object::rust_trait_object_class_name;
+use crate::codegen::generate_swift::generate_swift_class_methods;
+use crate::parse::{OpaqueForeignTypeDeclaration, TypeDeclaration};
+use crate::{ParsedExternFn, SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use std::collections::HashMap;
+
+impl SwiftBridgeModule {
+    /// Generate the Swift protocol for a `#[swift_bridge(protocol)]` type.
+    ///
+    /// ```swift
+    /// public protocol Renderer: AnyObject {
+    ///     func render(width: UInt32) -> String
+    /// }
+    /// ```
+    pub(super) fn generate_swift_protocol(&self, ty: &OpaqueForeignTypeDeclaration) -> String {
+        let requirements: Vec<String> = self
+            .functions
+            .iter()
+            .filter(|func| match func.associated_type.as_ref() {
+                Some(TypeDeclaration::Opaque(associated)) => associated.ty == ty.ty,
+                _ => false,
+            })
+            .map(|func| {
+                format!(
+                    "    {}\n",
+                    func.to_swift_protocol_requirement(&self.types, &self.swift_bridge_path)
+                )
+            })
+            .collect();
+
+        format!(
+            r#"public protocol {ty_name}: AnyObject {{
+{requirements}}}"#,
+            ty_name = ty.ty,
+            requirements = requirements.join("")
+        )
+    }
+
+    /// Generate the Swift protocol for a Rust `#[swift_bridge(protocol)]` trait, along with the
+    /// class that conforms to it by calling into a `Box<dyn SomeTrait>`.
+    ///
+    /// ```swift
+    /// public protocol Storage: AnyObject {
+    ///     func get(_ key: UInt32) -> UInt32
+    /// }
+    ///
+    /// public class RustStorage: Storage {
+    ///     // ...
+    /// }
+    /// ```
+    pub(super) fn generate_rust_trait_object_class(
+        &self,
+        ty: &OpaqueForeignTypeDeclaration,
+        associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+    ) -> String {
+        let protocol = self.generate_swift_protocol(ty);
+
+        let class_name = rust_trait_object_class_name(&ty.ty);
+        let class_methods = generate_swift_class_methods(
+            &ty.to_string(),
+            associated_funcs_and_methods,
+            &self.types,
+            &self.swift_bridge_path,
+        );
+        let methods: Vec<String> = class_methods
+            .ref_self_methods
+            .into_iter()
+            .chain(class_methods.ref_mut_self_methods)
+            .collect();
+
+        let methods = if methods.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                r#"
+extension {class_name} {{
+{methods}
+}}"#,
+                class_name = class_name,
+                methods = methods.join("\n\n")
+            )
+        };
+
+        format!(
+            r#"{protocol}
+
+public class {class_name}: {protocol_name} {{
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public init(ptr: UnsafeMutableRawPointer) {{
+        self.ptr = ptr
+    }}
+
+    deinit {{
+        if isOwned {{
+            {prefix}${protocol_name}$_free(ptr)
+        }}
+    }}
+}}{methods}"#,
+            protocol = protocol,
+            class_name = class_name,
+            protocol_name = ty.ty,
+            prefix = SWIFT_BRIDGE_PREFIX,
+            methods = methods
+        )
+    }
+}
@@ -1,4 +1,6 @@
+use crate::parse::{OpaqueForeignTypeDeclaration, TypeDeclarations};
 use proc_macro2::Ident;
+use syn::Path;
 
 /// Generate the `extension MyRustType: Vectorizable {}` for the Swift side.
 pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
@@ -51,6 +53,335 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
         __swift_bridge__$Vec_{ty}$len(vecPtr)
     }}
 }}
+"#,
+        ty = ty,
+    )
+}
+
+/// Generate the `extension SomeCopyType: Vectorizable {}` for an opaque Rust Copy type.
+///
+/// Elements are copied across the FFI boundary, so getting an element gives Swift its own copy.
+pub(super) fn generate_vectorizable_extension_for_copy_type(ty: &Ident) -> String {
+    format!(
+        r#"extension {ty}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{ty}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{ty}$drop(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {ty}) {{
+        __swift_bridge__$Vec_{ty}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let val = __swift_bridge__$Vec_{ty}$pop(vecPtr)
+        if val.is_some {{
+            return val.val.intoSwiftRepr()
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}> {{
+        let val = __swift_bridge__$Vec_{ty}$get(vecPtr, index)
+        if val.is_some {{
+            return val.val.intoSwiftRepr()
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}> {{
+        let val = __swift_bridge__$Vec_{ty}$get_mut(vecPtr, index)
+        if val.is_some {{
+            return val.val.intoSwiftRepr()
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<{ty}> {{
+        UnsafePointer<{ty}>(OpaquePointer(__swift_bridge__$Vec_{ty}$as_ptr(vecPtr)))
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{ty}$len(vecPtr)
+    }}
+}}
+"#,
+        ty = ty,
+    )
+}
+
+/// Generate the `extension SomeType: Vectorizable {}` for a generic opaque Rust type.
+///
+/// Swift rejects more than one conformance of a generic type to the same protocol, even when the
+/// conformances are conditional on different generic types. So instead of one
+/// `extension SomeType: Vectorizable where A == UInt32` per concrete instantiation, we emit a single
+/// conformance for all of the module's instantiations that picks the right Rust `Vec` functions
+/// by checking the generic types at runtime.
+pub(super) fn generate_generic_vectorizable_extension(
+    instantiations: &[&OpaqueForeignTypeDeclaration],
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let first = instantiations[0];
+    let ty = first.ty.to_string();
+    let generics = first.generics.angle_bracketed_generic_placeholders_string();
+    let is_copy = first.attributes.copy.is_some();
+
+    let instantiations: Vec<(String, String, String)> = instantiations
+        .iter()
+        .map(|instantiation| {
+            let ffi_name = format!(
+                "{}{}",
+                ty,
+                instantiation.generics.dollar_prefixed_generics_string()
+            );
+            let concrete_generics = instantiation
+                .generics
+                .angle_bracketed_generic_concrete_swift_types_string(types, swift_bridge_path);
+            let type_checks = instantiation
+                .generics
+                .rust_opaque_type_swift_generic_type_checks(types, swift_bridge_path);
+
+            (ffi_name, concrete_generics, type_checks)
+        })
+        .collect();
+
+    let dispatch = |body: &dyn Fn(&str, &str) -> String| -> String {
+        let mut dispatch = "".to_string();
+
+        for (ffi_name, concrete_generics, type_checks) in instantiations.iter() {
+            dispatch += &format!(
+                "        if {type_checks} {{\n{body}        }}\n",
+                type_checks = type_checks,
+                body = body(ffi_name, concrete_generics)
+            );
+        }
+
+        dispatch += &format!(
+            r#"        fatalError("RustVec<{ty}{generics}> is not supported for this generic instantiation")"#,
+            ty = ty,
+            generics = generics
+        );
+        dispatch
+    };
+
+    let (elem_ref, elem_ref_mut) = if is_copy {
+        (format!("{}{}", ty, generics), format!("{}{}", ty, generics))
+    } else {
+        (
+            format!("{}Ref{}", ty, generics),
+            format!("{}RefMut{}", ty, generics),
+        )
+    };
+
+    let new = dispatch(&|ffi_name, _| {
+        format!(
+            "            return __swift_bridge__$Vec_{ffi_name}$new()\n",
+            ffi_name = ffi_name
+        )
+    });
+    let free = dispatch(&|ffi_name, _| {
+        format!(
+            "            return __swift_bridge__$Vec_{ffi_name}$drop(vecPtr)\n",
+            ffi_name = ffi_name
+        )
+    });
+    let len = dispatch(&|ffi_name, _| {
+        format!(
+            "            return __swift_bridge__$Vec_{ffi_name}$len(vecPtr)\n",
+            ffi_name = ffi_name
+        )
+    });
+    let as_ptr = dispatch(&|ffi_name, _| {
+        format!(
+            "            return UnsafePointer<{elem_ref}>(OpaquePointer(__swift_bridge__$Vec_{ffi_name}$as_ptr(vecPtr)))\n",
+            elem_ref = elem_ref,
+            ffi_name = ffi_name
+        )
+    });
+
+    let (push, pop, get, get_mut) = if is_copy {
+        let push = dispatch(&|ffi_name, concrete_generics| {
+            format!(
+                "            return __swift_bridge__$Vec_{ffi_name}$push(vecPtr, (value as! {ty}{concrete_generics}).intoFfiRepr())\n",
+                ffi_name = ffi_name,
+                ty = ty,
+                concrete_generics = concrete_generics
+            )
+        });
+        let copy_out = |func: &str, ret: &str| {
+            dispatch(&|ffi_name, _| {
+                format!(
+                    r#"            let val = __swift_bridge__$Vec_{ffi_name}${func}
+            if val.is_some {{
+                return (val.val.intoSwiftRepr() as! {ret})
+            }} else {{
+                return nil
+            }}
+"#,
+                    ffi_name = ffi_name,
+                    func = func,
+                    ret = ret,
+                )
+            })
+        };
+        (
+            push,
+            copy_out("pop(vecPtr)", "Self"),
+            copy_out("get(vecPtr, index)", &elem_ref),
+            copy_out("get_mut(vecPtr, index)", &elem_ref_mut),
+        )
+    } else {
+        let push = dispatch(&|ffi_name, _| {
+            format!(
+                "            return __swift_bridge__$Vec_{ffi_name}$push(vecPtr, {{value.isOwned = false; return value.ptr;}}())\n",
+                ffi_name = ffi_name
+            )
+        });
+        let pointer_out = |func: &str, ret: &str| {
+            dispatch(&|ffi_name, _| {
+                format!(
+                    r#"            let pointer = __swift_bridge__$Vec_{ffi_name}${func}
+            if pointer == nil {{
+                return nil
+            }} else {{
+                return {ret}
+            }}
+"#,
+                    ffi_name = ffi_name,
+                    func = func,
+                    ret = ret,
+                )
+            })
+        };
+
+        (
+            push,
+            pointer_out(
+                "pop(vecPtr)",
+                &format!("({}{}(ptr: pointer!) as! Self)", ty, generics),
+            ),
+            pointer_out(
+                "get(vecPtr, index)",
+                &format!("{}(ptr: pointer!)", elem_ref),
+            ),
+            pointer_out(
+                "get_mut(vecPtr, index)",
+                &format!("{}(ptr: pointer!)", elem_ref_mut),
+            ),
+        )
+    };
+
+    format!(
+        r#"extension {ty}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+{new}
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+{free}
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {ty}{generics}) {{
+{push}
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+{pop}
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{elem_ref}> {{
+{get}
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{elem_ref_mut}> {{
+{get_mut}
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<{elem_ref}> {{
+{as_ptr}
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+{len}
+    }}
+}}
+"#,
+        ty = ty,
+        generics = generics,
+        elem_ref = elem_ref,
+        elem_ref_mut = elem_ref_mut,
+        new = new,
+        free = free,
+        push = push,
+        pop = pop,
+        get = get,
+        get_mut = get_mut,
+        as_ptr = as_ptr,
+        len = len,
+    )
+}
+
+/// Generate the `extension MySwiftType: Vectorizable {}` for an opaque Swift type.
+///
+/// The Rust `Vec` holds a retained reference to each Swift class instance. Popping an element
+/// hands that reference back to Swift, while getting an element leaves it in the `Vec`.
+pub(super) fn generate_vectorizable_extension_for_swift_type(ty: &Ident) -> String {
+    format!(
+        r#"extension {ty}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{ty}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{ty}$drop(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {ty}) {{
+        __swift_bridge__$Vec_{ty}$push(vecPtr, Unmanaged.passRetained(value).toOpaque())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let pointer = __swift_bridge__$Vec_{ty}$pop(vecPtr)
+        if pointer == nil {{
+            return nil
+        }} else {{
+            return (Unmanaged<{ty}>.fromOpaque(pointer!).takeRetainedValue() as! Self)
+        }}
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}> {{
+        let pointer = __swift_bridge__$Vec_{ty}$get(vecPtr, index)
+        if pointer == nil {{
+            return nil
+        }} else {{
+            return Unmanaged<{ty}>.fromOpaque(pointer!).takeUnretainedValue()
+        }}
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}> {{
+        let pointer = __swift_bridge__$Vec_{ty}$get_mut(vecPtr, index)
+        if pointer == nil {{
+            return nil
+        }} else {{
+            return Unmanaged<{ty}>.fromOpaque(pointer!).takeUnretainedValue()
+        }}
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<{ty}> {{
+        UnsafePointer<{ty}>(OpaquePointer(__swift_bridge__$Vec_{ty}$as_ptr(vecPtr)))
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{ty}$len(vecPtr)
+    }}
+}}
 "#,
         ty = ty.to_string()
     )
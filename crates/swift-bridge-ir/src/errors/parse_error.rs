@@ -1,3 +1,5 @@
+use crate::bridged_type::ComparisonProtocol;
+use crate::parsed_extern_fn::SwiftOperator;
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::{Error, FnArg, Item, Receiver};
@@ -40,6 +42,11 @@ pub(crate) enum ParseError {
     StructUnrecognizedAttribute { attribute: Ident },
     /// An enum was declared with an unrecognized attribute.
     EnumUnrecognizedAttribute { attribute: Ident },
+    /// `struct Point<f32>;` was declared without first declaring `struct Point<T> { ... }`.
+    GenericStructNotDeclared { struct_ident: Ident },
+    /// A generic struct field used a generic parameter inside of another type,
+    /// such as `Option<T>`. Generic parameters must be a field's entire type.
+    GenericStructFieldNestedParam { ty: Type },
     /// There is no reason to use `swift_repr = "class"` on an empty struct.
     /// It's extra overhead with no advantages.
     EmptyStructHasSwiftReprClass {
@@ -56,11 +63,83 @@ pub(crate) enum ParseError {
     InvalidModuleItem { item: Item },
     /// The associated_to attribute is used for only an associated method.
     InvalidAssociatedTo { self_: FnArg },
+    /// A `#[swift_bridge(protocol)]` type's functions become protocol requirements, so they
+    /// must take `&self` or `&mut self`.
+    ProtocolFunctionNotRefSelf { fn_ident: Ident },
+    /// A type was used somewhere that it is not yet supported, such as
+    /// `Option<HashMap<K, V>>` or a `[T; N]` shared struct field.
+    UnsupportedType { ty: Type, message: String },
+    /// An `extern "Swift"` function returned a reference, such as a `&SomeRustType`, without
+    /// taking `&self` or exactly one reference argument that the returned reference could borrow
+    /// from.
+    /// A `&str` or `Option<&str>` is allowed, since Swift can return a buffer that it owns.
+    SwiftFunctionReturnsUnboundReference { fn_ident: Ident, ty: Type },
+    /// A field of a `#[swift_bridge(Codable)]` struct or enum has a type that does not conform to
+    /// `Codable` in Swift, such as a `Vec<T>` or an opaque type.
+    CodableFieldNotCodable { ty: Type },
+    /// A field of a struct or enum that derives `PartialEq`, `Hash` or `PartialOrd` has a type
+    /// that does not conform to the corresponding Swift protocol, such as a `Vec<T>`.
+    DeriveFieldDoesNotConform {
+        ty: Type,
+        protocol: ComparisonProtocol,
+    },
 }
 
 /// An error while parsing a function attribute.
 pub(crate) enum FunctionAttributeParseError {
     Identifiable(IdentifiableParseError),
+    Monomorphize(MonomorphizeParseError),
+    Operator(OperatorParseError),
+    Property(PropertyParseError),
+}
+
+/// An error while parsing a function's `property` or `setter` attribute.
+pub(crate) enum PropertyParseError {
+    /// A property getter must be an `extern "Rust"` method that takes `&self` and returns a
+    /// value.
+    InvalidGetter { fn_ident: Ident },
+    /// A property setter must be an `extern "Rust"` method that takes `&mut self` and one
+    /// argument, and does not return a value.
+    InvalidSetter { fn_ident: Ident },
+    /// A setter must have a getter with the same property name.
+    SetterWithoutGetter { fn_ident: Ident, name: LitStr },
+    /// A setter's argument must have the same type that its getter returns.
+    SetterTypeMismatch {
+        setter_ty: Box<Type>,
+        getter_ty: Box<Type>,
+    },
+}
+
+/// An error while parsing a function's `operator` attribute.
+pub(crate) enum OperatorParseError {
+    /// The function takes the wrong number of arguments for the operator.
+    WrongNumberOfArguments {
+        fn_ident: Ident,
+        operator: SwiftOperator,
+    },
+    /// An operator function must return a value.
+    MissingReturnType { fn_ident: Ident },
+    /// Only `extern "Rust"` functions can implement Swift operators.
+    ExternSwiftFunction { fn_ident: Ident },
+    /// At least one operand must be a type that is declared in a bridge module, since Swift
+    /// already implements operators such as `Double + Double`.
+    NoDeclaredOperand {
+        fn_ident: Ident,
+        operator: SwiftOperator,
+    },
+    /// A subscript setter must be a method on an opaque Rust type that takes `&mut self`, an
+    /// index and a value, and does not return a value.
+    InvalidSubscriptSetter { fn_ident: Ident },
+    /// A subscript setter must have a subscript getter with the same index type.
+    SubscriptSetterWithoutGetter { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `monomorphize` attribute.
+pub(crate) enum MonomorphizeParseError {
+    /// An instantiation must have one concrete type for each of the function's generics.
+    WrongNumberOfGenerics { fn_ident: Ident, expected: usize },
+    /// Only `extern "Rust"` functions can be monomorphized.
+    ExternSwiftFunction { fn_ident: Ident },
 }
 
 /// An error while parsing a function's `Identifiable` attribute.
@@ -177,6 +256,25 @@ struct {struct_name};
                 let message = format!(r#"Did not recognize enum attribute "{}"."#, attribute);
                 Error::new_spanned(attribute, message)
             }
+            ParseError::GenericStructNotDeclared { struct_ident } => {
+                let message = format!(
+                    r#"Generic struct {struct_name} must be declared before it is instantiated.
+ 
+```
+#[swift_bridge(swift_repr = "struct")]
+struct {struct_name}<T> {{
+    // ... fields ...
+}}
+```
+"#,
+                    struct_name = struct_ident
+                );
+                Error::new_spanned(struct_ident, message)
+            }
+            ParseError::GenericStructFieldNestedParam { ty } => {
+                let message = r#"Generic parameters can only be used as a field's entire type, such as `field: T`."#;
+                Error::new_spanned(ty, message)
+            }
             ParseError::FunctionAttribute(fn_attrib) => match fn_attrib {
                 FunctionAttributeParseError::Identifiable(identifiable) => match identifiable {
                     IdentifiableParseError::MustBeRefSelf { fn_ident } => {
@@ -194,6 +292,108 @@ struct {struct_name};
                         Error::new_spanned(fn_ident, message)
                     }
                 },
+                FunctionAttributeParseError::Monomorphize(monomorphize) => match monomorphize {
+                    MonomorphizeParseError::WrongNumberOfGenerics { fn_ident, expected } => {
+                        let message = format!(
+                            r#"Each monomorphization of function {} must have {} concrete type(s)."#,
+                            fn_ident, expected
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    MonomorphizeParseError::ExternSwiftFunction { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} cannot be monomorphized since only extern "Rust" functions can be generic."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Operator(operator) => match operator {
+                    OperatorParseError::WrongNumberOfArguments { fn_ident, operator } => {
+                        let expected = match operator {
+                            SwiftOperator::Sub => "one or two arguments",
+                            SwiftOperator::Not => "one argument",
+                            SwiftOperator::IndexSet => "three arguments",
+                            _ => "two arguments",
+                        };
+                        let message = format!(
+                            r#"Operator function {} must take {} for the "{}" operator, including `self`."#,
+                            fn_ident,
+                            expected,
+                            operator.symbol()
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    OperatorParseError::MissingReturnType { fn_ident } => {
+                        let message =
+                            format!(r#"Operator function {} must have a return type."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    OperatorParseError::ExternSwiftFunction { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} cannot be a Swift operator since only extern "Rust" functions can implement operators."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    OperatorParseError::NoDeclaredOperand { fn_ident, operator } => {
+                        let message = format!(
+                            r#"Operator function {} must take at least one type that is declared in a bridge module, since Swift already implements the "{}" operator for built in types."#,
+                            fn_ident,
+                            operator.symbol()
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    OperatorParseError::InvalidSubscriptSetter { fn_ident } => {
+                        let message = format!(
+                            r#"Subscript setter {} must be an extern "Rust" method that takes `&mut self`, an index and a value, and does not return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    OperatorParseError::SubscriptSetterWithoutGetter { fn_ident } => {
+                        let message = format!(
+                            r#"Subscript setter {} needs a method with #[swift_bridge(operator = "[]")] on the same type that takes the same index type."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Property(property) => match property {
+                    PropertyParseError::InvalidGetter { fn_ident } => {
+                        let message = format!(
+                            r#"Property getter {} must be an extern "Rust" method that takes `&self` and returns a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::InvalidSetter { fn_ident } => {
+                        let message = format!(
+                            r#"Property setter {} must be an extern "Rust" method that takes `&mut self` and one argument, and does not return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::SetterWithoutGetter { fn_ident, name } => {
+                        let message = format!(
+                            r#"Setter {} needs a method with #[swift_bridge(property = "{}")] on the same type."#,
+                            fn_ident,
+                            name.value()
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::SetterTypeMismatch {
+                        setter_ty,
+                        getter_ty,
+                    } => {
+                        let message = format!(
+                            r#"Setter argument type {} must be the same as the property's getter return type {}."#,
+                            setter_ty.to_token_stream(),
+                            getter_ty.to_token_stream()
+                        );
+                        Error::new_spanned(setter_ty, message)
+                    }
+                },
             },
             ParseError::ArgCopyAndRefMut { arg } => {
                 let message =
@@ -209,6 +409,37 @@ struct {struct_name};
                     format!(r#"The associated_to attribute can only be used on static methods."#);
                 Error::new_spanned(self_, message)
             }
+            ParseError::ProtocolFunctionNotRefSelf { fn_ident } => {
+                let message = format!(
+                    r#"Protocol function {} must take `&self` or `&mut self` as its first argument."#,
+                    fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
+            ParseError::UnsupportedType { ty, message } => Error::new_spanned(ty, message),
+            ParseError::SwiftFunctionReturnsUnboundReference { fn_ident, ty } => {
+                let message = format!(
+                    r#"Swift function {} returns a reference, so it must take `&self` or exactly one reference argument that the returned reference borrows from."#,
+                    fn_ident
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::CodableFieldNotCodable { ty } => {
+                let message = format!(
+                    r#"{} does not conform to Codable in Swift, so it can not be a field of a #[swift_bridge(Codable)] type."#,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::DeriveFieldDoesNotConform { ty, protocol } => {
+                let message = format!(
+                    r#"{} does not conform to {} in Swift, so it can not be a field of a type that derives {}."#,
+                    ty.to_token_stream(),
+                    protocol.swift_name(),
+                    protocol.rust_derive()
+                );
+                Error::new_spanned(ty, message)
+            }
         }
     }
 }
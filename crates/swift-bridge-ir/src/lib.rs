@@ -15,7 +15,7 @@ use crate::parse::TypeDeclarations;
 use crate::parsed_extern_fn::ParsedExternFn;
 
 pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
-pub use self::codegen::CodegenConfig;
+pub use self::codegen::{CodegenConfig, GenericInstantiation};
 
 mod errors;
 mod parse;
@@ -1,14 +1,21 @@
 use crate::bridge_module_attributes::CfgAttr;
-use crate::bridged_type::BridgedType;
-use crate::errors::{ParseError, ParseErrors};
+use crate::bridged_type::bridgeable_serde_type::serde_field_swift_type;
+use crate::bridged_type::{
+    pat_type_pat_is_self, BridgeableType, BridgedType, ComparisonProtocol, TypePosition, TypeUsage,
+};
+use crate::errors::{
+    FunctionAttributeParseError, OperatorParseError, ParseError, ParseErrors, PropertyParseError,
+};
 use crate::parse::parse_enum::SharedEnumDeclarationParser;
 use crate::parse::parse_extern_mod::ForeignModParser;
 use crate::parse::parse_struct::SharedStructDeclarationParser;
-use crate::SwiftBridgeModule;
+use crate::parsed_extern_fn::SwiftOperator;
+use crate::{ParsedExternFn, SwiftBridgeModule};
 use proc_macro2::TokenTree;
 use quote::{quote, ToTokens};
+use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Item, ItemMod, Token};
+use syn::{FnArg, Item, ItemMod, ReturnType, Token, Type};
 
 mod parse_enum;
 mod parse_extern_mod;
@@ -90,8 +97,29 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             errors: &mut errors,
                         }
                         .parse()?;
+
+                        let shared_struct = if shared_struct.generics.concrete.is_empty() {
+                            shared_struct
+                        } else {
+                            match type_declarations.get_generic_shared_struct(
+                                &shared_struct.name,
+                                shared_struct.generics.concrete.len(),
+                            ) {
+                                Some(declaration) => declaration.instantiate(
+                                    shared_struct.generics.concrete,
+                                    shared_struct.already_declared,
+                                ),
+                                None => {
+                                    errors.push(ParseError::GenericStructNotDeclared {
+                                        struct_ident: shared_struct.name,
+                                    });
+                                    continue;
+                                }
+                            }
+                        };
+
                         type_declarations.insert(
-                            shared_struct.name.to_string(),
+                            shared_struct.type_declaration_key(),
                             TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)),
                         );
                     }
@@ -113,6 +141,86 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 };
             }
 
+            // A setter's getter may be declared in a different extern block, so we check for it
+            // once all of the functions have been parsed.
+            for setter_fn in functions.iter() {
+                let name = match setter_fn.setter.as_ref() {
+                    Some(name) => name,
+                    None => continue,
+                };
+                let getter_fn = functions.iter().find(|getter_fn| {
+                    getter_fn.property.as_ref().map(|property| property.value())
+                        == Some(name.value())
+                        && opaque_associated_type_name(getter_fn)
+                            == opaque_associated_type_name(setter_fn)
+                });
+                let getter_fn = match getter_fn {
+                    Some(getter_fn) => getter_fn,
+                    None => {
+                        errors.push(ParseError::FunctionAttribute(
+                            FunctionAttributeParseError::Property(
+                                PropertyParseError::SetterWithoutGetter {
+                                    fn_ident: setter_fn.func.sig.ident.clone(),
+                                    name: name.clone(),
+                                },
+                            ),
+                        ));
+                        continue;
+                    }
+                };
+
+                // Invalid getters and setters are reported while parsing their attributes.
+                let getter_ty = match &getter_fn.func.sig.output {
+                    ReturnType::Type(_, ty) => ty,
+                    ReturnType::Default => continue,
+                };
+                let setter_ty = match setter_fn.func.sig.inputs.iter().nth(1) {
+                    Some(FnArg::Typed(pat_ty)) => &pat_ty.ty,
+                    _ => continue,
+                };
+                if setter_ty.to_token_stream().to_string()
+                    != getter_ty.to_token_stream().to_string()
+                {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Property(
+                            PropertyParseError::SetterTypeMismatch {
+                                setter_ty: setter_ty.clone(),
+                                getter_ty: getter_ty.clone(),
+                            },
+                        ),
+                    ));
+                }
+            }
+
+            // A subscript setter's getter may also be declared in a different extern block.
+            for setter_fn in functions.iter() {
+                if setter_fn.operator != Some(SwiftOperator::IndexSet) {
+                    continue;
+                }
+                let index_ty = setter_fn
+                    .subscript_index_ty()
+                    .map(|ty| ty.to_token_stream().to_string());
+                let has_getter = functions.iter().any(|getter_fn| {
+                    getter_fn.operator == Some(SwiftOperator::Index)
+                        && opaque_associated_type_name(getter_fn)
+                            == opaque_associated_type_name(setter_fn)
+                        && getter_fn
+                            .subscript_index_ty()
+                            .map(|ty| ty.to_token_stream().to_string())
+                            == index_ty
+                });
+
+                if !has_getter {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Operator(
+                            OperatorParseError::SubscriptSetterWithoutGetter {
+                                fn_ident: setter_fn.func.sig.ident.clone(),
+                            },
+                        ),
+                    ));
+                }
+            }
+
             for unresolved_type in unresolved_types.into_iter() {
                 if BridgedType::new_with_type(&unresolved_type, &type_declarations).is_some() {
                     continue;
@@ -123,6 +231,130 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 });
             }
 
+            type_declarations.set_module_id(module_id(&functions));
+
+            for func in functions.iter() {
+                for (idx, arg) in func.func.sig.inputs.iter().enumerate() {
+                    if let FnArg::Typed(pat_ty) = arg {
+                        check_type_usage(
+                            &pat_ty.ty,
+                            TypePosition::FnArg(func.host_lang, idx),
+                            &type_declarations,
+                            &mut errors,
+                        );
+                    }
+                }
+                if let ReturnType::Type(_, ty) = &func.func.sig.output {
+                    // An async Rust function passes its return value to Swift's completion
+                    // callback instead of returning it.
+                    let is_async = func.func.sig.asyncness.is_some();
+                    let position = if func.host_lang.is_rust() && is_async {
+                        TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy
+                    } else {
+                        TypePosition::FnReturn(func.host_lang)
+                    };
+                    check_type_usage(ty, position, &type_declarations, &mut errors);
+
+                    if func.host_lang.is_swift()
+                        && !func.returned_reference_is_bound(&type_declarations)
+                        && func.swift_owned_str_return(&type_declarations).is_none()
+                    {
+                        errors.push(ParseError::SwiftFunctionReturnsUnboundReference {
+                            fn_ident: func.func.sig.ident.clone(),
+                            ty: *ty.clone(),
+                        });
+                    }
+                }
+
+                check_serde_fn_usage(func, &type_declarations, &mut errors);
+
+                if let Some(operator) = func.operator {
+                    if func.host_lang.is_rust()
+                        && !operator_has_declared_operand(func, &type_declarations)
+                    {
+                        errors.push(ParseError::FunctionAttribute(
+                            FunctionAttributeParseError::Operator(
+                                OperatorParseError::NoDeclaredOperand {
+                                    fn_ident: func.func.sig.ident.clone(),
+                                    operator,
+                                },
+                            ),
+                        ));
+                    }
+                }
+            }
+            for ty in type_declarations.types() {
+                if let TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) = ty {
+                    if shared_struct.codable && !shared_struct.serde {
+                        for field in shared_struct.fields.normalized_fields() {
+                            check_codable_field(&field.ty, &type_declarations, &mut errors);
+                        }
+                    }
+
+                    // Serde structs are passed over FFI as JSON, so their fields only need a
+                    // Swift type to be decoded into.
+                    if shared_struct.serde {
+                        for field in shared_struct.fields.normalized_fields() {
+                            check_serde_field(&field.ty, &type_declarations, &mut errors);
+                        }
+                        continue;
+                    }
+
+                    let derives = &shared_struct.derives;
+                    let derives = [
+                        (derives.partial_eq, ComparisonProtocol::Equatable),
+                        (derives.hash, ComparisonProtocol::Hashable),
+                        (derives.partial_ord, ComparisonProtocol::Comparable),
+                    ];
+                    for field in shared_struct.fields.normalized_fields() {
+                        check_type_usage(
+                            &field.ty,
+                            TypePosition::SharedStructField,
+                            &type_declarations,
+                            &mut errors,
+                        );
+                        check_derive_field(&field.ty, derives, &type_declarations, &mut errors);
+                    }
+                }
+
+                if let TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) = ty {
+                    if shared_enum.serde {
+                        for variant in shared_enum.variants.iter() {
+                            for field in variant.fields.normalized_fields() {
+                                check_serde_field(&field.ty, &type_declarations, &mut errors);
+                            }
+                        }
+                    }
+
+                    if shared_enum.codable && !shared_enum.serde {
+                        for variant in shared_enum.variants.iter() {
+                            for field in variant.fields.normalized_fields() {
+                                check_codable_field(&field.ty, &type_declarations, &mut errors);
+                            }
+                        }
+                    }
+
+                    if !shared_enum.serde {
+                        let derives = &shared_enum.derive;
+                        let derives = [
+                            (derives.partial_eq, ComparisonProtocol::Equatable),
+                            (derives.hash, ComparisonProtocol::Hashable),
+                            (derives.partial_ord, ComparisonProtocol::Comparable),
+                        ];
+                        for variant in shared_enum.variants.iter() {
+                            for field in variant.fields.normalized_fields() {
+                                check_derive_field(
+                                    &field.ty,
+                                    derives,
+                                    &type_declarations,
+                                    &mut errors,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
             let module = SwiftBridgeModule {
                 name: module_name,
                 types: type_declarations,
@@ -161,9 +393,189 @@ fn move_input_cursor_to_next_comma(input: ParseStream) {
     }
 }
 
+/// Report an error if the type is used somewhere that it is not yet supported, such as
+/// `Option<HashMap<K, V>>`.
+///
+/// Types that we can not resolve are skipped, since we report those separately.
+fn check_type_usage(
+    ty: &Type,
+    position: TypePosition,
+    types: &TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    let bridged = match BridgedType::new_with_type(ty, types) {
+        Some(bridged) => bridged,
+        None => return,
+    };
+
+    if let Err(message) = bridged.check_usage(TypeUsage::Direct(position), types) {
+        errors.push(ParseError::UnsupportedType {
+            ty: ty.clone(),
+            message,
+        });
+    }
+}
+
+/// Report an error if a field of a `#[swift_bridge(Codable)]` type can not be encoded or decoded
+/// in Swift, such as a `Vec<T>` that Swift sees as a `RustVec<T>` class.
+///
+/// Types that we can not resolve, such as generic parameters, are skipped.
+fn check_codable_field(ty: &Type, types: &TypeDeclarations, errors: &mut ParseErrors) {
+    let bridged = match BridgedType::new_with_type(ty, types) {
+        Some(bridged) => bridged,
+        None => return,
+    };
+
+    if !bridged.is_swift_codable(types) {
+        errors.push(ParseError::CodableFieldNotCodable { ty: ty.clone() });
+    }
+}
+
+/// Report an error if a field of a type that derives `PartialEq`, `Hash` or `PartialOrd` does not
+/// conform to the Swift protocol that we generate for that derive, such as a `Vec<T>` that Swift
+/// sees as a `RustVec<T>` class.
+///
+/// Types that we can not resolve, such as generic parameters, are skipped.
+fn check_derive_field(
+    ty: &Type,
+    derives: [(bool, ComparisonProtocol); 3],
+    types: &TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    let bridged = match BridgedType::new_with_type(ty, types) {
+        Some(bridged) => bridged,
+        None => return,
+    };
+
+    for (derived, protocol) in derives {
+        if derived && !bridged.conforms_to_swift_comparison_protocol(protocol, types) {
+            errors.push(ParseError::DeriveFieldDoesNotConform {
+                ty: ty.clone(),
+                protocol,
+            });
+            return;
+        }
+    }
+}
+
+/// Report an error if a field of a `#[swift_bridge(serde)]` type has no Swift type that it can be
+/// decoded into, such as a `u128`.
+fn check_serde_field(ty: &Type, types: &TypeDeclarations, errors: &mut ParseErrors) {
+    if let Err(unsupported) = serde_field_swift_type(ty, types) {
+        errors.push(ParseError::UnsupportedType {
+            ty: unsupported.clone(),
+            message: "#[swift_bridge(serde)] type fields must be primitives, strings, Box<T>, \
+Option<T>, Vec<T> and other sequences, HashMap<K, V> and other maps, or other \
+#[swift_bridge(serde)] types."
+                .to_string(),
+        });
+    }
+}
+
+/// Report an error if a `#[swift_bridge(serde)]` type is passed to an extern "Rust" function
+/// whose generated Swift code can not throw, such as an async function or a property accessor.
+///
+/// An async function's return type is checked by `check_type_usage`, so we only check it here for
+/// property accessors.
+fn check_serde_fn_usage(func: &ParsedExternFn, types: &TypeDeclarations, errors: &mut ParseErrors) {
+    if !func.host_lang.is_rust() {
+        return;
+    }
+
+    let is_accessor = func.property.is_some() || func.setter.is_some();
+    let message = if is_accessor {
+        "#[swift_bridge(serde)] types can not yet be used in property getters or setters."
+    } else if func.func.sig.asyncness.is_some() {
+        "#[swift_bridge(serde)] types can not yet be used in async functions."
+    } else {
+        return;
+    };
+
+    let mut tys: Vec<&Type> = func
+        .func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) => Some(pat_ty.ty.as_ref()),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    if let (true, ReturnType::Type(_, ty)) = (is_accessor, &func.func.sig.output) {
+        tys.push(ty);
+    }
+
+    for ty in tys {
+        let contains_serde_type = BridgedType::new_with_type(ty, types)
+            .map(|bridged| bridged.contains_serde_type_recursive())
+            .unwrap_or(false);
+
+        if contains_serde_type {
+            errors.push(ParseError::UnsupportedType {
+                ty: ty.clone(),
+                message: message.to_string(),
+            });
+        }
+    }
+}
+
+/// A hash of the names of the module's functions.
+///
+/// Every function in a crate gets its own link name, so this is unique to the module. We hash the
+/// names the same way in the `#[swift_bridge::bridge]` macro and in `swift-bridge-build` so that
+/// both agree on the names of the `extern "C"` functions that use it.
+fn module_id(functions: &[ParsedExternFn]) -> String {
+    // 64-bit FNV-1a
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for func in functions {
+        let associated_type = match func.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => ty.ty.to_string(),
+            Some(TypeDeclaration::Shared(SharedTypeDeclaration::Struct(ty))) => ty.name.to_string(),
+            Some(TypeDeclaration::Shared(SharedTypeDeclaration::Enum(ty))) => ty.name.to_string(),
+            None => "".to_string(),
+        };
+        let name = format!("{}${};", associated_type, func.func.sig.ident);
+
+        for byte in name.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    format!("{:016x}", hash)
+}
+
+/// The name of the opaque type that a function is associated to, if any.
+/// Whether or not one of an operator function's operands is a type that is declared in a bridge
+/// module, such as `self` or a `&Vector`.
+///
+/// Swift already implements operators for its built in types, so an operator such as
+/// `f64 + f64` would collide with the standard library.
+fn operator_has_declared_operand(func: &ParsedExternFn, types: &TypeDeclarations) -> bool {
+    func.func.sig.inputs.iter().any(|arg| match arg {
+        FnArg::Receiver(_) => true,
+        FnArg::Typed(pat_ty) if pat_type_pat_is_self(pat_ty) => true,
+        FnArg::Typed(pat_ty) => {
+            let ty = match pat_ty.ty.deref() {
+                Type::Reference(reference) => reference.elem.deref(),
+                ty => ty,
+            };
+            types.get(&ty.to_token_stream().to_string()).is_some()
+        }
+    })
+}
+
+fn opaque_associated_type_name(func: &ParsedExternFn) -> Option<String> {
+    match func.associated_type.as_ref() {
+        Some(TypeDeclaration::Opaque(ty)) => Some(ty.ty.to_string()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parsed_extern_fn::SwiftOwnedStr;
     use crate::test_utils::{parse_errors, parse_ok};
 
     /// Verify that we can parse a cfg feature from a module.
@@ -207,4 +619,122 @@ mod tests {
             _ => panic!(),
         }
     }
+
+    /// Verify that we get an error if an extern "Swift" function returns a reference that Rust
+    /// can't tell the lifetime of.
+    #[test]
+    fn error_if_swift_function_returns_unbound_reference() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    type SomeRustType;
+                }
+
+                extern "Swift" {
+                    fn no_args() -> &SomeRustType;
+                    fn two_args(a: &str, b: &str) -> (&str, u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for (error, expected_fn) in errors.iter().zip(["no_args", "two_args"]) {
+            match error {
+                ParseError::SwiftFunctionReturnsUnboundReference { fn_ident, .. } => {
+                    assert_eq!(fn_ident, expected_fn);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that an extern "Swift" function can return a reference that borrows from its only
+    /// reference argument or from `&self`.
+    #[test]
+    fn swift_function_returns_bound_reference() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Swift" {
+                    type SomeSwiftType;
+
+                    fn one_arg(a: &str, b: u8) -> &str;
+                    fn option_arg(a: Option<&str>) -> Option<&str>;
+                    fn static_str() -> &'static str;
+                    fn method(&self, a: &str, b: &str) -> &str;
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
+
+    /// Verify that an extern "Swift" function can return a `&str` or `Option<&str>` with nothing
+    /// to borrow from, since Swift returns a buffer that it owns.
+    #[test]
+    fn swift_function_returns_swift_owned_str() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    type SomeRustType;
+                }
+
+                extern "Swift" {
+                    fn no_args() -> &str;
+                    fn two_args(a: &str, b: &str) -> Option<&str>;
+                    fn opaque_arg(a: &SomeRustType) -> &str;
+                    fn bound(a: &str) -> &str;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let swift_owned_strs: Vec<Option<SwiftOwnedStr>> = module
+            .functions
+            .iter()
+            .map(|func| func.swift_owned_str_return(&module.types))
+            .collect();
+        assert_eq!(
+            swift_owned_strs,
+            vec![
+                Some(SwiftOwnedStr::Str),
+                Some(SwiftOwnedStr::OptionStr),
+                Some(SwiftOwnedStr::Str),
+                None
+            ]
+        );
+    }
+
+    /// Verify that modules with different functions get different ids, and that parsing the same
+    /// module twice gives the same id.
+    #[test]
+    fn module_id_is_unique_to_module() {
+        let module_a = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn a();
+                }
+            }
+        };
+        let module_b = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn b();
+                }
+            }
+        };
+
+        let id_a = parse_ok(module_a.clone()).types.module_id().to_string();
+        let id_b = parse_ok(module_b).types.module_id().to_string();
+
+        assert_ne!(id_a, id_b);
+        assert_eq!(id_a, parse_ok(module_a).types.module_id());
+    }
 }
@@ -34,6 +34,8 @@ impl<'a> SharedEnumDeclarationParser<'a> {
             already_declared: attribs.swift_bridge.already_declared,
             swift_name: attribs.swift_bridge.swift_name,
             derive: attribs.derive,
+            codable: attribs.swift_bridge.codable,
+            serde: attribs.swift_bridge.serde,
         };
 
         Ok(shared_enum)
@@ -153,6 +155,106 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse the `#[swift_bridge(Codable)]` attribute.
+    #[test]
+    fn codable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = &module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.codable);
+    }
+
+    /// Verify that we return an error if a variant field of a `#[swift_bridge(Codable)]` enum is
+    /// not `Codable` in Swift.
+    #[test]
+    fn error_if_codable_variant_field_not_codable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable)]
+                enum SomeEnum {
+                    VariantA(u8, Vec<u8>),
+                    VariantB { field: i128 },
+                    VariantC(String),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::CodableFieldNotCodable { ty } => ty.to_token_stream().to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(tys, vec!["Vec < u8 >", "i128"]);
+    }
+
+    /// Verify that we return an error if a variant field of an enum that derives `PartialEq`,
+    /// `Hash` or `PartialOrd` does not conform to the corresponding Swift protocol.
+    #[test]
+    fn error_if_derive_variant_field_does_not_conform() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq, Hash)]
+                enum SomeEnum {
+                    VariantA(u8, Vec<u8>),
+                    VariantB { field: SomeOpaqueType },
+                    VariantC(String, Option<bool>),
+                }
+
+                extern "Rust" {
+                    type SomeOpaqueType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::DeriveFieldDoesNotConform { ty, .. } => {
+                    ty.to_token_stream().to_string()
+                }
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(tys, vec!["Vec < u8 >", "SomeOpaqueType"]);
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(serde)]` attribute.
+    #[test]
+    fn serde_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(serde)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = &module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.serde);
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
@@ -184,6 +286,37 @@ mod tests {
         };
     }
 
+    /// Verify that we push an error for derive macros that we do not support.
+    #[test]
+    fn error_if_derive_unrecognized() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Debug, Clone, serde::Serialize)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::EnumUnrecognizedAttribute { attribute } => {
+                assert_eq!(&attribute.to_string(), "Clone");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::EnumUnrecognizedAttribute { attribute } => {
+                assert_eq!(&attribute.to_string(), "Serialize");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we can parse #[derive(Debug)] on enums
     #[test]
     fn derive_debug() {
@@ -202,4 +335,28 @@ mod tests {
         let ty = module.types.types()[0].unwrap_shared_enum();
         assert!(ty.derive.debug);
     }
+
+    /// Verify that we can parse #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)] on enums
+    #[test]
+    fn derive_comparison_traits() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+                enum Foo {
+                    Variant1
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(!ty.derive.debug);
+        assert!(ty.derive.partial_eq);
+        assert!(ty.derive.eq);
+        assert!(ty.derive.hash);
+        assert!(ty.derive.partial_ord);
+        assert!(ty.derive.ord);
+    }
 }
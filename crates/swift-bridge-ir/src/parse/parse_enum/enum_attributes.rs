@@ -26,9 +26,19 @@ impl SharedEnumAllAttributes {
                         syn::punctuated::Punctuated::<Path, syn::Token![,]>::parse_terminated;
                     let args = attr.parse_args_with(parser)?;
                     for arg in args.into_iter() {
-                        match arg.get_ident().unwrap().to_string().as_str() {
+                        let derive = &arg.segments.last().unwrap().ident;
+                        match derive.to_string().as_str() {
                             "Debug" => attributes.derive.debug = true,
-                            _ => todo!("Unsupported derive macro; please see https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027"),
+                            "PartialEq" => attributes.derive.partial_eq = true,
+                            "Eq" => attributes.derive.eq = true,
+                            "Hash" => attributes.derive.hash = true,
+                            "PartialOrd" => attributes.derive.partial_ord = true,
+                            "Ord" => attributes.derive.ord = true,
+                            _ => attributes
+                                .errors
+                                .push(ParseError::EnumUnrecognizedAttribute {
+                                    attribute: derive.clone(),
+                                }),
                         }
                     }
                 }
@@ -48,7 +58,9 @@ impl SharedEnumAllAttributes {
 
 pub(super) enum EnumAttr {
     AlreadyDeclared,
+    Codable,
     Error(ParseError),
+    Serde,
     SwiftName(LitStr),
 }
 
@@ -57,13 +69,17 @@ pub(super) struct SharedEnumSwiftBridgeAttributes {
     pub errors: Vec<ParseError>,
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
+    pub codable: bool,
+    pub serde: bool,
 }
 
 impl SharedEnumSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: EnumAttr) -> syn::Result<()> {
         match attrib {
             EnumAttr::AlreadyDeclared => self.already_declared = true,
+            EnumAttr::Codable => self.codable = true,
             EnumAttr::Error(error) => self.errors.push(error),
+            EnumAttr::Serde => self.serde = true,
             EnumAttr::SwiftName(name) => self.swift_name = Some(name),
         };
         Ok(())
@@ -91,6 +107,8 @@ impl Parse for EnumAttr {
 
         let attr = match key.to_string().as_str() {
             "already_declared" => EnumAttr::AlreadyDeclared,
+            "Codable" => EnumAttr::Codable,
+            "serde" => EnumAttr::Serde,
             "swift_name" => {
                 input.parse::<Token![=]>()?;
 
@@ -3,14 +3,18 @@ pub(crate) use self::opaque_type_attributes::OpaqueTypeAllAttributes;
 use crate::bridged_type::{
     bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType,
 };
-use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors};
+use crate::errors::{
+    FunctionAttributeParseError, IdentifiableParseError, MonomorphizeParseError,
+    OperatorParseError, ParseError, ParseErrors, PropertyParseError,
+};
 use crate::parse::parse_extern_mod::function_attributes::FunctionAttributes;
 use crate::parse::parse_extern_mod::generics::GenericOpaqueType;
+use crate::parse::parse_extern_mod::monomorphize::monomorphize_fn;
 use crate::parse::type_declarations::{
     OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
 };
 use crate::parse::{HostLang, OpaqueRustTypeGenerics};
-use crate::parsed_extern_fn::fn_arg_is_mutable_reference;
+use crate::parsed_extern_fn::{fn_arg_is_mutable_reference, SwiftOperator};
 use crate::ParsedExternFn;
 use proc_macro2::Ident;
 use quote::{format_ident, ToTokens};
@@ -24,6 +28,7 @@ use syn::{
 mod argument_attributes;
 mod function_attributes;
 mod generics;
+mod monomorphize;
 mod opaque_type_attributes;
 
 pub(super) struct ForeignModParser<'a> {
@@ -101,145 +106,66 @@ impl<'a> ForeignModParser<'a> {
                         attributes = attr.parse_args()?;
                     }
 
-                    for arg in func.sig.inputs.iter() {
-                        if let FnArg::Typed(pat_ty) = arg {
-                            let ty = &pat_ty.ty;
-                            if BridgedType::new_with_type(&ty, &self.type_declarations).is_none() {
-                                self.unresolved_types.push(ty.deref().clone());
-                            }
+                    let instantiations = match attributes.monomorphize.take() {
+                        Some(instantiations) => instantiations,
+                        None => {
+                            self.parse_extern_fn(
+                                func,
+                                attributes,
+                                vec![],
+                                host_lang,
+                                &mut local_type_declarations,
+                            )?;
+                            continue;
                         }
-                    }
+                    };
 
-                    let return_type = &func.sig.output;
-                    if let ReturnType::Type(_, return_ty) = return_type {
-                        if BridgedType::new_with_type(return_ty.deref(), &self.type_declarations)
-                            .is_none()
-                        {
-                            self.unresolved_types.push(return_ty.deref().clone());
-                        }
+                    if host_lang.is_swift() {
+                        self.errors.push(ParseError::FunctionAttribute(
+                            FunctionAttributeParseError::Monomorphize(
+                                MonomorphizeParseError::ExternSwiftFunction {
+                                    fn_ident: func.sig.ident.clone(),
+                                },
+                            ),
+                        ));
+                        continue;
                     }
 
-                    let first_input = func.sig.inputs.iter().next();
-
-                    let associated_type = self.get_associated_type(
-                        first_input,
-                        func.clone(),
-                        &attributes,
-                        &mut local_type_declarations,
-                    )?;
-
-                    if attributes.is_swift_identifiable {
-                        let args = &func.sig.inputs;
-
-                        let mut is_ref_self_no_args = args.len() == 1;
-                        if is_ref_self_no_args {
-                            is_ref_self_no_args = match args.iter().next().unwrap() {
-                                FnArg::Receiver(receiver) => {
-                                    receiver.reference.is_some() && receiver.mutability.is_none()
-                                }
-                                FnArg::Typed(pat_ty) => {
-                                    pat_type_pat_is_self(pat_ty)
-                                        && pat_ty.ty.to_token_stream().to_string().starts_with("&")
-                                }
-                            };
-                        }
-
-                        let has_return_type = matches!(&func.sig.output, ReturnType::Type(_, _));
-
-                        if !is_ref_self_no_args {
-                            self.errors.push(ParseError::FunctionAttribute(
-                                FunctionAttributeParseError::Identifiable(
-                                    IdentifiableParseError::MustBeRefSelf {
-                                        fn_ident: func.sig.ident.clone(),
-                                    },
-                                ),
-                            ));
-                        }
-                        if !has_return_type {
+                    let generic_params: Vec<Ident> = func
+                        .sig
+                        .generics
+                        .type_params()
+                        .map(|param| param.ident.clone())
+                        .collect();
+
+                    // Each instantiation becomes its own function, so we parse the attributes
+                    // again for each of them.
+                    for concrete in instantiations {
+                        if concrete.len() != generic_params.len() {
                             self.errors.push(ParseError::FunctionAttribute(
-                                FunctionAttributeParseError::Identifiable(
-                                    IdentifiableParseError::MissingReturnType {
+                                FunctionAttributeParseError::Monomorphize(
+                                    MonomorphizeParseError::WrongNumberOfGenerics {
                                         fn_ident: func.sig.ident.clone(),
+                                        expected: generic_params.len(),
                                     },
                                 ),
                             ));
+                            continue;
                         }
-                    }
-                    let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
-                    for arg in func.sig.inputs.iter() {
-                        let is_mutable_ref = fn_arg_is_mutable_reference(arg);
-
-                        let is_copy_opaque_type =
-                            if let Some(TypeDeclaration::Opaque(o)) = associated_type.as_ref() {
-                                o.attributes.copy.is_some()
-                            } else if let Some(ty) =
-                                bridgeable_type_from_fn_arg(arg, &self.type_declarations)
-                            {
-                                ty.has_swift_bridge_copy_annotation()
-                            } else {
-                                false
-                            };
-
-                        if is_mutable_ref && is_copy_opaque_type {
-                            self.errors
-                                .push(ParseError::ArgCopyAndRefMut { arg: arg.clone() });
-                        }
-                        match arg {
-                            syn::FnArg::Typed(ty) => {
-                                for attr in ty.attrs.iter() {
-                                    let attribute: ArgumentAttributes = attr.parse_args()?;
-                                    if let Some(label) = attribute.label {
-                                        argument_labels.insert(
-                                            format_ident!(
-                                                "{}",
-                                                ty.pat.to_token_stream().to_string()
-                                            ),
-                                            label,
-                                        );
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                    if let Some(ref args) = attributes.args_into {
-                        let mut func_sig_args = HashSet::with_capacity(args.len());
-                        for fn_arg in func.sig.inputs.iter() {
-                            match fn_arg {
-                                FnArg::Receiver(_) => {}
-                                FnArg::Typed(pat_ty) => {
-                                    let fn_arg_name = pat_ty.pat.to_token_stream().to_string();
-                                    func_sig_args.insert(fn_arg_name);
-                                }
-                            }
-                        }
-
-                        for arg in args.iter() {
-                            let arg_name = arg.to_token_stream().to_string();
 
-                            if !func_sig_args.contains(&arg_name) {
-                                self.errors.push(ParseError::ArgsIntoArgNotFound {
-                                    func: func.clone(),
-                                    missing_arg: arg.clone(),
-                                })
-                            }
+                        let mut attributes = FunctionAttributes::default();
+                        for attr in func.attrs.iter() {
+                            attributes = attr.parse_args()?;
                         }
+
+                        self.parse_extern_fn(
+                            monomorphize_fn(&func, &generic_params, &concrete),
+                            attributes,
+                            concrete,
+                            host_lang,
+                            &mut local_type_declarations,
+                        )?;
                     }
-                    let func = ParsedExternFn {
-                        func,
-                        associated_type,
-                        is_swift_initializer: attributes.is_swift_initializer,
-                        is_swift_identifiable: attributes.is_swift_identifiable,
-                        host_lang,
-                        rust_name_override: attributes.rust_name,
-                        swift_name_override: attributes.swift_name,
-                        return_into: attributes.return_into,
-                        return_with: attributes.return_with,
-                        args_into: attributes.args_into,
-                        get_field: attributes.get_field,
-                        argument_labels: argument_labels,
-                    };
-                    self.functions.push(func);
                 }
                 ForeignItem::Verbatim(foreign_item_verbatim) => {
                     if let Ok(generic_foreign_type) =
@@ -288,6 +214,244 @@ impl<'a> ForeignModParser<'a> {
         Ok(())
     }
 
+    fn parse_extern_fn(
+        &mut self,
+        func: ForeignItemFn,
+        attributes: FunctionAttributes,
+        monomorphized_generics: Vec<Ident>,
+        host_lang: HostLang,
+        local_type_declarations: &mut HashMap<String, OpaqueForeignTypeDeclaration>,
+    ) -> syn::Result<()> {
+        for arg in func.sig.inputs.iter() {
+            if let FnArg::Typed(pat_ty) = arg {
+                let ty = &pat_ty.ty;
+                if BridgedType::new_with_type(&ty, &self.type_declarations).is_none() {
+                    self.unresolved_types.push(ty.deref().clone());
+                }
+            }
+        }
+
+        let return_type = &func.sig.output;
+        if let ReturnType::Type(_, return_ty) = return_type {
+            if BridgedType::new_with_type(return_ty.deref(), &self.type_declarations).is_none() {
+                self.unresolved_types.push(return_ty.deref().clone());
+            }
+        }
+
+        let first_input = func.sig.inputs.iter().next();
+
+        let associated_type = self.get_associated_type(
+            first_input,
+            func.clone(),
+            &attributes,
+            local_type_declarations,
+        )?;
+
+        if attributes.is_swift_identifiable {
+            let args = &func.sig.inputs;
+
+            let mut is_ref_self_no_args = args.len() == 1;
+            if is_ref_self_no_args {
+                is_ref_self_no_args = match args.iter().next().unwrap() {
+                    FnArg::Receiver(receiver) => {
+                        receiver.reference.is_some() && receiver.mutability.is_none()
+                    }
+                    FnArg::Typed(pat_ty) => {
+                        pat_type_pat_is_self(pat_ty)
+                            && pat_ty.ty.to_token_stream().to_string().starts_with("&")
+                    }
+                };
+            }
+
+            let has_return_type = matches!(&func.sig.output, ReturnType::Type(_, _));
+
+            if !is_ref_self_no_args {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Identifiable(
+                        IdentifiableParseError::MustBeRefSelf {
+                            fn_ident: func.sig.ident.clone(),
+                        },
+                    ),
+                ));
+            }
+            if !has_return_type {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Identifiable(
+                        IdentifiableParseError::MissingReturnType {
+                            fn_ident: func.sig.ident.clone(),
+                        },
+                    ),
+                ));
+            }
+        }
+        let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
+        for arg in func.sig.inputs.iter() {
+            let is_mutable_ref = fn_arg_is_mutable_reference(arg);
+
+            let is_copy_opaque_type =
+                if let Some(TypeDeclaration::Opaque(o)) = associated_type.as_ref() {
+                    o.attributes.copy.is_some()
+                } else if let Some(ty) = bridgeable_type_from_fn_arg(arg, &self.type_declarations) {
+                    ty.has_swift_bridge_copy_annotation()
+                } else {
+                    false
+                };
+
+            if is_mutable_ref && is_copy_opaque_type {
+                self.errors
+                    .push(ParseError::ArgCopyAndRefMut { arg: arg.clone() });
+            }
+            match arg {
+                syn::FnArg::Typed(ty) => {
+                    for attr in ty.attrs.iter() {
+                        let attribute: ArgumentAttributes = attr.parse_args()?;
+                        if let Some(label) = attribute.label {
+                            argument_labels.insert(
+                                format_ident!("{}", ty.pat.to_token_stream().to_string()),
+                                label,
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(ref args) = attributes.args_into {
+            let mut func_sig_args = HashSet::with_capacity(args.len());
+            for fn_arg in func.sig.inputs.iter() {
+                match fn_arg {
+                    FnArg::Receiver(_) => {}
+                    FnArg::Typed(pat_ty) => {
+                        let fn_arg_name = pat_ty.pat.to_token_stream().to_string();
+                        func_sig_args.insert(fn_arg_name);
+                    }
+                }
+            }
+
+            for arg in args.iter() {
+                let arg_name = arg.to_token_stream().to_string();
+
+                if !func_sig_args.contains(&arg_name) {
+                    self.errors.push(ParseError::ArgsIntoArgNotFound {
+                        func: func.clone(),
+                        missing_arg: arg.clone(),
+                    })
+                }
+            }
+        }
+        if let Some(operator) = attributes.operator {
+            let fn_ident = func.sig.ident.clone();
+
+            if host_lang.is_swift() {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Operator(
+                        OperatorParseError::ExternSwiftFunction { fn_ident },
+                    ),
+                ));
+            } else if !operator.accepts_operand_count(func.sig.inputs.len()) {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Operator(
+                        OperatorParseError::WrongNumberOfArguments { fn_ident, operator },
+                    ),
+                ));
+            } else if operator != SwiftOperator::IndexSet
+                && matches!(&func.sig.output, ReturnType::Default)
+            {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Operator(OperatorParseError::MissingReturnType {
+                        fn_ident,
+                    }),
+                ));
+            }
+        }
+        let func = ParsedExternFn {
+            func,
+            associated_type,
+            is_swift_initializer: attributes.is_swift_initializer,
+            is_swift_identifiable: attributes.is_swift_identifiable,
+            host_lang,
+            rust_name_override: attributes.rust_name,
+            swift_name_override: attributes.swift_name,
+            return_into: attributes.return_into,
+            return_with: attributes.return_with,
+            args_into: attributes.args_into,
+            get_field: attributes.get_field,
+            argument_labels: argument_labels,
+            monomorphized_generics,
+            operator: attributes.operator,
+            property: attributes.property,
+            setter: attributes.setter,
+        };
+
+        if let Some(TypeDeclaration::Opaque(ty)) = func.associated_type.as_ref() {
+            if ty.attributes.protocol && func.self_reference().is_none() {
+                self.errors.push(ParseError::ProtocolFunctionNotRefSelf {
+                    fn_ident: func.func.sig.ident.clone(),
+                });
+            }
+        }
+        if func.property.is_some() || func.setter.is_some() {
+            let fn_ident = func.func.sig.ident.clone();
+            let is_ref_method_on_rust_type = host_lang.is_rust()
+                && func.self_reference().is_some()
+                && matches!(
+                    &func.associated_type,
+                    Some(TypeDeclaration::Opaque(ty)) if !ty.attributes.protocol
+                );
+            let returns_value = !matches!(&func.sig.output, ReturnType::Default);
+
+            if func.property.is_some()
+                && !(is_ref_method_on_rust_type
+                    && func.self_mutability().is_none()
+                    && func.sig.inputs.len() == 1
+                    && returns_value)
+            {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Property(PropertyParseError::InvalidGetter {
+                        fn_ident,
+                    }),
+                ));
+            } else if func.setter.is_some()
+                && !(is_ref_method_on_rust_type
+                    && func.self_mutability().is_some()
+                    && func.sig.inputs.len() == 2
+                    && !returns_value)
+            {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Property(PropertyParseError::InvalidSetter {
+                        fn_ident,
+                    }),
+                ));
+            }
+        }
+        if func.operator == Some(SwiftOperator::IndexSet)
+            && host_lang.is_rust()
+            && SwiftOperator::IndexSet.accepts_operand_count(func.sig.inputs.len())
+        {
+            let is_ref_mut_method_on_rust_type = func.self_reference().is_some()
+                && func.self_mutability().is_some()
+                && matches!(
+                    &func.associated_type,
+                    Some(TypeDeclaration::Opaque(ty)) if !ty.attributes.protocol
+                );
+            let returns_value = !matches!(&func.sig.output, ReturnType::Default);
+
+            if !is_ref_mut_method_on_rust_type || returns_value {
+                self.errors.push(ParseError::FunctionAttribute(
+                    FunctionAttributeParseError::Operator(
+                        OperatorParseError::InvalidSubscriptSetter {
+                            fn_ident: func.func.sig.ident.clone(),
+                        },
+                    ),
+                ));
+            }
+        }
+
+        self.functions.push(func);
+
+        Ok(())
+    }
+
     fn get_associated_type(
         &mut self,
         first: Option<&FnArg>,
@@ -841,6 +1005,104 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse the `Clone` attribute.
+    #[test]
+    fn parse_clone_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .clone
+        );
+    }
+
+    /// Verify that we can parse the `Comparable` attribute, and that it implies `Equatable`.
+    #[test]
+    fn parse_comparable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Comparable)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let attributes = &module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes;
+        assert!(attributes.comparable);
+        assert!(attributes.equatable);
+    }
+
+    /// Verify that we can parse the `Display` and `Debug` attributes.
+    #[test]
+    fn parse_display_and_debug_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Display, Debug)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let attributes = &module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes;
+        assert!(attributes.display);
+        assert!(attributes.debug);
+    }
+
+    /// Verify that we can parse the `protocol` attribute.
+    #[test]
+    fn parse_protocol_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type SomeProtocol;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeProtocol")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .protocol
+        );
+    }
+
     /// Verify that we can parse the `copy` attribute.
     #[test]
     fn parse_copy_attribute() {
@@ -972,4 +1234,37 @@ mod tests {
             }
         }
     }
+
+    /// Verify that we push errors for protocol functions that do not take `&self` or `&mut self`.
+    #[test]
+    fn error_if_protocol_function_does_not_take_self_by_reference() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(protocol)]
+                    type SomeProtocol;
+
+                    fn a(&self);
+                    fn b(&mut self);
+                    fn c(self);
+                    #[swift_bridge(associated_to = SomeProtocol)]
+                    fn d();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::ProtocolFunctionNotRefSelf { fn_ident } => {
+                    assert!(fn_ident == "c" || fn_ident == "d");
+                }
+                _ => panic!(),
+            }
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith};
+use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith, SwiftOperator};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
 use syn::{LitStr, Path, Token};
@@ -14,6 +14,10 @@ pub(super) struct FunctionAttributes {
     pub return_with: Option<Path>,
     pub args_into: Option<Vec<Ident>>,
     pub get_field: Option<GetField>,
+    pub monomorphize: Option<Vec<Vec<Ident>>>,
+    pub operator: Option<SwiftOperator>,
+    pub property: Option<LitStr>,
+    pub setter: Option<LitStr>,
 }
 
 impl FunctionAttributes {
@@ -43,6 +47,10 @@ impl FunctionAttributes {
             FunctionAttr::GetFieldWith(get_field) => {
                 self.get_field = Some(GetField::With(get_field))
             }
+            FunctionAttr::Monomorphize(instantiations) => self.monomorphize = Some(instantiations),
+            FunctionAttr::Operator(operator) => self.operator = Some(operator),
+            FunctionAttr::Property(name) => self.property = Some(name),
+            FunctionAttr::Setter(name) => self.setter = Some(name),
         }
     }
 }
@@ -58,6 +66,15 @@ pub(super) enum FunctionAttr {
     ArgsInto(Vec<Ident>),
     GetField(GetFieldDirect),
     GetFieldWith(GetFieldWith),
+    /// `monomorphize = (u32, f64)` for `fn parse<T>(..)`, or
+    /// `monomorphize = ((u32, u8), (f64, u16))` for `fn convert<T, U>(..)`
+    Monomorphize(Vec<Vec<Ident>>),
+    /// `operator = "+"`
+    Operator(SwiftOperator),
+    /// `property = "name"`
+    Property(LitStr),
+    /// `setter = "name"`
+    Setter(LitStr),
 }
 
 impl Parse for FunctionAttributes {
@@ -149,6 +166,45 @@ impl Parse for FunctionAttr {
                     path,
                 })
             }
+            "monomorphize" => {
+                input.parse::<Token![=]>()?;
+
+                let content;
+                syn::parenthesized!(content in input);
+
+                let instantiations =
+                    syn::punctuated::Punctuated::<MonomorphizedGenerics, Token![,]>::parse_terminated(
+                        &content,
+                    )?;
+                FunctionAttr::Monomorphize(instantiations.into_iter().map(|m| m.0).collect())
+            }
+            "operator" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                let operator = SwiftOperator::from_symbol(&value.value()).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &value,
+                        format!(
+                            r#"Unsupported operator "{}". Supported operators are +, -, *, /, %, !, [] and []=."#,
+                            value.value()
+                        ),
+                    )
+                })?;
+                FunctionAttr::Operator(operator)
+            }
+            "property" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                FunctionAttr::Property(value)
+            }
+            "setter" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                FunctionAttr::Setter(value)
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -162,9 +218,33 @@ impl Parse for FunctionAttr {
     }
 }
 
+/// The concrete generics of one instantiation in a `monomorphize = (...)` attribute.
+///
+/// `u32` or `(u32, u8)`
+struct MonomorphizedGenerics(Vec<Ident>);
+
+impl Parse for MonomorphizedGenerics {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            let generics =
+                syn::punctuated::Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            Ok(MonomorphizedGenerics(generics.into_iter().collect()))
+        } else {
+            Ok(MonomorphizedGenerics(vec![input.parse()?]))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::errors::{
+        FunctionAttributeParseError, IdentifiableParseError, MonomorphizeParseError,
+        OperatorParseError, ParseError, PropertyParseError,
+    };
+    use crate::parsed_extern_fn::SwiftOperator;
     use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
 
@@ -534,4 +614,468 @@ mod tests {
         assert_eq!(func.args_into.as_ref().unwrap().len(), 1);
         assert_eq!(func.return_into, true);
     }
+
+    /// Verify that we create one function for each instantiation in a monomorphize attribute.
+    #[test]
+    fn parse_monomorphize_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(monomorphize = (u32, f64))]
+                    fn parse<T>(s: &str) -> Option<T>;
+
+                    #[swift_bridge(monomorphize = ((u8, String)))]
+                    fn convert<A, B>(a: A) -> B;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let funcs = &module.functions;
+        assert_eq!(funcs.len(), 3);
+
+        assert_eq!(funcs[0].monomorphized_generics, vec!["u32"]);
+        assert_eq!(
+            funcs[0].sig.output.to_token_stream().to_string(),
+            "-> Option < u32 >"
+        );
+        assert_eq!(funcs[1].monomorphized_generics, vec!["f64"]);
+        assert_eq!(funcs[2].monomorphized_generics, vec!["u8", "String"]);
+        assert_eq!(funcs[2].sig.generics.params.len(), 0);
+    }
+
+    /// Verify that we push a parse error if an instantiation has the wrong number of generics.
+    #[test]
+    fn error_if_monomorphize_wrong_number_of_generics() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(monomorphize = (u32, (u8, u16)))]
+                    fn parse<T>(s: &str) -> Option<T>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Monomorphize(
+                MonomorphizeParseError::WrongNumberOfGenerics { fn_ident, expected },
+            )) => {
+                assert_eq!(fn_ident, "parse");
+                assert_eq!(*expected, 1);
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push a parse error if we monomorphize an extern "Swift" function.
+    #[test]
+    fn error_if_monomorphize_extern_swift_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(monomorphize = (u32))]
+                    fn parse<T>(s: &str) -> Option<T>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Monomorphize(
+                MonomorphizeParseError::ExternSwiftFunction { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "parse");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we can parse the operator attribute.
+    #[test]
+    fn parse_operator_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "+")]
+                    fn add(&self, other: &Vector) -> Vector;
+
+                    #[swift_bridge(operator = "-")]
+                    fn neg(&self) -> Vector;
+
+                    #[swift_bridge(operator = "[]")]
+                    fn get(&self, index: usize) -> f64;
+
+                    #[swift_bridge(operator = "[]=")]
+                    fn set(&mut self, index: usize, value: f64);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let funcs = &module.functions;
+
+        assert_eq!(funcs[0].operator, Some(SwiftOperator::Add));
+        assert_eq!(funcs[1].operator, Some(SwiftOperator::Sub));
+        assert_eq!(funcs[2].operator, Some(SwiftOperator::Index));
+        assert_eq!(funcs[3].operator, Some(SwiftOperator::IndexSet));
+    }
+
+    /// Verify that we push a parse error if none of an operator function's operands are declared
+    /// in a bridge module, since the operator would collide with Swift's own operators.
+    #[test]
+    fn error_if_operator_has_no_declared_operand() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "+")]
+                    fn add(a: f64, b: f64) -> f64;
+
+                    #[swift_bridge(operator = "*")]
+                    fn scale(factor: f64, vector: &Vector) -> Vector;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                OperatorParseError::NoDeclaredOperand { fn_ident, operator },
+            )) => {
+                assert_eq!(fn_ident, "add");
+                assert_eq!(*operator, SwiftOperator::Add);
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push a parse error if a subscript setter does not take `&mut self` or
+    /// returns a value.
+    #[test]
+    fn error_if_invalid_subscript_setter() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "[]")]
+                    fn get(&self, index: usize) -> f64;
+
+                    #[swift_bridge(operator = "[]=")]
+                    fn set(&self, index: usize, value: f64);
+
+                    #[swift_bridge(operator = "[]=")]
+                    fn replace(&mut self, index: usize, value: f64) -> f64;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (error, expected) in errors.iter().zip(["set", "replace"]) {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                    OperatorParseError::InvalidSubscriptSetter { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push a parse error if a subscript setter does not have a subscript getter
+    /// with the same index type.
+    #[test]
+    fn error_if_subscript_setter_without_getter() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "[]")]
+                    fn get(&self, index: u8) -> f64;
+
+                    #[swift_bridge(operator = "[]=")]
+                    fn set(&mut self, index: usize, value: f64);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                OperatorParseError::SubscriptSetterWithoutGetter { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "set");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push a parse error if an operator function takes the wrong number of
+    /// arguments.
+    #[test]
+    fn error_if_operator_wrong_number_of_arguments() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "*")]
+                    fn scale(&self) -> Vector;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                OperatorParseError::WrongNumberOfArguments { fn_ident, operator },
+            )) => {
+                assert_eq!(fn_ident, "scale");
+                assert_eq!(*operator, SwiftOperator::Mul);
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push a parse error if an operator function does not return a value.
+    #[test]
+    fn error_if_operator_missing_return_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector;
+
+                    #[swift_bridge(operator = "+")]
+                    fn add(&self, other: &Vector);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                OperatorParseError::MissingReturnType { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "add");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push a parse error if an extern "Swift" function is an operator.
+    #[test]
+    fn error_if_operator_extern_swift_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(operator = "+")]
+                    fn add(a: u8, b: u8) -> u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                OperatorParseError::ExternSwiftFunction { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "add");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we can parse the property and setter attributes.
+    #[test]
+    fn parse_property_and_setter_attributes() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Person;
+
+                    #[swift_bridge(property = "name")]
+                    fn name(&self) -> String;
+
+                    #[swift_bridge(setter = "name")]
+                    fn set_name(&mut self, name: String);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let funcs = &module.functions;
+
+        assert_eq!(funcs[0].property.as_ref().unwrap().value(), "name");
+        assert!(funcs[0].setter.is_none());
+        assert_eq!(funcs[1].setter.as_ref().unwrap().value(), "name");
+        assert!(funcs[1].property.is_none());
+    }
+
+    /// Verify that we push a parse error if a property getter does not take `&self` and return a
+    /// value.
+    #[test]
+    fn error_if_invalid_property_getter() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Person;
+
+                    #[swift_bridge(property = "a")]
+                    fn a(&mut self) -> u8;
+
+                    #[swift_bridge(property = "b")]
+                    fn b(&self);
+
+                    #[swift_bridge(property = "c")]
+                    fn c(&self, arg: u8) -> u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for (idx, expected_fn) in vec!["a", "b", "c"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Property(
+                    PropertyParseError::InvalidGetter { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected_fn);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push a parse error if a property setter does not take `&mut self` and one
+    /// argument.
+    #[test]
+    fn error_if_invalid_property_setter() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Person;
+
+                    #[swift_bridge(property = "age")]
+                    fn age(&self) -> u8;
+
+                    #[swift_bridge(setter = "age")]
+                    fn set_age(&self, age: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Property(
+                PropertyParseError::InvalidSetter { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "set_age");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push a parse error if a setter does not have a getter.
+    #[test]
+    fn error_if_setter_without_getter() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Person;
+
+                    #[swift_bridge(setter = "age")]
+                    fn set_age(&mut self, age: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Property(
+                PropertyParseError::SetterWithoutGetter { fn_ident, name },
+            )) => {
+                assert_eq!(fn_ident, "set_age");
+                assert_eq!(name.value(), "age");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push a parse error if a setter's argument type is not the type that its
+    /// getter returns.
+    #[test]
+    fn error_if_setter_type_does_not_match_getter() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Person;
+
+                    #[swift_bridge(property = "age")]
+                    fn age(&self) -> u8;
+
+                    #[swift_bridge(setter = "age")]
+                    fn set_age(&mut self, age: u16);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Property(
+                PropertyParseError::SetterTypeMismatch {
+                    setter_ty,
+                    getter_ty,
+                },
+            )) => {
+                assert_eq!(setter_ty.to_token_stream().to_string(), "u16");
+                assert_eq!(getter_ty.to_token_stream().to_string(), "u8");
+            }
+            _ => panic!(),
+        }
+    }
 }
@@ -0,0 +1,75 @@
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{ForeignItemFn, Generics, Signature};
+
+/// Create one concrete instantiation of a generic function.
+///
+/// `fn parse<T>(s: &str) -> Option<T>` with `T = u32` becomes `fn parse(s: &str) -> Option<u32>`
+pub(super) fn monomorphize_fn(
+    func: &ForeignItemFn,
+    generic_params: &[Ident],
+    concrete: &[Ident],
+) -> ForeignItemFn {
+    let mut sig = func.sig.clone();
+    sig.generics = Generics::default();
+
+    let sig_tokens = replace_idents(sig.to_token_stream(), generic_params, concrete);
+    let sig: Signature = syn::parse2(sig_tokens).unwrap();
+
+    ForeignItemFn {
+        sig,
+        ..func.clone()
+    }
+}
+
+fn replace_idents(tokens: TokenStream, from: &[Ident], to: &[Ident]) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) => match from.iter().position(|f| f == &ident) {
+                Some(idx) => {
+                    let mut replacement = to[idx].clone();
+                    replacement.set_span(ident.span());
+                    TokenTree::Ident(replacement)
+                }
+                None => TokenTree::Ident(ident),
+            },
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_idents(group.stream(), from, to));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::{format_ident, quote};
+
+    /// Verify that we replace generic parameters, including ones nested within other types.
+    #[test]
+    fn replaces_generic_params() {
+        let func: ForeignItemFn = syn::parse2(quote! {
+            fn convert<T, U>(a: T, b: Vec<U>) -> Option<(T, U)>;
+        })
+        .unwrap();
+
+        let monomorphized = monomorphize_fn(
+            &func,
+            &[format_ident!("T"), format_ident!("U")],
+            &[format_ident!("u32"), format_ident!("String")],
+        );
+
+        assert_eq!(
+            monomorphized.to_token_stream().to_string(),
+            quote! {
+                fn convert(a: u32, b: Vec<String>) -> Option<(u32, String)>;
+            }
+            .to_string()
+        );
+    }
+}
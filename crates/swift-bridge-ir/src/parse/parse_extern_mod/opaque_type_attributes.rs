@@ -20,18 +20,33 @@ pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
     /// If it was, we won't generate Swift and C type declarations for this type, since we
     /// will elsewhere.
     pub already_declared: bool,
+    /// `#[swift_bridge(Clone)]`
+    /// Used to determine if a `clone()` method need to be generated.
+    pub clone: bool,
+    /// `#[swift_bridge(Comparable)]`
+    /// Used to determine if Comparable need to be implemented.
+    pub comparable: bool,
     /// `#[swift_bridge(Copy(...)]`
     /// Describes the type's Copy semantics.
     pub copy: Option<OpaqueCopy>,
+    /// `#[swift_bridge(Debug)]`
+    /// Used to determine if CustomDebugStringConvertible need to be implemented.
+    pub debug: bool,
     /// `#[swift_bridge(declare_generic)]`
     /// Used to declare a generic type.
     pub declare_generic: bool,
+    /// `#[swift_bridge(Display)]`
+    /// Used to determine if CustomStringConvertible need to be implemented.
+    pub display: bool,
     /// `#[swift_bridge(Equatable)]`
     /// Used to determine if Equatable need to be implemented.
     pub equatable: bool,
     /// `#[swift_bridge(Hashable)]`
     /// Used to determine if Hashable need to be implemented.
     pub hashable: bool,
+    /// `#[swift_bridge(protocol)]`
+    /// Used to bridge a Rust trait and a Swift protocol instead of a concrete type.
+    pub protocol: bool,
 }
 
 impl OpaqueTypeAllAttributes {
@@ -73,20 +88,35 @@ impl OpaqueTypeSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: OpaqueTypeAttr) {
         match attrib {
             OpaqueTypeAttr::AlreadyDeclared => self.already_declared = true,
+            OpaqueTypeAttr::Clone => self.clone = true,
+            OpaqueTypeAttr::Comparable => {
+                self.comparable = true;
+                // Swift's `Comparable` refines `Equatable`, and Rust's `PartialOrd` requires
+                // `PartialEq`, so the type is always `Equatable` as well.
+                self.equatable = true;
+            }
             OpaqueTypeAttr::Copy { size } => self.copy = Some(OpaqueCopy { size_bytes: size }),
+            OpaqueTypeAttr::Debug => self.debug = true,
             OpaqueTypeAttr::DeclareGeneric => self.declare_generic = true,
+            OpaqueTypeAttr::Display => self.display = true,
             OpaqueTypeAttr::Equatable => self.equatable = true,
             OpaqueTypeAttr::Hashable => self.hashable = true,
+            OpaqueTypeAttr::Protocol => self.protocol = true,
         }
     }
 }
 
 pub(crate) enum OpaqueTypeAttr {
     AlreadyDeclared,
+    Clone,
+    Comparable,
     Copy { size: usize },
+    Debug,
     DeclareGeneric,
+    Display,
     Equatable,
     Hashable,
+    Protocol,
 }
 
 impl Parse for OpaqueTypeSwiftBridgeAttributes {
@@ -110,6 +140,8 @@ impl Parse for OpaqueTypeAttr {
 
         let attrib = match key.to_string().as_str() {
             "already_declared" => OpaqueTypeAttr::AlreadyDeclared,
+            "Clone" => OpaqueTypeAttr::Clone,
+            "Comparable" => OpaqueTypeAttr::Comparable,
             // Copy(10)
             "Copy" => {
                 let content;
@@ -121,9 +153,12 @@ impl Parse for OpaqueTypeAttr {
                     size: size.to_string().parse().unwrap(),
                 }
             }
+            "Debug" => OpaqueTypeAttr::Debug,
             "declare_generic" => OpaqueTypeAttr::DeclareGeneric,
+            "Display" => OpaqueTypeAttr::Display,
             "Equatable" => OpaqueTypeAttr::Equatable,
             "Hashable" => OpaqueTypeAttr::Hashable,
+            "protocol" => OpaqueTypeAttr::Protocol,
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -1,9 +1,8 @@
-use crate::bridged_type::{
-    shared_struct::StructDerives, SharedStruct, StructFields, StructSwiftRepr,
-};
+use crate::bridged_type::shared_struct::{type_is_ident, SharedStructGenerics, StructDerives};
+use crate::bridged_type::{SharedStruct, StructFields, StructSwiftRepr};
 use crate::errors::{ParseError, ParseErrors};
 use crate::parse::move_input_cursor_to_next_comma;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::{ItemStruct, LitStr, Meta, Token};
@@ -18,6 +17,8 @@ enum StructAttr {
     SwiftName(LitStr),
     Error(StructAttrParseError),
     AlreadyDeclared,
+    Codable,
+    Serde,
 }
 
 enum StructAttrParseError {
@@ -31,6 +32,8 @@ struct StructAttribs {
     swift_name: Option<LitStr>,
     already_declared: bool,
     derives: StructDerives,
+    codable: bool,
+    serde: bool,
 }
 
 impl Default for StructDerives {
@@ -38,6 +41,11 @@ impl Default for StructDerives {
         StructDerives {
             copy: false,
             clone: false,
+            partial_eq: false,
+            eq: false,
+            hash: false,
+            partial_ord: false,
+            ord: false,
         }
     }
 }
@@ -77,6 +85,8 @@ impl Parse for StructAttr {
                 StructAttr::SwiftName(name)
             }
             "already_declared" => StructAttr::AlreadyDeclared,
+            "Codable" => StructAttr::Codable,
+            "serde" => StructAttr::Serde,
             _ => {
                 move_input_cursor_to_next_comma(input);
                 StructAttr::Error(StructAttrParseError::UnrecognizedAttribute(key))
@@ -124,6 +134,12 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             StructAttr::AlreadyDeclared => {
                                 attribs.already_declared = true;
                             }
+                            StructAttr::Codable => {
+                                attribs.codable = true;
+                            }
+                            StructAttr::Serde => {
+                                attribs.serde = true;
+                            }
                         };
                     }
                 }
@@ -133,6 +149,11 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             match derive.to_token_stream().to_string().as_str() {
                                 "Copy" => attribs.derives.copy = true,
                                 "Clone" => attribs.derives.clone = true,
+                                "PartialEq" => attribs.derives.partial_eq = true,
+                                "Eq" => attribs.derives.eq = true,
+                                "Hash" => attribs.derives.hash = true,
+                                "PartialOrd" => attribs.derives.partial_ord = true,
+                                "Ord" => attribs.derives.ord = true,
                                 _ => {}
                             }
                         }
@@ -162,6 +183,9 @@ impl<'a> SharedStructDeclarationParser<'a> {
             StructSwiftRepr::Structure
         } else if let Some((swift_repr, _)) = attribs.swift_repr {
             swift_repr
+        } else if attribs.serde {
+            // A `#[swift_bridge(serde)]` struct is always decoded into a Swift struct.
+            StructSwiftRepr::Structure
         } else {
             self.errors.push(ParseError::StructMissingSwiftRepr {
                 struct_ident: item_struct.ident.clone(),
@@ -170,19 +194,63 @@ impl<'a> SharedStructDeclarationParser<'a> {
             StructSwiftRepr::Structure
         };
 
+        let generic_idents: Vec<Ident> = item_struct
+            .generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect();
+        let fields = StructFields::from_syn_fields(item_struct.fields);
+
+        // `struct Point<T> { x: T }` declares a generic struct, while the bodiless
+        // `struct Point<f32>;` declares one of its concrete instantiations.
+        let generics = if fields.is_empty() {
+            SharedStructGenerics {
+                params: vec![],
+                concrete: generic_idents,
+            }
+        } else {
+            for norm_field in fields.normalized_fields() {
+                let is_param = generic_idents
+                    .iter()
+                    .any(|param| type_is_ident(&norm_field.ty, param));
+                if !is_param
+                    && tokens_contain_any_ident(norm_field.ty.to_token_stream(), &generic_idents)
+                {
+                    self.errors
+                        .push(ParseError::GenericStructFieldNestedParam { ty: norm_field.ty });
+                }
+            }
+
+            SharedStructGenerics {
+                params: generic_idents,
+                concrete: vec![],
+            }
+        };
+
         let shared_struct = SharedStruct {
             name: item_struct.ident,
             swift_repr,
-            fields: StructFields::from_syn_fields(item_struct.fields),
+            fields,
             swift_name: attribs.swift_name,
             already_declared: attribs.already_declared,
             derives: attribs.derives,
+            generics,
+            codable: attribs.codable,
+            serde: attribs.serde,
         };
 
         Ok(shared_struct)
     }
 }
 
+fn tokens_contain_any_ident(tokens: TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => tokens_contain_any_ident(group.stream(), idents),
+        _ => false,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,6 +393,123 @@ mod tests {
         };
     }
 
+    /// Verify that we can parse a generic struct declaration along with its instantiations.
+    #[test]
+    fn parse_generic_struct_instantiations() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point<T> {
+                    x: T,
+                    label: u8
+                }
+                struct Point<f32>;
+                struct Point<f64>;
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let declaration = module.types.get("Point<T>").unwrap().unwrap_shared_struct();
+        assert!(declaration.generics.is_generic_declaration());
+
+        for concrete in ["f32", "f64"] {
+            let ty = module
+                .types
+                .get(&format!("Point<{}>", concrete))
+                .unwrap()
+                .unwrap_shared_struct();
+
+            assert_eq!(ty.swift_repr, StructSwiftRepr::Structure);
+            assert_eq!(ty.generics.concrete[0], concrete);
+            match &ty.fields {
+                StructFields::Named(fields) => {
+                    assert_eq!(fields[0].ty.to_token_stream().to_string(), concrete);
+                    assert_eq!(fields[1].ty.to_token_stream().to_string(), "u8");
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we push an error if a generic struct is instantiated without being declared.
+    #[test]
+    fn error_if_generic_struct_not_declared() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                struct Point<f32>;
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::GenericStructNotDeclared { struct_ident } => {
+                assert_eq!(struct_ident, "Point");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push an error if a generic parameter is used within a field's type.
+    #[test]
+    fn error_if_generic_param_nested_in_field_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point<T> {
+                    x: Option<T>
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::GenericStructFieldNestedParam { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Option < T >");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we return an error if a generic struct instantiation is used in a `Vec<T>`.
+    #[test]
+    fn error_if_vec_of_generic_struct_instantiation() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct Point<T> {
+                    x: T,
+                    y: T
+                }
+                struct Point<f32>;
+
+                extern "Rust" {
+                    fn pts() -> Vec<Point<f32>>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::UnsupportedType { ty, message } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Vec < Point < f32 > >");
+                assert_eq!(message, "Vec<Point<f32>> is not yet supported.");
+            }
+            _ => panic!(),
+        }
+    }
+
     /// Verify that we parse the swift_name = "..."
     #[test]
     fn parse_swift_name_attribute() {
@@ -369,6 +554,37 @@ mod tests {
         assert_eq!(ty2.derives.clone, true);
     }
 
+    /// Verify that we parse the comparison trait derives.
+    #[test]
+    fn parse_derive_comparison_traits() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+                struct Foo;
+
+                #[derive(PartialEq, PartialOrd)]
+                struct Bar;
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.derives.partial_eq);
+        assert!(ty.derives.eq);
+        assert!(ty.derives.hash);
+        assert!(ty.derives.partial_ord);
+        assert!(ty.derives.ord);
+
+        let ty2 = module.types.types()[1].unwrap_shared_struct();
+        assert!(ty2.derives.partial_eq);
+        assert!(!ty2.derives.eq);
+        assert!(!ty2.derives.hash);
+        assert!(ty2.derives.partial_ord);
+        assert!(!ty2.derives.ord);
+    }
+
     /// Verify that we properly parse multiple comma separated struct attributes.
     #[test]
     fn parses_multiple_struct_attributes() {
@@ -429,6 +645,206 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse the `#[swift_bridge(Codable)]` attribute.
+    #[test]
+    fn parses_struct_codable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeType {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.codable);
+    }
+
+    /// Verify that the fields of a `#[swift_bridge(Codable)]` struct can be other types that are
+    /// `Codable` in Swift.
+    #[test]
+    fn codable_struct_codable_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeType {
+                    field_a: String,
+                    field_b: Option<u64>,
+                    field_c: AnotherType,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct AnotherType(bool);
+            }
+        };
+
+        parse_ok(tokens);
+    }
+
+    /// Verify that we return an error if a field of a `#[swift_bridge(Codable)]` struct is not
+    /// `Codable` in Swift.
+    #[test]
+    fn error_if_codable_struct_field_not_codable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeType {
+                    field_a: Vec<u8>,
+                    field_b: SomeOpaqueType,
+                    field_c: u128,
+                    field_d: Option<AnotherType>,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct AnotherType(bool);
+
+                extern "Rust" {
+                    type SomeOpaqueType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::CodableFieldNotCodable { ty } => ty.to_token_stream().to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            tys,
+            vec![
+                "Vec < u8 >",
+                "SomeOpaqueType",
+                "u128",
+                "Option < AnotherType >"
+            ]
+        );
+    }
+
+    /// Verify that we return an error if a field of a struct that derives `PartialEq`, `Hash` or
+    /// `PartialOrd` does not conform to the corresponding Swift protocol.
+    #[test]
+    fn error_if_derive_struct_field_does_not_conform() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(PartialEq, Hash, PartialOrd)]
+                struct SomeType {
+                    field_a: u8,
+                    field_b: Vec<u8>,
+                    field_c: SomeOpaqueType,
+                    field_d: bool,
+                    field_e: Option<u8>,
+                    field_f: AnotherType,
+                    field_g: ComparableOpaqueType,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(PartialEq, Hash)]
+                struct AnotherType(u8);
+
+                extern "Rust" {
+                    type SomeOpaqueType;
+
+                    #[swift_bridge(Equatable, Hashable, Comparable)]
+                    type ComparableOpaqueType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        let tys: Vec<(String, &str)> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::DeriveFieldDoesNotConform { ty, protocol } => {
+                    (ty.to_token_stream().to_string(), protocol.swift_name())
+                }
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            tys,
+            vec![
+                ("Vec < u8 >".to_string(), "Equatable"),
+                ("SomeOpaqueType".to_string(), "Equatable"),
+                ("bool".to_string(), "Comparable"),
+                ("Option < u8 >".to_string(), "Comparable"),
+                ("AnotherType".to_string(), "Comparable"),
+            ]
+        );
+    }
+
+    /// Verify that we return an error if a struct that does not derive `Clone` is used in a
+    /// `Vec<T>`, since we only generate `Vec<T>` support for `Clone` structs.
+    #[test]
+    fn error_if_vec_of_struct_without_clone() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct CloneStruct {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Vec<SomeStruct>) -> Vec<CloneStruct>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::UnsupportedType { ty, message } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Vec < SomeStruct >");
+                assert_eq!(
+                    message,
+                    "Vec<SomeStruct> requires `SomeStruct` to #[derive(Clone)]."
+                );
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(serde)]` attribute, and that it does not
+    /// require a `swift_repr`.
+    #[test]
+    fn parses_struct_serde_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(serde)]
+                struct SomeType {
+                    field: Vec<HashMap<String, u8>>
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.serde);
+        assert_eq!(ty.swift_repr, StructSwiftRepr::Structure);
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
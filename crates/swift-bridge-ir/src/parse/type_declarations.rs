@@ -1,3 +1,4 @@
+use crate::bridged_type::bridgeable_trait_object::BridgeableTraitObject;
 use crate::bridged_type::{
     BridgedType, CustomBridgedType, OpaqueForeignType, SharedEnum, SharedStruct, SharedType,
 };
@@ -17,6 +18,7 @@ pub(crate) use self::generics::*;
 pub(crate) struct TypeDeclarations {
     decls: HashMap<String, TypeDeclaration>,
     order: Vec<String>,
+    module_id: String,
 }
 
 #[derive(Clone)]
@@ -33,6 +35,10 @@ pub(crate) enum SharedTypeDeclaration {
 
 impl TypeDeclaration {
     pub fn to_bridged_type(&self, reference: bool, mutable: bool) -> BridgedType {
+        if let Some(serde_type) = self.to_serde_type() {
+            return BridgedType::Bridgeable(Box::new(serde_type));
+        }
+
         match self {
             TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
                 BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(
@@ -74,9 +80,33 @@ pub(crate) struct OpaqueForeignTypeDeclaration {
 }
 
 impl OpaqueForeignTypeDeclaration {
+    /// The `Box<dyn SomeProtocol>`, `&dyn SomeProtocol` or `&mut dyn SomeProtocol` for a
+    /// `#[swift_bridge(protocol)]` type.
+    pub(crate) fn to_trait_object(
+        &self,
+        reference: bool,
+        mutable: bool,
+    ) -> Option<BridgeableTraitObject> {
+        if !self.attributes.protocol {
+            return None;
+        }
+
+        Some(BridgeableTraitObject {
+            ty: self.ty.clone(),
+            host_lang: self.host_lang,
+            reference,
+            mutable,
+        })
+    }
+
     pub(crate) fn ffi_repr_type_tokens(&self) -> TokenStream {
         if self.attributes.copy.is_some() {
             self.ffi_copy_repr_ident().to_token_stream()
+        } else if self.attributes.protocol {
+            let ty_name = &self.ty;
+            quote::quote! {
+                *mut Box<dyn super::#ty_name>
+            }
         } else {
             let ty_name = &self.ty;
             quote::quote! {
@@ -238,11 +268,42 @@ impl TypeDeclarations {
         self.get(&ty)
     }
 
+    /// Get the generic `struct Point<T> { .. }` declaration that `struct Point<f32>;`
+    /// instantiates.
+    pub(crate) fn get_generic_shared_struct(
+        &self,
+        name: &Ident,
+        generics_count: usize,
+    ) -> Option<&SharedStruct> {
+        self.decls.values().find_map(|decl| match decl {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))
+                if &shared_struct.name == name
+                    && shared_struct.generics.is_generic_declaration()
+                    && shared_struct.generics.params.len() == generics_count =>
+            {
+                Some(shared_struct)
+            }
+            _ => None,
+        })
+    }
+
     pub(crate) fn insert(&mut self, type_name: String, ty: TypeDeclaration) {
         self.decls.insert(type_name.clone(), ty);
         self.order.push(type_name);
     }
 
+    /// An identifier that is unique to the bridge module within its crate.
+    ///
+    /// Used to name the `extern "C"` functions that we generate for generic built-in types such
+    /// as `HashMap<K, V>`, since more than one bridge module can use the same type.
+    pub(crate) fn module_id(&self) -> &str {
+        &self.module_id
+    }
+
+    pub(crate) fn set_module_id(&mut self, module_id: String) {
+        self.module_id = module_id;
+    }
+
     pub fn types(&self) -> Vec<&TypeDeclaration> {
         self.order
             .iter()
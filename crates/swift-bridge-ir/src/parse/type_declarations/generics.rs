@@ -53,6 +53,41 @@ impl OpaqueRustTypeGenerics {
         bounds.join(", ")
     }
 
+    /// For Rust type `SomeType<u32, u64>`:
+    /// A.self == UInt32.self && B.self == UInt64.self
+    pub(crate) fn rust_opaque_type_swift_generic_type_checks(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if self.generics.is_empty() {
+            return "".to_string();
+        }
+
+        let checks: Vec<String> = self
+            .generics
+            .iter()
+            .enumerate()
+            .map(|(idx, g)| {
+                format!(
+                    "{}.self == {}.self",
+                    GENERIC_PLACEHOLDERS[idx],
+                    BridgedType::new_with_str(&g.ident.to_string(), types)
+                        .unwrap()
+                        // TODO: FnReturn isn't the real position.. Add a
+                        //  new variant that makes more sense for our use case (generic bounds).
+                        .to_swift_type(
+                            TypePosition::FnReturn(HostLang::Rust),
+                            types,
+                            swift_bridge_path
+                        )
+                )
+            })
+            .collect();
+
+        checks.join(" && ")
+    }
+
     /// "<A, B, C>" if there are generics.
     /// "" if there are no generics.
     pub(crate) fn angle_bracketed_generic_placeholders_string(&self) -> String {
@@ -1,8 +1,8 @@
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned, ToTokens};
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
@@ -20,6 +20,19 @@ pub(crate) enum SwiftFuncGenerics {
     Str,
 }
 
+/// The `&str` that an `extern "Swift"` function returns when it has nothing for the `&str` to
+/// borrow from, such as `fn make_string() -> &str`.
+///
+/// Swift returns a `String` whose bytes it keeps alive until Rust drops the `SwiftStr` that it
+/// receives.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum SwiftOwnedStr {
+    /// `-> &str` becomes `-> SwiftStr`
+    Str,
+    /// `-> Option<&str>` becomes `-> Option<SwiftStr>`
+    OptionStr,
+}
+
 impl SwiftFuncGenerics {
     pub fn as_bound(&self) -> &'static str {
         match self {
@@ -93,6 +106,119 @@ pub(crate) struct ParsedExternFn {
     /// Get one of the associated type's fields
     pub get_field: Option<GetField>,
     pub argument_labels: HashMap<Ident, LitStr>,
+    /// The concrete generics of one of the instantiations of a generic function.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(monomorphize = (u32, f64))]
+    /// fn parse<T: FromStr>(s: &str) -> Option<T>;
+    ///
+    /// // Approximate generated code for the `[u32]` instantiation
+    /// extern "C" fn parse_u32(s: RustStr) -> Option<u32> {
+    ///     super::parse::<u32>(s.to_str())
+    /// }
+    /// ```
+    pub monomorphized_generics: Vec<Ident>,
+    /// A Swift operator or subscript that calls this function.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(operator = "+")]
+    /// fn add(&self, other: &Vector) -> Vector;
+    ///
+    /// // Approximate generated Swift code
+    /// extension VectorRef {
+    ///     public static func + (lhs: VectorRef, rhs: VectorRef) -> Vector {
+    ///         lhs.add(rhs)
+    ///     }
+    /// }
+    /// ```
+    pub operator: Option<SwiftOperator>,
+    /// The name of the Swift computed property that this method is the getter of.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(property = "name")]
+    /// fn name(&self) -> String;
+    ///
+    /// // Approximate generated Swift code
+    /// extension PersonRef {
+    ///     public var name: RustString {
+    ///         RustString(ptr: __swift_bridge__$Person$name(ptr))
+    ///     }
+    /// }
+    /// ```
+    pub property: Option<LitStr>,
+    /// The name of the Swift computed property that this method is the setter of.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(setter = "name")]
+    /// fn set_name(&mut self, name: String);
+    /// ```
+    pub setter: Option<LitStr>,
+}
+
+/// A Swift operator that can be implemented by a bridged function.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum SwiftOperator {
+    /// `+`
+    Add,
+    /// `-`, either infix subtraction or prefix negation.
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `%`
+    Rem,
+    /// `!`
+    Not,
+    /// `[]`, a subscript's getter.
+    Index,
+    /// `[]=`, a subscript's setter.
+    IndexSet,
+}
+
+impl SwiftOperator {
+    pub(crate) fn from_symbol(symbol: &str) -> Option<Self> {
+        let operator = match symbol {
+            "+" => SwiftOperator::Add,
+            "-" => SwiftOperator::Sub,
+            "*" => SwiftOperator::Mul,
+            "/" => SwiftOperator::Div,
+            "%" => SwiftOperator::Rem,
+            "!" => SwiftOperator::Not,
+            "[]" => SwiftOperator::Index,
+            "[]=" => SwiftOperator::IndexSet,
+            _ => return None,
+        };
+        Some(operator)
+    }
+
+    pub(crate) fn symbol(&self) -> &'static str {
+        match self {
+            SwiftOperator::Add => "+",
+            SwiftOperator::Sub => "-",
+            SwiftOperator::Mul => "*",
+            SwiftOperator::Div => "/",
+            SwiftOperator::Rem => "%",
+            SwiftOperator::Not => "!",
+            SwiftOperator::Index => "[]",
+            SwiftOperator::IndexSet => "[]=",
+        }
+    }
+
+    /// Whether or not a function with this many arguments, including `self`, can implement
+    /// the operator.
+    pub(crate) fn accepts_operand_count(&self, count: usize) -> bool {
+        match self {
+            SwiftOperator::Sub => count == 1 || count == 2,
+            SwiftOperator::Not => count == 1,
+            SwiftOperator::IndexSet => count == 3,
+            _ => count == 2,
+        }
+    }
 }
 
 pub(crate) enum GetField {
@@ -145,6 +271,86 @@ impl ParsedExternFn {
         }
     }
 
+    /// Whether or not a `#[swift_bridge(serde)]` type is passed to or returned from the function.
+    ///
+    /// The Swift function throws if a value can not be converted to or from JSON.
+    pub fn contains_serde_type(&self, types: &TypeDeclarations) -> bool {
+        let args = self
+            .func
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| BridgedType::new_with_fn_arg(arg, types));
+        let ret = BridgedType::new_with_return_type(&self.func.sig.output, types);
+
+        args.chain(ret).any(|ty| ty.contains_serde_type_recursive())
+    }
+
+    /// Whether or not Rust can elide the lifetime of the reference that an `extern "Swift"`
+    /// function returns, such as a `&str`.
+    ///
+    /// The function must take `&self` or exactly one reference argument for the returned
+    /// reference to borrow from.
+    /// We count the references in the generated Rust function's arguments, since some arguments
+    /// such as `&SomeOpaqueRustType` are not passed to it as references.
+    pub(crate) fn returned_reference_is_bound(&self, types: &TypeDeclarations) -> bool {
+        let sig = &self.func.sig;
+
+        let ret = match &sig.output {
+            ReturnType::Type(_, ty) => ty,
+            ReturnType::Default => return true,
+        };
+        if count_elided_references(ret.to_token_stream()) == 0 {
+            return true;
+        }
+
+        let mut arg_references = 0;
+        for arg in sig.inputs.iter() {
+            match arg {
+                FnArg::Receiver(receiver) => {
+                    if receiver.reference.is_some() {
+                        return true;
+                    }
+                }
+                FnArg::Typed(_) => {
+                    if let Some(ty) = BridgedType::new_with_fn_arg(arg, types) {
+                        arg_references += count_elided_references(
+                            ty.maybe_convert_pointer_to_super_pointer(types),
+                        );
+                    }
+                }
+            }
+        }
+
+        arg_references == 1
+    }
+
+    /// Whether this is an `extern "Swift"` function that returns a `&str` or `Option<&str>` with
+    /// nothing to borrow from, so Swift returns a buffer that it owns.
+    pub(crate) fn swift_owned_str_return(&self, types: &TypeDeclarations) -> Option<SwiftOwnedStr> {
+        if !self.host_lang.is_swift() || self.returned_reference_is_bound(types) {
+            return None;
+        }
+
+        match BridgedType::new_with_return_type(&self.func.sig.output, types)? {
+            BridgedType::StdLib(StdLibType::Str) => Some(SwiftOwnedStr::Str),
+            BridgedType::StdLib(StdLibType::Option(opt))
+                if matches!(opt.ty.deref(), BridgedType::StdLib(StdLibType::Str)) =>
+            {
+                Some(SwiftOwnedStr::OptionStr)
+            }
+            _ => None,
+        }
+    }
+
+    /// The type of the index that a `[]` or `[]=` subscript operator function takes after `self`.
+    pub(crate) fn subscript_index_ty(&self) -> Option<&Type> {
+        match self.func.sig.inputs.iter().nth(1)? {
+            FnArg::Typed(pat_ty) => Some(&pat_ty.ty),
+            FnArg::Receiver(_) => None,
+        }
+    }
+
     pub fn self_mutability(&self) -> Option<Token![mut]> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.mutability,
@@ -163,6 +369,10 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let sig = &self.func.sig;
 
+        if self.swift_owned_str_return(types).is_some() {
+            return quote! { -> #swift_bridge_path::string::SwiftStrFfi };
+        }
+
         if let Some(ret) = BridgedType::new_with_return_type(&sig.output, types) {
             if ret.can_be_encoded_with_zero_bytes() {
                 return quote! {};
@@ -280,6 +490,10 @@ impl ParsedExternFn {
                         if self.host_lang.is_rust() {
                             arg = if let Some(repr) = built_in.only_encoding() {
                                 repr.rust
+                            } else if let BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) =
+                                &built_in
+                            {
+                                boxed_fn.swift_closure_into_boxed_fn(&arg, types)
                             } else {
                                 built_in.convert_ffi_expression_to_rust_type(
                                     &arg,
@@ -327,7 +541,7 @@ impl ParsedExternFn {
     pub fn to_c_header_params(&self, types: &TypeDeclarations) -> String {
         let mut params = vec![];
         let inputs = &self.func.sig.inputs;
-        for arg in inputs {
+        for (idx, arg) in inputs.iter().enumerate() {
             match arg {
                 FnArg::Receiver(_receiver) => {
                     self.push_self_param(&mut params);
@@ -344,7 +558,14 @@ impl ParsedExternFn {
                             continue;
                         }
 
-                        let ty = built_in.to_c(types);
+                        let ty = match &built_in {
+                            BridgedType::StdLib(StdLibType::BoxedFn(_))
+                                if self.host_lang.is_rust() =>
+                            {
+                                self.call_boxed_fn_link_name(idx)
+                            }
+                            _ => built_in.to_c(types),
+                        };
 
                         let arg_name = pat.to_token_stream().to_string();
                         params.push(format!("{} {}", ty, arg_name));
@@ -466,13 +687,23 @@ impl ParsedExternFn {
             .unwrap_or("".to_string());
 
         format!(
-            "{}{}${}",
+            "{}{}${}{}",
             SWIFT_BRIDGE_PREFIX,
             host_type,
-            self.func.sig.ident.to_string()
+            self.func.sig.ident.to_string(),
+            self.dollar_prefixed_monomorphized_generics()
         )
     }
 
+    /// "$u32" for the `u32` instantiation of `#[swift_bridge(monomorphize = (u32, f64))]`.
+    /// "" if the function is not generic.
+    pub fn dollar_prefixed_monomorphized_generics(&self) -> String {
+        self.monomorphized_generics
+            .iter()
+            .map(|g| format!("${}", g))
+            .collect()
+    }
+
     pub fn call_boxed_fn_link_name(&self, boxed_fn_idx: usize) -> String {
         format!("{}$param{}", self.link_name(), boxed_fn_idx)
     }
@@ -480,13 +711,23 @@ impl ParsedExternFn {
         format!("{}$_free$param{}", self.link_name(), boxed_fn_idx)
     }
 
+    /// The Rust struct that holds a Swift closure that was passed to an `extern "Rust"` function.
+    ///
+    /// `__swift_bridge__some_function_param0`
+    pub fn swift_closure_struct_name(&self, boxed_fn_idx: usize) -> Ident {
+        Ident::new(
+            &format!("{}_param{}", self.prefixed_fn_name(), boxed_fn_idx),
+            self.sig.ident.span(),
+        )
+    }
+
     /// Generates something like:
     /// void __swift_bridge__$some_function$param0(void* boxed_fn, uint8_t arg);
     /// void __swift_bridge__$some_function$_free$param0(void* boxed_fn);
     pub fn boxed_fn_to_c_header_fns(
         &self,
         idx: usize,
-        boxed_fn: &BridgeableBoxedFnOnce,
+        boxed_fn: &BridgeableBoxedFn,
         types: &TypeDeclarations,
     ) -> String {
         let call_boxed_fn_link_name = self.call_boxed_fn_link_name(idx);
@@ -528,12 +769,18 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
             })
             .unwrap_or_default();
         let fn_name = &self.func.sig.ident;
+        let monomorphized_generics: String = self
+            .monomorphized_generics
+            .iter()
+            .map(|g| format!("_{}", g))
+            .collect();
         let prefixed_fn_name = Ident::new(
             &format!(
-                "{}{}{}",
+                "{}{}{}{}",
                 SWIFT_BRIDGE_PREFIX,
                 host_type_prefix,
-                fn_name.to_string()
+                fn_name.to_string(),
+                monomorphized_generics
             ),
             fn_name.span(),
         );
@@ -546,7 +793,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
     pub fn args_filtered_to_boxed_fns(
         &self,
         type_decls: &TypeDeclarations,
-    ) -> Vec<(usize, BridgeableBoxedFnOnce)> {
+    ) -> Vec<(usize, BridgeableBoxedFn)> {
         self.func
             .sig
             .inputs
@@ -556,7 +803,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
                 let ty = BridgedType::new_with_fn_arg(arg, type_decls)?;
 
                 match ty {
-                    BridgedType::StdLib(StdLibType::BoxedFnOnce(boxed_fn)) => Some((idx, boxed_fn)),
+                    BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) => Some((idx, boxed_fn)),
                     _ => None,
                 }
             })
@@ -575,13 +822,14 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
 
         for (idx, fn_once) in self.args_filtered_to_boxed_fns(types) {
             let arg_name = self.arg_name_at_idx(idx).unwrap();
+            let class_prefix = fn_once.kind.swift_callback_class_prefix();
 
             if fn_once.params.is_empty() && fn_once.ret.is_null() {
                 initializers += &format!(
-                "{maybe_space}let cb{idx} = __private__RustFnOnceCallbackNoArgsNoRet(ptr: {arg_name});"
-            );
+                    "{maybe_space}let cb{idx} = {class_prefix}NoArgsNoRet(ptr: {arg_name});"
+                );
             } else {
-                initializers += &format!("{maybe_space}let cb{idx} = __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx}(ptr: {arg_name});");
+                initializers += &format!("{maybe_space}let cb{idx} = {class_prefix}{maybe_associated_ty}${fn_name}$param{idx}(ptr: {arg_name});");
             }
 
             maybe_space = " ";
@@ -660,6 +908,32 @@ impl Deref for ParsedExternFn {
     }
 }
 
+/// `&str` -> 1, `(&str, &'static str)` -> 1, `Option<&str>` -> 1, `u8` -> 0
+fn count_elided_references(tokens: TokenStream) -> usize {
+    let mut count = 0;
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '&' => {
+                let has_lifetime = matches!(
+                    tokens.peek(),
+                    Some(TokenTree::Punct(next)) if next.as_char() == '\''
+                );
+                if !has_lifetime {
+                    count += 1;
+                }
+            }
+            TokenTree::Group(group) => {
+                count += count_elided_references(group.stream());
+            }
+            _ => {}
+        }
+    }
+
+    count
+}
+
 pub(crate) fn fn_arg_is_mutable_reference(fn_arg: &FnArg) -> bool {
     match fn_arg {
         FnArg::Receiver(receiver) => receiver.reference.is_some() && receiver.mutability.is_some(),
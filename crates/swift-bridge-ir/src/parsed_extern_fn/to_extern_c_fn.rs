@@ -132,6 +132,21 @@ impl ParsedExternFn {
             }
         };
 
+        let fn_name = if self.monomorphized_generics.is_empty() {
+            fn_name
+        } else {
+            let generics: Vec<TokenStream> = self
+                .monomorphized_generics
+                .iter()
+                .map(|g| {
+                    BridgedType::new_with_str(&g.to_string(), types)
+                        .unwrap()
+                        .to_rust_type_path(types)
+                })
+                .collect();
+            quote! { #fn_name::<#(#generics),*> }
+        };
+
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
 
         let call_fn = quote! {
@@ -147,7 +162,7 @@ impl ParsedExternFn {
         let return_ty = self.return_ty_built_in(types).unwrap();
 
         if self.return_into {
-            call_fn = return_ty.rust_expression_into(&call_fn);
+            call_fn = return_ty.rust_expression_into(&call_fn, types);
         }
 
         if let Some(return_with) = self.return_with.as_ref() {
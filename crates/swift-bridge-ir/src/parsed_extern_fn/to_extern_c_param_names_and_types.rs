@@ -1,4 +1,4 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
+use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use proc_macro2::{Ident, TokenStream};
@@ -17,7 +17,7 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let mut params = vec![];
         let inputs = &self.func.sig.inputs;
-        for arg in inputs {
+        for (idx, arg) in inputs.iter().enumerate() {
             match arg {
                 FnArg::Receiver(_receiver) => match self.host_lang {
                     HostLang::Rust => {
@@ -55,7 +55,23 @@ impl ParsedExternFn {
                             }
 
                             let pat = &pat_ty.pat;
-                            let ty = built_in.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                            let ty = match &built_in {
+                                BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn))
+                                    if self.host_lang.is_rust() =>
+                                {
+                                    let struct_name = self.swift_closure_struct_name(idx);
+                                    let swift_closure_struct = boxed_fn.swift_closure_rust_struct(
+                                        &struct_name,
+                                        swift_bridge_path,
+                                        types,
+                                    );
+                                    custom_type_definitions
+                                        .insert(struct_name.to_string(), swift_closure_struct);
+
+                                    quote! { #struct_name }
+                                }
+                                _ => built_in.to_ffi_compatible_rust_type(swift_bridge_path, types),
+                            };
 
                             params.push(quote! { #pat: #ty});
 
@@ -1,6 +1,7 @@
+use crate::bridged_type::boxed_fn::BoxedFnKind;
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parsed_extern_fn::{ParsedExternFn, SwiftOwnedStr};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use std::ops::Deref;
@@ -34,28 +35,37 @@ impl ParsedExternFn {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let sig = &self.func.sig;
-        let fn_name = &sig.ident;
+        let func = self.to_rust_trait_method_that_calls_a_swift_extern(swift_bridge_path, types);
 
-        let ret = &sig.output;
+        quote! {
+            pub #func
+        }
+    }
 
-        let ret = match &ret {
-            ReturnType::Default => {
-                quote! {#ret}
-            }
-            ReturnType::Type(arrow, _ty) => {
-                if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
-                    let ty = built_in.maybe_convert_pointer_to_super_pointer(types);
-                    let return_ty_span = sig.output.span();
+    /// Generates the `fn render(&self, width: u32) -> String;` declaration that a
+    /// `#[swift_bridge(protocol)]` type's generated Rust trait holds.
+    pub fn to_rust_trait_method_declaration(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = self.rust_sig_that_calls_a_swift_extern(swift_bridge_path, types);
 
-                    quote_spanned! {return_ty_span=> #arrow #ty}
-                } else {
-                    quote! { #ret }
-                }
-            }
-        };
+        quote! {
+            #sig;
+        }
+    }
+
+    /// Generates a function that calls a Swift extern, without a visibility modifier so that it
+    /// can be used to implement a `#[swift_bridge(protocol)]` type's generated Rust trait.
+    pub fn to_rust_trait_method_that_calls_a_swift_extern(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = &self.func.sig;
+        let rust_sig = self.rust_sig_that_calls_a_swift_extern(swift_bridge_path, types);
 
-        let params = self.params_with_explicit_self_types_removed(types);
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
         let linked_fn_name = self.extern_swift_linked_fn_new();
 
@@ -63,7 +73,12 @@ impl ParsedExternFn {
             unsafe { #linked_fn_name(#call_args) }
         };
 
-        if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
+        if let Some(swift_owned_str) = self.swift_owned_str_return(types) {
+            inner = match swift_owned_str {
+                SwiftOwnedStr::Str => quote! { #inner.into_swift_str() },
+                SwiftOwnedStr::OptionStr => quote! { #inner.into_option_swift_str() },
+            };
+        } else if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types) {
             inner = built_in.convert_ffi_expression_to_rust_type(
                 &inner,
                 sig.output.span(),
@@ -75,12 +90,55 @@ impl ParsedExternFn {
         }
 
         quote! {
-            pub fn #fn_name(#params) #ret {
+            #rust_sig {
                 #inner
             }
         }
     }
 
+    fn rust_sig_that_calls_a_swift_extern(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = &self.func.sig;
+        let fn_name = &sig.ident;
+
+        let ret = &sig.output;
+
+        let ret = match &ret {
+            ReturnType::Default => {
+                quote! {#ret}
+            }
+            ReturnType::Type(arrow, _ty) => {
+                if let Some(swift_owned_str) = self.swift_owned_str_return(types) {
+                    let swift_str = quote! { #swift_bridge_path::string::SwiftStr };
+                    let ty = match swift_owned_str {
+                        SwiftOwnedStr::Str => swift_str,
+                        SwiftOwnedStr::OptionStr => quote! { Option<#swift_str> },
+                    };
+                    let return_ty_span = sig.output.span();
+
+                    quote_spanned! {return_ty_span=> #arrow #ty}
+                } else if let Some(built_in) = BridgedType::new_with_return_type(&sig.output, types)
+                {
+                    let ty = built_in.maybe_convert_pointer_to_super_pointer(types);
+                    let return_ty_span = sig.output.span();
+
+                    quote_spanned! {return_ty_span=> #arrow #ty}
+                } else {
+                    quote! { #ret }
+                }
+            }
+        };
+
+        let params = self.params_with_explicit_self_types_removed(types);
+
+        quote! {
+            fn #fn_name(#params) #ret
+        }
+    }
+
     /// #\[export_name = "__swift_bridge__$SomeType$some_method$param1"]
     /// pub extern "C" fn SomeType_some_method_param1(boxed_fn: *mut dyn FnOnce(u8) -> (), arg0: u8) {
     ///     unsafe { Box::from_raw(boxed_fn) }(arg0)
@@ -144,8 +202,21 @@ impl ParsedExternFn {
             let arg_name = self.arg_name_tokens_at_idx(idx).unwrap();
             let arg_name = Ident::new(&format!("{}_{}", fn_name, arg_name), arg_name.span());
 
-            let call_boxed_fn = quote! {
-                unsafe { Box::from_raw(#arg_name)(#(#call_args),*) }
+            // An `FnOnce` is consumed by the call, while an `Fn` or `FnMut` stays alive until
+            // Swift frees it.
+            // Swift could call an `FnMut` again while it is running, such as when the function
+            // calls a Swift function that calls it, so we borrow it through its `RefCell` and
+            // abort if it is already borrowed.
+            let call_boxed_fn = match boxed_fn.kind {
+                BoxedFnKind::FnOnce => quote! {
+                    unsafe { Box::from_raw(#arg_name)(#(#call_args),*) }
+                },
+                BoxedFnKind::FnMut => quote! {
+                    (*#swift_bridge_path::boxed_fn_support::borrow_boxed_fn_mut(unsafe { &*#arg_name }))(#(#call_args),*)
+                },
+                BoxedFnKind::Fn => quote! {
+                    unsafe { (*#arg_name)(#(#call_args),*) }
+                },
             };
             let call_boxed_fn = boxed_fn.ret.convert_rust_expression_to_ffi_type(
                 &call_boxed_fn,
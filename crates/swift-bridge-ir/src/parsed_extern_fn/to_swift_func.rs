@@ -1,5 +1,7 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, TypePosition};
-use crate::parse::TypeDeclarations;
+use crate::bridged_type::{
+    pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType, TypePosition,
+};
+use crate::parse::{HostLang, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use quote::{format_ident, ToTokens};
 use std::ops::Deref;
@@ -105,33 +107,44 @@ impl ParsedExternFn {
                     let arg = pat.to_token_stream().to_string();
                     let arg_name = arg.clone();
 
-                    let arg =
-                        if let Some(bridged_ty) = BridgedType::new_with_type(&pat_ty.ty, types) {
-                            if self.host_lang.is_rust() {
-                                if bridged_ty.can_be_encoded_with_zero_bytes() {
-                                    continue;
-                                }
+                    let arg = if let Some(bridged_ty) =
+                        BridgedType::new_with_type(&pat_ty.ty, types)
+                    {
+                        if self.host_lang.is_rust() {
+                            if bridged_ty.can_be_encoded_with_zero_bytes() {
+                                continue;
+                            }
 
+                            if let BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) = &bridged_ty
+                            {
+                                boxed_fn.convert_swift_closure_to_ffi_type(
+                                    &arg,
+                                    &self.call_boxed_fn_link_name(arg_idx),
+                                    types,
+                                    swift_bridge_path,
+                                )
+                            } else {
                                 bridged_ty.convert_swift_expression_to_ffi_type(
                                     &arg,
                                     types,
                                     TypePosition::FnArg(self.host_lang, arg_idx),
                                 )
-                            } else {
-                                if let Some(only) = bridged_ty.only_encoding() {
-                                    only.swift
-                                } else {
-                                    bridged_ty.convert_ffi_value_to_swift_value(
-                                        &arg,
-                                        TypePosition::FnArg(self.host_lang, arg_idx),
-                                        types,
-                                        swift_bridge_path,
-                                    )
-                                }
                             }
                         } else {
-                            todo!("Push to ParsedErrors")
-                        };
+                            if let Some(only) = bridged_ty.only_encoding() {
+                                only.swift
+                            } else {
+                                bridged_ty.convert_ffi_value_to_swift_value(
+                                    &arg,
+                                    TypePosition::FnArg(self.host_lang, arg_idx),
+                                    types,
+                                    swift_bridge_path,
+                                )
+                            }
+                        }
+                    } else {
+                        todo!("Push to ParsedErrors")
+                    };
                     let arg = if include_var_name {
                         format!("{}: {}", arg_name, arg)
                     } else {
@@ -150,7 +163,15 @@ impl ParsedExternFn {
         types: &TypeDeclarations,
         swift_bridge_path: &Path,
     ) -> String {
+        // Swift throws if a `#[swift_bridge(serde)]` value can not be passed to or from Rust.
+        let throws_serde_error = self.host_lang.is_rust() && self.contains_serde_type(types);
+
+        if self.swift_owned_str_return(types).is_some() {
+            return " -> __private__SwiftStr".to_string();
+        }
+
         match &self.func.sig.output {
+            ReturnType::Default if throws_serde_error => " throws".to_string(),
             ReturnType::Default => "".to_string(),
             ReturnType::Type(_, ty) => {
                 if let Some(built_in) = BridgedType::new_with_type(&ty, types) {
@@ -160,7 +181,11 @@ impl ParsedExternFn {
                         }
                     }
 
-                    let maybe_throws = if built_in.is_result() { "throws " } else { "" };
+                    let maybe_throws = if built_in.is_result() || throws_serde_error {
+                        "throws "
+                    } else {
+                        ""
+                    };
 
                     format!(
                         " {}-> {}",
@@ -178,6 +203,74 @@ impl ParsedExternFn {
         }
     }
 
+    /// The requirement that a `#[swift_bridge(protocol)]` type's Swift protocol declares for
+    /// this method.
+    ///
+    /// For a Swift protocol, `fn render(&self, width: u32) -> String` becomes
+    /// `func render(width: UInt32) -> String`
+    ///
+    /// For a Rust trait, the requirement matches the method on the generated class, so
+    /// `fn get(&self, key: u32) -> u32` becomes `func get(_ key: UInt32) -> UInt32`
+    pub fn to_swift_protocol_requirement(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let fn_name = if let Some(swift_name) = self.swift_name_override.as_ref() {
+            swift_name.value()
+        } else {
+            self.func.sig.ident.to_string()
+        };
+
+        if self.host_lang.is_rust() {
+            return format!(
+                "func {}{}({}){}",
+                fn_name,
+                self.maybe_swift_generics(types),
+                self.to_swift_param_names_and_types(false, types, swift_bridge_path),
+                self.to_swift_return_type(types, swift_bridge_path)
+            );
+        }
+
+        let mut params = vec![];
+        for arg in self.func.sig.inputs.iter() {
+            let pat_ty = match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+                _ => continue,
+            };
+
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+            // Swift receives the same value that a Rust function would return to it.
+            let ty = BridgedType::new_with_type(&pat_ty.ty, types)
+                .unwrap()
+                .to_swift_type(
+                    TypePosition::FnReturn(HostLang::Rust),
+                    types,
+                    swift_bridge_path,
+                );
+
+            params.push(format!("{}: {}", arg_name, ty));
+        }
+
+        let ret = match BridgedType::new_with_return_type(&self.func.sig.output, types) {
+            Some(ret) if !ret.is_null() => {
+                // Swift returns the same value that it would pass to a Rust function. A protocol
+                // requirement can't introduce a generic return type, so we use a `String`.
+                let ret = ret
+                    .to_swift_type(
+                        TypePosition::FnArg(HostLang::Rust, 0),
+                        types,
+                        swift_bridge_path,
+                    )
+                    .replace("GenericIntoRustString", "String");
+                format!(" -> {}", ret)
+            }
+            _ => "".to_string(),
+        };
+
+        format!("func {}({}){}", fn_name, params.join(", "), ret)
+    }
+
     fn push_receiver_as_arg(&self, args: &mut Vec<String>, is_reference: bool) {
         let arg = if self.is_copy_method_on_opaque_type() {
             "self.bytes"
@@ -0,0 +1,42 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct ArrayTestStruct {
+        field: u8,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_array_u8(arg: [u8; 4]) -> [u8; 4];
+
+        fn rust_reflect_array_f64(arg: [f64; 3]) -> [f64; 3];
+
+        fn rust_reflect_array_transparent_struct(arg: [ArrayTestStruct; 2])
+            -> [ArrayTestStruct; 2];
+    }
+
+    extern "Rust" {
+        fn run_array_tests();
+    }
+
+    extern "Swift" {
+        fn swift_reflect_array_i32(arg: [i32; 3]) -> [i32; 3];
+    }
+}
+
+fn run_array_tests() {
+    assert_eq!(ffi::swift_reflect_array_i32([-1, 0, 1]), [-1, 0, 1]);
+}
+
+fn rust_reflect_array_u8(arg: [u8; 4]) -> [u8; 4] {
+    arg
+}
+
+fn rust_reflect_array_f64(arg: [f64; 3]) -> [f64; 3] {
+    arg
+}
+
+fn rust_reflect_array_transparent_struct(
+    arg: [ffi::ArrayTestStruct; 2],
+) -> [ffi::ArrayTestStruct; 2] {
+    arg
+}
@@ -1,3 +1,7 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Swift" {
@@ -19,6 +23,13 @@ mod ffi {
         fn swift_func_takes_callback_with_result_arg(
             arg: Box<dyn FnOnce(Result<CallbackTestOpaqueRustType, String>)>,
         );
+
+        fn swift_calls_rust_fn_callback_repeatedly(arg: Box<dyn Fn(u8) -> u8>) -> u8;
+        fn swift_calls_rust_fn_mut_callback_repeatedly(arg: Box<dyn FnMut() -> u32>) -> u32;
+        fn swift_calls_rust_fn_mut_callback_no_args_no_return(arg: Box<dyn FnMut()>);
+        fn swift_calls_rust_fn_callback_opaque_rust(
+            arg: Box<dyn Fn(CallbackTestOpaqueRustType) -> u32>,
+        ) -> u32;
     }
 
     extern "Swift" {
@@ -64,6 +75,40 @@ mod ffi {
     extern "Rust" {
         fn test_callbacks_rust_calls_swift();
     }
+
+    extern "Rust" {
+        fn rust_stores_swift_closure(callback: Box<dyn Fn(u32) -> bool + Send>);
+        fn rust_calls_stored_swift_closure(val: u32) -> bool;
+        fn rust_drops_stored_swift_closure();
+
+        fn rust_calls_swift_closure_on_another_thread(
+            callback: Box<dyn Fn(u32) -> bool + Send>,
+        ) -> bool;
+        fn rust_calls_swift_fn_mut_closure_twice(callback: Box<dyn FnMut()>);
+    }
+}
+
+static STORED_SWIFT_CLOSURE: Mutex<Option<Box<dyn Fn(u32) -> bool + Send>>> = Mutex::new(None);
+
+fn rust_stores_swift_closure(callback: Box<dyn Fn(u32) -> bool + Send>) {
+    *STORED_SWIFT_CLOSURE.lock().unwrap() = Some(callback);
+}
+
+fn rust_calls_stored_swift_closure(val: u32) -> bool {
+    (STORED_SWIFT_CLOSURE.lock().unwrap().as_ref().unwrap())(val)
+}
+
+fn rust_drops_stored_swift_closure() {
+    STORED_SWIFT_CLOSURE.lock().unwrap().take();
+}
+
+fn rust_calls_swift_closure_on_another_thread(callback: Box<dyn Fn(u32) -> bool + Send>) -> bool {
+    std::thread::spawn(move || callback(10)).join().unwrap()
+}
+
+fn rust_calls_swift_fn_mut_closure_twice(mut callback: Box<dyn FnMut()>) {
+    callback();
+    callback();
 }
 
 // TODO
@@ -136,4 +181,30 @@ fn test_callbacks_rust_calls_swift() {
     ffi::swift_func_takes_callback_with_result_arg(Box::new(|result| {
         assert_eq!(result.unwrap().val(), 555)
     }));
+
+    test_repeatable_callbacks_rust_calls_swift();
+}
+
+fn test_repeatable_callbacks_rust_calls_swift() {
+    // Swift calls the callback with 1, 2 and 3 and sums the results.
+    let sum_of_doubles = ffi::swift_calls_rust_fn_callback_repeatedly(Box::new(|num| num * 2));
+    assert_eq!(sum_of_doubles, 12);
+
+    let mut call_count = 0;
+    let last_call_count = ffi::swift_calls_rust_fn_mut_callback_repeatedly(Box::new(move || {
+        call_count += 1;
+        call_count
+    }));
+    assert_eq!(last_call_count, 3);
+
+    let call_count = Rc::new(Cell::new(0));
+    let call_count_clone = call_count.clone();
+    ffi::swift_calls_rust_fn_mut_callback_no_args_no_return(Box::new(move || {
+        call_count_clone.set(call_count_clone.get() + 1);
+    }));
+    assert_eq!(call_count.get(), 2);
+
+    // Swift passes 10 and then 20.
+    let sum = ffi::swift_calls_rust_fn_callback_opaque_rust(Box::new(|rust_ty| rust_ty.val()));
+    assert_eq!(sum, 30);
 }
@@ -1,3 +1,4 @@
 mod already_declared;
+mod codable;
 mod derive;
 mod swift_name;
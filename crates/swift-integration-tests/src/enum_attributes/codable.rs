@@ -0,0 +1,10 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(Codable)]
+    enum CodableEnum {
+        Unit,
+        NewType(u8),
+        Tuple(u8, u16),
+        Named { id: u32 },
+    }
+}
@@ -4,4 +4,11 @@ mod ffi {
     enum DeriveDebugEnum {
         Variant,
     }
+
+    #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+    enum DeriveComparisonEnum {
+        Low,
+        Medium(u8),
+        High { level: u8 },
+    }
 }
@@ -2,6 +2,9 @@ mod args_into;
 mod get;
 mod get_with;
 mod identifiable;
+mod monomorphize;
+mod operator;
+mod property;
 mod return_into;
 mod return_with;
 mod rust_name;
@@ -0,0 +1,13 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(monomorphize = (u32, f64))]
+        fn parse_number<T: FromStr>(s: &str) -> Option<T>;
+    }
+}
+
+use std::str::FromStr;
+
+fn parse_number<T: FromStr>(s: &str) -> Option<T> {
+    s.parse().ok()
+}
@@ -0,0 +1,131 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct OperatorMoney {
+        cents: i64,
+    }
+
+    extern "Rust" {
+        type OperatorVector;
+
+        #[swift_bridge(init)]
+        fn new(x: f64, y: f64) -> OperatorVector;
+
+        fn x(&self) -> f64;
+        fn y(&self) -> f64;
+
+        #[swift_bridge(operator = "+")]
+        fn add(&self, other: &OperatorVector) -> OperatorVector;
+
+        #[swift_bridge(operator = "-")]
+        fn neg(&self) -> OperatorVector;
+
+        #[swift_bridge(operator = "*")]
+        fn scale(&self, factor: f64) -> OperatorVector;
+
+        #[swift_bridge(operator = "[]")]
+        fn component(&self, index: usize) -> f64;
+
+        #[swift_bridge(operator = "[]=")]
+        fn set_component(&mut self, index: usize, value: f64);
+    }
+
+    extern "Rust" {
+        #[swift_bridge(operator = "+")]
+        fn add_operator_money(lhs: OperatorMoney, rhs: OperatorMoney) -> OperatorMoney;
+
+        #[swift_bridge(operator = "*")]
+        fn multiply_operator_money(factor: i64, money: OperatorMoney) -> OperatorMoney;
+    }
+}
+
+use self::ffi::OperatorMoney;
+use std::ops::{Add, Index, IndexMut, Mul, Neg};
+
+pub struct OperatorVector {
+    components: [f64; 2],
+}
+
+impl OperatorVector {
+    fn new(x: f64, y: f64) -> Self {
+        OperatorVector { components: [x, y] }
+    }
+
+    fn x(&self) -> f64 {
+        self.components[0]
+    }
+
+    fn y(&self) -> f64 {
+        self.components[1]
+    }
+
+    fn add(&self, other: &OperatorVector) -> OperatorVector {
+        self + other
+    }
+
+    fn neg(&self) -> OperatorVector {
+        -self
+    }
+
+    fn scale(&self, factor: f64) -> OperatorVector {
+        self * factor
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        self[index]
+    }
+
+    fn set_component(&mut self, index: usize, value: f64) {
+        self[index] = value;
+    }
+}
+
+impl Add for &OperatorVector {
+    type Output = OperatorVector;
+
+    fn add(self, other: &OperatorVector) -> OperatorVector {
+        OperatorVector::new(self.x() + other.x(), self.y() + other.y())
+    }
+}
+
+impl Neg for &OperatorVector {
+    type Output = OperatorVector;
+
+    fn neg(self) -> OperatorVector {
+        OperatorVector::new(-self.x(), -self.y())
+    }
+}
+
+impl Mul<f64> for &OperatorVector {
+    type Output = OperatorVector;
+
+    fn mul(self, factor: f64) -> OperatorVector {
+        OperatorVector::new(self.x() * factor, self.y() * factor)
+    }
+}
+
+impl Index<usize> for OperatorVector {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        &self.components[index]
+    }
+}
+
+impl IndexMut<usize> for OperatorVector {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        &mut self.components[index]
+    }
+}
+
+fn add_operator_money(lhs: OperatorMoney, rhs: OperatorMoney) -> OperatorMoney {
+    OperatorMoney {
+        cents: lhs.cents + rhs.cents,
+    }
+}
+
+fn multiply_operator_money(factor: i64, money: OperatorMoney) -> OperatorMoney {
+    OperatorMoney {
+        cents: factor * money.cents,
+    }
+}
@@ -0,0 +1,41 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type PropertyPerson;
+
+        #[swift_bridge(init)]
+        fn new(name: String, age: u8) -> PropertyPerson;
+
+        #[swift_bridge(property = "name")]
+        fn name(&self) -> String;
+
+        #[swift_bridge(setter = "name")]
+        fn set_name(&mut self, name: String);
+
+        #[swift_bridge(property = "age")]
+        fn age(&self) -> u8;
+    }
+}
+
+pub struct PropertyPerson {
+    name: String,
+    age: u8,
+}
+
+impl PropertyPerson {
+    fn new(name: String, age: u8) -> Self {
+        PropertyPerson { name, age }
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    fn age(&self) -> u8 {
+        self.age
+    }
+}
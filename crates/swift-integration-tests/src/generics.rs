@@ -8,6 +8,7 @@ mod ffi {
 
         fn new_some_generic_type_u32() -> SomeGenericType<u32>;
         fn reflect_generic_u32(arg: SomeGenericType<u32>) -> SomeGenericType<u32>;
+        fn reflect_vec_generic_u32(arg: Vec<SomeGenericType<u32>>) -> Vec<SomeGenericType<u32>>;
     }
 
     extern "Rust" {
@@ -16,6 +17,9 @@ mod ffi {
 
         fn new_some_generic_copy_type_u32() -> SomeGenericCopyType<u32>;
         fn reflect_generic_copy_u32(arg: SomeGenericCopyType<u32>) -> SomeGenericCopyType<u32>;
+        fn reflect_vec_generic_copy_u32(
+            arg: Vec<SomeGenericCopyType<u32>>,
+        ) -> Vec<SomeGenericCopyType<u32>>;
     }
 
     extern "Rust" {
@@ -56,6 +60,10 @@ fn reflect_generic_u32(arg: SomeGenericType<u32>) -> SomeGenericType<u32> {
     arg
 }
 
+fn reflect_vec_generic_u32(arg: Vec<SomeGenericType<u32>>) -> Vec<SomeGenericType<u32>> {
+    arg
+}
+
 fn new_some_generic_copy_type_u32() -> SomeGenericCopyType<u32> {
     SomeGenericCopyType { field: 123 }
 }
@@ -63,6 +71,12 @@ fn reflect_generic_copy_u32(arg: SomeGenericCopyType<u32>) -> SomeGenericCopyTyp
     arg
 }
 
+fn reflect_vec_generic_copy_u32(
+    arg: Vec<SomeGenericCopyType<u32>>,
+) -> Vec<SomeGenericCopyType<u32>> {
+    arg
+}
+
 fn new_generic_with_inner_opaque_type() -> GenericWithOpaqueRustInnerTy<InnerTy> {
     GenericWithOpaqueRustInnerTy { field: InnerTy }
 }
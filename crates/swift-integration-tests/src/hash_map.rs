@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hash_map_string_u32(arg: HashMap<String, u32>) -> HashMap<String, u32>;
+
+        fn rust_reflect_hash_map_u8_string(arg: HashMap<u8, String>) -> HashMap<u8, String>;
+    }
+
+    extern "Rust" {
+        fn run_hash_map_tests();
+    }
+
+    extern "Swift" {
+        fn swift_return_hash_map_string_u32() -> HashMap<String, u32>;
+        fn swift_arg_hash_map_string_u32(map: HashMap<String, u32>);
+    }
+}
+
+// A second bridge module that uses the same `HashMap<K, V>` as the module above.
+#[swift_bridge::bridge]
+mod ffi2 {
+    extern "Rust" {
+        fn rust_hash_map_string_u32_sum(arg: HashMap<String, u32>) -> u32;
+    }
+}
+
+fn run_hash_map_tests() {
+    let map = ffi::swift_return_hash_map_string_u32();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get("one"), Some(&1));
+    assert_eq!(map.get("two"), Some(&2));
+
+    let mut map = HashMap::new();
+    map.insert("three".to_string(), 3);
+    map.insert("four".to_string(), 4);
+    ffi::swift_arg_hash_map_string_u32(map);
+}
+
+fn rust_reflect_hash_map_string_u32(arg: HashMap<String, u32>) -> HashMap<String, u32> {
+    arg
+}
+
+fn rust_reflect_hash_map_u8_string(arg: HashMap<u8, String>) -> HashMap<u8, String> {
+    arg
+}
+
+fn rust_hash_map_string_u32_sum(arg: HashMap<String, u32>) -> u32 {
+    arg.values().sum()
+}
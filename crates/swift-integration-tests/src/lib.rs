@@ -1,21 +1,26 @@
 mod expose_opaque_rust_type;
 mod import_opaque_swift_class;
 
+mod array;
 mod async_function;
 mod boxed_functions;
 mod conditional_compilation;
 mod generics;
+mod hash_map;
 mod option;
 mod pointer;
 mod primitive;
 mod result;
 mod rust_function_uses_opaque_swift_type;
+mod serde_types;
+mod set;
 mod shared_types;
 mod single_representation_type_elision;
 mod slice;
 mod string;
 mod swift_function_uses_opaque_rust_type;
 mod swift_function_uses_opaque_swift_type;
+mod trait_objects;
 mod tuple;
 mod vec;
 
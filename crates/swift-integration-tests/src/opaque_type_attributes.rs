@@ -1,4 +1,7 @@
 mod already_declared;
+mod clone;
+mod comparable;
 mod copy;
+mod display_and_debug;
 mod equatable;
 mod hashable;
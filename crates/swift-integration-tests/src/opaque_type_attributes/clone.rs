@@ -0,0 +1,30 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Clone)]
+        type RustCloneType;
+
+        #[swift_bridge(init)]
+        fn new(num: isize) -> RustCloneType;
+
+        fn value(&self) -> isize;
+        fn set_value(&mut self, num: isize);
+    }
+}
+
+#[derive(Clone)]
+pub struct RustCloneType(isize);
+
+impl RustCloneType {
+    fn new(num: isize) -> Self {
+        RustCloneType(num)
+    }
+
+    fn value(&self) -> isize {
+        self.0
+    }
+
+    fn set_value(&mut self, num: isize) {
+        self.0 = num;
+    }
+}
@@ -0,0 +1,25 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Comparable)]
+        type RustComparableType;
+
+        #[swift_bridge(init)]
+        fn new(num: isize) -> RustComparableType;
+
+        fn value(&self) -> isize;
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct RustComparableType(isize);
+
+impl RustComparableType {
+    fn new(num: isize) -> Self {
+        RustComparableType(num)
+    }
+
+    fn value(&self) -> isize {
+        self.0
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Display, Debug)]
+        type RustPrintableType;
+
+        #[swift_bridge(init)]
+        fn new(name: &str) -> RustPrintableType;
+
+        fn as_ref(&self) -> &RustPrintableType;
+        fn as_mut(&mut self) -> &mut RustPrintableType;
+    }
+}
+
+#[derive(Debug)]
+pub struct RustPrintableType {
+    name: String,
+}
+
+impl RustPrintableType {
+    fn new(name: &str) -> Self {
+        RustPrintableType {
+            name: name.to_string(),
+        }
+    }
+
+    fn as_ref(&self) -> &Self {
+        self
+    }
+
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl Display for RustPrintableType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Printable {}", self.name)
+    }
+}
@@ -137,9 +137,25 @@ mod ffi {
         fn swift_reflect_option_bool(arg: Option<bool>) -> Option<bool>;
 
         fn swift_reflect_option_string(arg: Option<String>) -> Option<String>;
-        // TODO: Change to `swift_reflect_option_str` once we support Swift returning `-> &str`
-        fn swift_arg_option_str(arg: Option<&str>) -> bool;
-        // fn swift_reflect_option_str(arg: Option<&str>) -> Option<&str>;
+        fn swift_reflect_option_str(arg: Option<&str>) -> Option<&str>;
+
+        fn swift_reflect_option_enum_with_no_data(
+            arg: Option<OptionEnumWithNoData>,
+        ) -> Option<OptionEnumWithNoData>;
+        fn swift_reflect_option_opaque_rust_type(
+            arg: Option<OptTestOpaqueRustType>,
+        ) -> Option<OptTestOpaqueRustType>;
+    }
+
+    extern "Swift" {
+        type OptTestOpaqueSwiftType;
+
+        fn field(&self) -> u8;
+
+        fn swift_create_option_opaque_swift_type(field: u8) -> Option<OptTestOpaqueSwiftType>;
+        fn swift_reflect_option_opaque_swift_type(
+            arg: Option<OptTestOpaqueSwiftType>,
+        ) -> Option<OptTestOpaqueSwiftType>;
     }
 }
 
@@ -184,14 +200,24 @@ fn test_rust_calls_swift_option_primitive() {
         Some("hello".to_string())
     );
 
-    // TODO: Change to `swift_reflect_option_str` once we support Swift returning `-> &str`
-    assert_eq!(ffi::swift_arg_option_str(None), false);
-    assert_eq!(
-        ffi::swift_arg_option_str(Some("this is an option str")),
-        true
-    );
-    // assert_eq!(ffi::swift_reflect_option_str(None), None);
-    // assert_eq!(ffi::swift_reflect_option_str(Some("a str")), Some("a str"));
+    assert_eq!(ffi::swift_reflect_option_str(None), None);
+    assert_eq!(ffi::swift_reflect_option_str(Some("a str")), Some("a str"));
+
+    assert!(ffi::swift_reflect_option_enum_with_no_data(None).is_none());
+    assert!(matches!(
+        ffi::swift_reflect_option_enum_with_no_data(Some(ffi::OptionEnumWithNoData::Variant2)),
+        Some(ffi::OptionEnumWithNoData::Variant2)
+    ));
+
+    assert!(ffi::swift_reflect_option_opaque_rust_type(None).is_none());
+    let reflected =
+        ffi::swift_reflect_option_opaque_rust_type(Some(OptTestOpaqueRustType::new(123))).unwrap();
+    assert_eq!(reflected.field(), 123);
+
+    assert!(ffi::swift_reflect_option_opaque_swift_type(None).is_none());
+    let swift_type = ffi::swift_create_option_opaque_swift_type(55).unwrap();
+    let reflected = ffi::swift_reflect_option_opaque_swift_type(Some(swift_type)).unwrap();
+    assert_eq!(reflected.field(), 55);
 }
 
 pub struct OptTestOpaqueRustType {
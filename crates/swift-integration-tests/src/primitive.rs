@@ -1,5 +1,12 @@
 #[swift_bridge::bridge]
 mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct PrimitiveTestStruct {
+        id: u128,
+        offset: i128,
+        letter: char,
+    }
+
     extern "Rust" {
         fn test_rust_calls_swift_primitives();
 
@@ -14,6 +21,16 @@ mod ffi {
         fn rust_double_f32(arg: f32) -> f32;
         fn rust_double_f64(arg: f64) -> f64;
         fn rust_negate_bool(arg: bool) -> bool;
+        fn rust_double_u128(arg: u128) -> u128;
+        fn rust_double_i128(arg: i128) -> i128;
+        fn rust_uppercase_char(arg: char) -> char;
+
+        fn rust_reflect_option_u128(arg: Option<u128>) -> Option<u128>;
+        fn rust_reflect_option_char(arg: Option<char>) -> Option<char>;
+        fn rust_reflect_vec_i128(arg: Vec<i128>) -> Vec<i128>;
+        fn rust_reflect_vec_char(arg: Vec<char>) -> Vec<char>;
+        fn rust_reflect_tuple_u128_char(arg: (u128, char)) -> (u128, char);
+        fn rust_reflect_primitive_test_struct(arg: PrimitiveTestStruct) -> PrimitiveTestStruct;
     }
 
     extern "Swift" {
@@ -28,6 +45,9 @@ mod ffi {
         fn swift_double_f32(arg: f32) -> f32;
         fn swift_double_f64(arg: f64) -> f64;
         fn swift_negate_bool(arg: bool) -> bool;
+        fn swift_reflect_u128(arg: u128) -> u128;
+        fn swift_reflect_i128(arg: i128) -> i128;
+        fn swift_uppercase_char(arg: char) -> char;
     }
 }
 
@@ -44,6 +64,10 @@ fn test_rust_calls_swift_primitives() {
     assert_eq!(ffi::swift_double_f64(5.), 10.);
     assert_eq!(ffi::swift_negate_bool(true), false);
     assert_eq!(ffi::swift_negate_bool(false), true);
+    assert_eq!(ffi::swift_reflect_u128(u128::MAX), u128::MAX);
+    assert_eq!(ffi::swift_reflect_i128(i128::MIN), i128::MIN);
+    assert_eq!(ffi::swift_uppercase_char('a'), 'A');
+    assert_eq!(ffi::swift_uppercase_char('é'), 'É');
 }
 
 fn rust_double_u8(arg: u8) -> u8 {
@@ -89,3 +113,39 @@ fn rust_double_f64(arg: f64) -> f64 {
 fn rust_negate_bool(arg: bool) -> bool {
     !arg
 }
+
+fn rust_double_u128(arg: u128) -> u128 {
+    arg * 2
+}
+
+fn rust_double_i128(arg: i128) -> i128 {
+    arg * 2
+}
+
+fn rust_uppercase_char(arg: char) -> char {
+    arg.to_ascii_uppercase()
+}
+
+fn rust_reflect_option_u128(arg: Option<u128>) -> Option<u128> {
+    arg
+}
+
+fn rust_reflect_option_char(arg: Option<char>) -> Option<char> {
+    arg
+}
+
+fn rust_reflect_vec_i128(arg: Vec<i128>) -> Vec<i128> {
+    arg
+}
+
+fn rust_reflect_vec_char(arg: Vec<char>) -> Vec<char> {
+    arg
+}
+
+fn rust_reflect_tuple_u128_char(arg: (u128, char)) -> (u128, char) {
+    arg
+}
+
+fn rust_reflect_primitive_test_struct(arg: ffi::PrimitiveTestStruct) -> ffi::PrimitiveTestStruct {
+    arg
+}
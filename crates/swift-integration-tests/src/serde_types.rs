@@ -0,0 +1,64 @@
+use self::ffi::{SerdeConfig, SerdeShape};
+use std::collections::HashMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(serde)]
+    struct SerdeConfig {
+        name: String,
+        tags: Vec<String>,
+        limits: std::collections::HashMap<String, u32>,
+        shape: Option<SerdeShape>,
+        children: Vec<SerdeConfig>,
+    }
+
+    #[swift_bridge(serde)]
+    enum SerdeShape {
+        Empty,
+        Polygon(Vec<f64>),
+        Circle { radius: f64 },
+    }
+
+    extern "Rust" {
+        fn rust_make_serde_config() -> SerdeConfig;
+        fn rust_reflect_serde_config(config: SerdeConfig) -> SerdeConfig;
+        fn rust_reflect_serde_shape(shape: SerdeShape) -> SerdeShape;
+        fn rust_reflect_optional_serde_shape(shape: Option<SerdeShape>) -> Option<SerdeShape>;
+        fn rust_make_unencodable_serde_shape() -> SerdeShape;
+    }
+}
+
+fn rust_make_serde_config() -> SerdeConfig {
+    let child = SerdeConfig {
+        name: "child".to_string(),
+        tags: vec![],
+        limits: HashMap::new(),
+        shape: Some(SerdeShape::Polygon(vec![1., 2.])),
+        children: vec![],
+    };
+
+    SerdeConfig {
+        name: "parent".to_string(),
+        tags: vec!["a".to_string(), "b".to_string()],
+        limits: HashMap::from([("cpu".to_string(), 4)]),
+        shape: Some(SerdeShape::Circle { radius: 1.5 }),
+        children: vec![child],
+    }
+}
+
+fn rust_reflect_serde_config(config: SerdeConfig) -> SerdeConfig {
+    config
+}
+
+fn rust_reflect_serde_shape(shape: SerdeShape) -> SerdeShape {
+    shape
+}
+
+fn rust_reflect_optional_serde_shape(shape: Option<SerdeShape>) -> Option<SerdeShape> {
+    shape
+}
+
+/// serde_json encodes NaN as `null`, which Swift can not decode into a `Double`.
+fn rust_make_unencodable_serde_shape() -> SerdeShape {
+    SerdeShape::Polygon(vec![f64::NAN])
+}
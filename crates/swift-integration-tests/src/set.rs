@@ -0,0 +1,47 @@
+use std::collections::{BTreeSet, HashSet};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hash_set_string(arg: HashSet<String>) -> HashSet<String>;
+
+        fn rust_reflect_hash_set_u32(arg: HashSet<u32>) -> HashSet<u32>;
+
+        fn rust_reflect_btree_set_i64(arg: BTreeSet<i64>) -> BTreeSet<i64>;
+
+        fn rust_make_btree_set_of_strings() -> BTreeSet<String>;
+    }
+
+    extern "Rust" {
+        fn run_set_tests();
+    }
+
+    extern "Swift" {
+        fn swift_return_hash_set_u32() -> HashSet<u32>;
+        fn swift_arg_btree_set_string(set: BTreeSet<String>);
+    }
+}
+
+fn run_set_tests() {
+    let set = ffi::swift_return_hash_set_u32();
+    assert_eq!(set, HashSet::from([1, 2, 3]));
+
+    let set = BTreeSet::from(["a".to_string(), "b".to_string()]);
+    ffi::swift_arg_btree_set_string(set);
+}
+
+fn rust_reflect_hash_set_string(arg: HashSet<String>) -> HashSet<String> {
+    arg
+}
+
+fn rust_reflect_hash_set_u32(arg: HashSet<u32>) -> HashSet<u32> {
+    arg
+}
+
+fn rust_reflect_btree_set_i64(arg: BTreeSet<i64>) -> BTreeSet<i64> {
+    arg
+}
+
+fn rust_make_btree_set_of_strings() -> BTreeSet<String> {
+    BTreeSet::from(["c".to_string(), "a".to_string(), "b".to_string()])
+}
@@ -66,6 +66,27 @@ mod ffi {
             arg: EnumWithGenericOpaqueRust,
         ) -> EnumWithGenericOpaqueRust;
     }
+
+    enum EnumWithCollections {
+        Named {
+            bytes: Vec<u8>,
+            maybe_string: Option<String>,
+        },
+        Unnamed(Option<u32>, Vec<String>),
+        MaybeOpaque(Option<OpaqueRustForEnumTest>),
+    }
+
+    extern "Rust" {
+        fn reflect_enum_with_collections(arg: EnumWithCollections) -> EnumWithCollections;
+
+        fn test_rust_calls_swift_data_carrying_enums();
+    }
+
+    extern "Swift" {
+        fn swift_reflect_enum_with_collections(arg: EnumWithCollections) -> EnumWithCollections;
+        fn swift_reflect_enum_with_unnamed_data(arg: EnumWithUnnamedData) -> EnumWithUnnamedData;
+        fn swift_reflect_enum_with_named_data(arg: EnumWithNamedData) -> EnumWithNamedData;
+    }
 }
 
 fn reflect_enum_with_no_data(arg: ffi::EnumWithNoData) -> ffi::EnumWithNoData {
@@ -107,3 +128,53 @@ fn reflect_enum_with_generic_opaque_type(
 ) -> ffi::EnumWithGenericOpaqueRust {
     arg
 }
+
+fn reflect_enum_with_collections(arg: ffi::EnumWithCollections) -> ffi::EnumWithCollections {
+    arg
+}
+
+fn test_rust_calls_swift_data_carrying_enums() {
+    let named = ffi::EnumWithCollections::Named {
+        bytes: vec![1, 2, 3],
+        maybe_string: Some("hello".to_string()),
+    };
+    match ffi::swift_reflect_enum_with_collections(named) {
+        ffi::EnumWithCollections::Named {
+            bytes,
+            maybe_string,
+        } => {
+            assert_eq!(bytes, vec![1, 2, 3]);
+            assert_eq!(maybe_string.as_deref(), Some("hello"));
+        }
+        _ => panic!(),
+    };
+
+    let unnamed = ffi::EnumWithCollections::Unnamed(None, vec!["a".to_string(), "b".to_string()]);
+    match ffi::swift_reflect_enum_with_collections(unnamed) {
+        ffi::EnumWithCollections::Unnamed(maybe_u32, strings) => {
+            assert_eq!(maybe_u32, None);
+            assert_eq!(strings, vec!["a".to_string(), "b".to_string()]);
+        }
+        _ => panic!(),
+    };
+
+    let opaque = ffi::EnumWithCollections::MaybeOpaque(Some(OpaqueRustForEnumTest::new()));
+    match ffi::swift_reflect_enum_with_collections(opaque) {
+        ffi::EnumWithCollections::MaybeOpaque(val) => {
+            assert!(val == Some(OpaqueRustForEnumTest::new()));
+        }
+        _ => panic!(),
+    };
+
+    let unnamed = ffi::EnumWithUnnamedData::TwoFields("hi".to_string(), OpaqueRustForEnumTest);
+    match ffi::swift_reflect_enum_with_unnamed_data(unnamed) {
+        ffi::EnumWithUnnamedData::TwoFields(string, _opaque) => assert_eq!(string, "hi"),
+        _ => panic!(),
+    };
+
+    let named = ffi::EnumWithNamedData::OneField { data_i32: -5 };
+    match ffi::swift_reflect_enum_with_named_data(named) {
+        ffi::EnumWithNamedData::OneField { data_i32 } => assert_eq!(data_i32, -5),
+        _ => panic!(),
+    };
+}
@@ -22,6 +22,14 @@ mod ffi {
         field: String,
     }
 
+    #[swift_bridge(swift_repr = "struct")]
+    struct GenericPoint<T> {
+        x: T,
+        y: T,
+    }
+    struct GenericPoint<f32>;
+    struct GenericPoint<f64>;
+
     extern "Rust" {
         fn test_rust_calls_swift();
 
@@ -34,6 +42,8 @@ mod ffi {
         fn swift_calls_rust_tuple_struct(
             arg: StructReprStructTupleStruct,
         ) -> StructReprStructTupleStruct;
+
+        fn swift_calls_rust_generic_struct(arg: GenericPoint<f32>) -> GenericPoint<f64>;
     }
 
     extern "Swift" {
@@ -46,12 +56,15 @@ mod ffi {
         fn rust_calls_swift_struct_repr_struct_one_string_field(
             arg: StructReprStructWithOneStringField,
         ) -> StructReprStructWithOneStringField;
+
+        fn rust_calls_swift_generic_struct(arg: GenericPoint<f64>) -> GenericPoint<f32>;
     }
 }
 
 fn test_rust_calls_swift() {
     self::tests::test_rust_calls_swift_struct_with_no_fields();
     self::tests::test_rust_calls_swift_struct_repr_struct_one_primitive_field();
+    self::tests::test_rust_calls_swift_generic_struct();
 }
 
 fn swift_calls_rust_struct_with_no_fields(arg: ffi::StructWithNoFields) -> ffi::StructWithNoFields {
@@ -70,6 +83,13 @@ fn swift_calls_rust_tuple_struct(
     arg
 }
 
+fn swift_calls_rust_generic_struct(arg: ffi::GenericPoint<f32>) -> ffi::GenericPoint<f64> {
+    ffi::GenericPoint {
+        x: arg.x as f64,
+        y: arg.y as f64,
+    }
+}
+
 #[deny(unused)]
 mod tests {
     use super::ffi;
@@ -86,4 +106,13 @@ mod tests {
 
         assert_eq!(val.named_field, 10);
     }
+
+    pub(super) fn test_rust_calls_swift_generic_struct() {
+        let arg = ffi::GenericPoint { x: 1.5f64, y: 2.5 };
+
+        let val = ffi::rust_calls_swift_generic_struct(arg);
+
+        assert_eq!(val.x, 1.5f32);
+        assert_eq!(val.y, 2.5f32);
+    }
 }
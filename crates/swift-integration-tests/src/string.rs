@@ -4,11 +4,16 @@ mod ffi {
         fn run_string_tests();
 
         fn create_string(str: &str) -> String;
+        fn trim_str(arg: &str) -> &str;
     }
 
     extern "Swift" {
         fn create_swift_string() -> String;
         fn reflect_rust_string(arg: String) -> String;
+        fn swift_reflect_str(arg: &str) -> &str;
+        fn swift_trim_str(arg: &str) -> &str;
+        fn swift_make_str() -> &str;
+        fn swift_make_option_str(some: bool) -> Option<&str>;
     }
 }
 
@@ -21,8 +26,23 @@ fn run_string_tests() {
     let string = ffi::reflect_rust_string(foo.to_string());
     assert_eq!(string.len(), 3);
     assert_eq!(&string, foo);
+
+    assert_eq!(ffi::swift_reflect_str("hello world"), "hello world");
+
+    // Swift returns a `&str` that points into the middle of the `&str` argument.
+    assert_eq!(ffi::swift_trim_str("  padded  "), "padded");
+
+    // Swift returns a `&str` that points into a buffer that Swift owns.
+    let swift_str = ffi::swift_make_str();
+    assert_eq!(&*swift_str, "made in swift");
+    assert_eq!(ffi::swift_make_option_str(true).unwrap(), "made in swift");
+    assert!(ffi::swift_make_option_str(false).is_none());
 }
 
 fn create_string(str: &str) -> String {
     str.to_string()
 }
+
+fn trim_str(arg: &str) -> &str {
+    arg.trim()
+}
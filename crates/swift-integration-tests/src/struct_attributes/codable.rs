@@ -0,0 +1,11 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Codable)]
+    struct CodableStruct {
+        id: u32,
+        display_name: String,
+    }
+
+    #[swift_bridge(swift_repr = "struct", Codable)]
+    struct CodableTupleStruct(u8, u16);
+}
@@ -25,4 +25,11 @@ mod ffi {
     struct StructDeriveClone3 {
         field: String,
     }
+
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+    struct StructDeriveComparison {
+        major: u8,
+        name: String,
+    }
 }
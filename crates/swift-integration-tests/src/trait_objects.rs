@@ -0,0 +1,151 @@
+use std::cell::RefCell;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_renders_with_swift_renderer(renderer: Box<dyn SwiftRenderer>, width: u32)
+            -> String;
+        fn rust_scales_swift_renderer_twice(renderer: Box<dyn SwiftRenderer>, scale: f32) -> f32;
+        fn rust_renders_with_optional_swift_renderer(
+            renderer: Option<Box<dyn SwiftRenderer>>,
+        ) -> Option<String>;
+
+        fn rust_stores_swift_renderer(renderer: Box<dyn SwiftRenderer>);
+        fn rust_drops_stored_swift_renderer();
+
+        fn test_rust_calls_swift_made_renderer();
+    }
+
+    extern "Rust" {
+        #[swift_bridge(protocol)]
+        type Counter;
+
+        fn count(&self) -> u32;
+        fn increment(&mut self);
+
+        fn make_counter(start: u32, step: u32) -> Box<dyn Counter>;
+        fn rust_reads_counter(counter: &dyn Counter) -> u32;
+        fn rust_increments_counter(counter: &mut dyn Counter);
+        fn rust_consumes_counter(counter: Box<dyn Counter>) -> u32;
+        fn rust_consumes_optional_counter(counter: Option<Box<dyn Counter>>) -> Option<u32>;
+
+        fn test_swift_increments_rust_counter();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(protocol)]
+        type SwiftRenderer;
+
+        fn render(&self, width: u32) -> String;
+        fn scale(&self) -> f32;
+        fn set_scale(&mut self, scale: f32);
+
+        fn swift_makes_renderer(scale: f32) -> Box<dyn SwiftRenderer>;
+
+        fn swift_increments_rust_counter(counter: Box<dyn Counter>) -> Box<dyn Counter>;
+    }
+}
+
+use ffi::SwiftRenderer;
+
+thread_local! {
+    static STORED_RENDERER: RefCell<Option<Box<dyn SwiftRenderer>>> = RefCell::new(None);
+}
+
+fn rust_renders_with_swift_renderer(renderer: Box<dyn SwiftRenderer>, width: u32) -> String {
+    renderer.render(width)
+}
+
+fn rust_scales_swift_renderer_twice(mut renderer: Box<dyn SwiftRenderer>, scale: f32) -> f32 {
+    let current = renderer.scale();
+    renderer.set_scale(current * scale);
+
+    let current = renderer.scale();
+    renderer.set_scale(current * scale);
+
+    renderer.scale()
+}
+
+fn rust_renders_with_optional_swift_renderer(
+    renderer: Option<Box<dyn SwiftRenderer>>,
+) -> Option<String> {
+    renderer.map(|renderer| renderer.render(10))
+}
+
+fn rust_stores_swift_renderer(renderer: Box<dyn SwiftRenderer>) {
+    STORED_RENDERER.with(|stored| *stored.borrow_mut() = Some(renderer));
+}
+
+fn rust_drops_stored_swift_renderer() {
+    STORED_RENDERER.with(|stored| *stored.borrow_mut() = None);
+}
+
+fn test_rust_calls_swift_made_renderer() {
+    let mut renderer = ffi::swift_makes_renderer(2.);
+    assert_eq!(renderer.scale(), 2.);
+    assert_eq!(renderer.render(5), "width: 10");
+
+    renderer.set_scale(3.);
+    assert_eq!(renderer.render(5), "width: 15");
+}
+
+pub trait Counter {
+    fn count(&self) -> u32;
+    fn increment(&mut self);
+}
+
+struct OneStepCounter(u32);
+
+impl Counter for OneStepCounter {
+    fn count(&self) -> u32 {
+        self.0
+    }
+
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+}
+
+struct SteppedCounter {
+    count: u32,
+    step: u32,
+}
+
+impl Counter for SteppedCounter {
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn increment(&mut self) {
+        self.count += self.step;
+    }
+}
+
+fn make_counter(start: u32, step: u32) -> Box<dyn Counter> {
+    if step == 1 {
+        Box::new(OneStepCounter(start))
+    } else {
+        Box::new(SteppedCounter { count: start, step })
+    }
+}
+
+fn rust_reads_counter(counter: &dyn Counter) -> u32 {
+    counter.count()
+}
+
+fn rust_increments_counter(counter: &mut dyn Counter) {
+    counter.increment();
+}
+
+fn rust_consumes_counter(counter: Box<dyn Counter>) -> u32 {
+    counter.count()
+}
+
+fn rust_consumes_optional_counter(counter: Option<Box<dyn Counter>>) -> Option<u32> {
+    counter.map(|counter| counter.count())
+}
+
+fn test_swift_increments_rust_counter() {
+    let counter = ffi::swift_increments_rust_counter(make_counter(10, 5));
+    assert_eq!(counter.count(), 15);
+}
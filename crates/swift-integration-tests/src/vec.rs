@@ -5,6 +5,13 @@ mod ffi {
         VariantB,
     }
 
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Clone)]
+    struct TransparentStructInsideVecT {
+        string: String,
+        integer: i64,
+    }
+
     extern "Rust" {
         type ARustTypeInsideVecT;
 
@@ -20,12 +27,49 @@ mod ffi {
         ) -> Vec<ARustTypeInsideVecT>;
     }
 
+    extern "Rust" {
+        #[swift_bridge(Copy(4))]
+        type ACopyTypeInsideVecT;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> ACopyTypeInsideVecT;
+
+        fn value(&self) -> u32;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_vec_opaque_copy_type(
+            arg: Vec<ACopyTypeInsideVecT>,
+        ) -> Vec<ACopyTypeInsideVecT>;
+    }
+
+    extern "Swift" {
+        type ASwiftTypeInsideVecT;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_vec_opaque_swift_type(
+            arg: Vec<ASwiftTypeInsideVecT>,
+        ) -> Vec<ASwiftTypeInsideVecT>;
+    }
+
     extern "Rust" {
         fn rust_reflect_vec_transparent_enum(
             arg: Vec<TransparentEnumInsideVecT>,
         ) -> Vec<TransparentEnumInsideVecT>;
     }
 
+    extern "Rust" {
+        fn rust_reflect_vec_transparent_struct(
+            arg: Vec<TransparentStructInsideVecT>,
+        ) -> Vec<TransparentStructInsideVecT>;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_vec_string(arg: Vec<String>) -> Vec<String>;
+        fn rust_reflect_vec_str(arg: Vec<&str>) -> Vec<&str>;
+    }
+
     extern "Rust" {
         fn run_vec_tests();
     }
@@ -69,8 +113,47 @@ fn rust_reflect_vec_opaque_rust_type(arg: Vec<ARustTypeInsideVecT>) -> Vec<ARust
     arg
 }
 
+#[derive(Copy, Clone)]
+pub struct ACopyTypeInsideVecT {
+    value: u32,
+}
+
+impl ACopyTypeInsideVecT {
+    fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+fn rust_reflect_vec_opaque_copy_type(arg: Vec<ACopyTypeInsideVecT>) -> Vec<ACopyTypeInsideVecT> {
+    arg
+}
+
+fn rust_reflect_vec_opaque_swift_type(
+    arg: Vec<ffi::ASwiftTypeInsideVecT>,
+) -> Vec<ffi::ASwiftTypeInsideVecT> {
+    arg
+}
+
 fn rust_reflect_vec_transparent_enum(
     arg: Vec<ffi::TransparentEnumInsideVecT>,
 ) -> Vec<ffi::TransparentEnumInsideVecT> {
     arg
 }
+
+fn rust_reflect_vec_transparent_struct(
+    arg: Vec<ffi::TransparentStructInsideVecT>,
+) -> Vec<ffi::TransparentStructInsideVecT> {
+    arg
+}
+
+fn rust_reflect_vec_string(arg: Vec<String>) -> Vec<String> {
+    arg
+}
+
+fn rust_reflect_vec_str(arg: Vec<&str>) -> Vec<&str> {
+    arg
+}
@@ -160,9 +160,18 @@ fn generate_code(bridge_module_source: &str) -> Result<GeneratedCode, String> {
     };
     let generated = module.generate_swift_code_and_c_header(config);
 
+    let mut swift = generated.swift;
+    let mut c_header = generated.c_header;
+    for instantiation in generated.generic_instantiations {
+        swift += "\n";
+        swift += &instantiation.swift;
+        c_header += "\n";
+        c_header += &instantiation.c_header;
+    }
+
     Ok(GeneratedCode {
         rust,
-        swift: generated.swift,
-        c_header: generated.c_header,
+        swift,
+        c_header,
     })
 }
@@ -1,17 +1,64 @@
 #![allow(non_snake_case)]
 
+use std::cell::{RefCell, RefMut};
+
 #[export_name = "__swift_bridge__$call_boxed_fn_once_no_args_no_return"]
-pub extern "C" fn __swift_bridge__call_boxed_fn_once_no_args_no_return(
-    boxed_fn: *mut Box<dyn FnOnce() -> ()>,
+pub unsafe extern "C" fn __swift_bridge__call_boxed_fn_once_no_args_no_return(
+    boxed_fn: *mut Box<dyn FnOnce()>,
 ) {
-    unsafe { Box::from_raw(boxed_fn)() };
+    Box::from_raw(boxed_fn)();
 }
 
 #[export_name = "__swift_bridge__$free_boxed_fn_once_no_args_no_return"]
-pub extern "C" fn __swift_bridge__free_boxed_fn_once_no_args_no_return(
-    boxed_fn: *mut Box<dyn FnOnce() -> ()>,
+pub unsafe extern "C" fn __swift_bridge__free_boxed_fn_once_no_args_no_return(
+    boxed_fn: *mut Box<dyn FnOnce()>,
 ) {
-    unsafe {
-        let _ = Box::from_raw(boxed_fn);
+    let _ = Box::from_raw(boxed_fn);
+}
+
+/// Borrow a `Box<dyn FnMut>` that Swift is calling.
+///
+/// Swift could call the function again while it is running, such as when the function calls a
+/// Swift function that calls it, so we borrow it through a `RefCell` instead of handing out two
+/// mutable references to it.
+///
+/// We are called from an `extern "C"` function that can not unwind, so a second call aborts the
+/// process instead of panicking.
+#[doc(hidden)]
+pub fn borrow_boxed_fn_mut<F: ?Sized>(boxed_fn: &RefCell<Box<F>>) -> RefMut<'_, Box<F>> {
+    match boxed_fn.try_borrow_mut() {
+        Ok(boxed_fn) => boxed_fn,
+        Err(_) => {
+            eprintln!("Box<dyn FnMut> was called while it was already running.");
+            std::process::abort()
+        }
     }
 }
+
+#[export_name = "__swift_bridge__$call_boxed_fn_mut_no_args_no_return"]
+pub unsafe extern "C" fn __swift_bridge__call_boxed_fn_mut_no_args_no_return(
+    boxed_fn: *mut RefCell<Box<dyn FnMut()>>,
+) {
+    (*borrow_boxed_fn_mut(&*boxed_fn))();
+}
+
+#[export_name = "__swift_bridge__$free_boxed_fn_mut_no_args_no_return"]
+pub unsafe extern "C" fn __swift_bridge__free_boxed_fn_mut_no_args_no_return(
+    boxed_fn: *mut RefCell<Box<dyn FnMut()>>,
+) {
+    let _ = Box::from_raw(boxed_fn);
+}
+
+#[export_name = "__swift_bridge__$call_boxed_fn_no_args_no_return"]
+pub unsafe extern "C" fn __swift_bridge__call_boxed_fn_no_args_no_return(
+    boxed_fn: *mut Box<dyn Fn()>,
+) {
+    (*boxed_fn)();
+}
+
+#[export_name = "__swift_bridge__$free_boxed_fn_no_args_no_return"]
+pub unsafe extern "C" fn __swift_bridge__free_boxed_fn_no_args_no_return(
+    boxed_fn: *mut Box<dyn Fn()>,
+) {
+    let _ = Box::from_raw(boxed_fn);
+}
@@ -6,7 +6,10 @@ pub use swift_bridge_macro::bridge;
 
 mod std_bridge;
 
-pub use self::std_bridge::{option, result, string};
+pub use self::std_bridge::{int128, option, result, string};
+
+#[doc(hidden)]
+pub use self::std_bridge::rust_hash_map;
 
 #[doc(hidden)]
 #[cfg(feature = "async")]
@@ -18,6 +21,10 @@ pub mod boxed_fn_support;
 #[doc(hidden)]
 pub mod copy_support;
 
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+pub mod serde_support;
+
 #[doc(hidden)]
 #[repr(C)]
 pub struct FfiSlice<T> {
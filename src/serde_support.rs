@@ -0,0 +1,56 @@
+//! Support for types that are bridged using `#[swift_bridge(serde)]`.
+//!
+//! A value is passed over FFI as a pointer to the boxed Rust value.
+//! Swift converts between the boxed value and its own `Codable` type by passing JSON through the
+//! `$_to_json` and `$_from_json` functions that are generated for each type, which return any
+//! serde error to Swift so that Swift can throw it.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/codable.swift
+
+use crate::result::ResultPtrAndPtr;
+use crate::string::RustString;
+
+pub use serde;
+
+/// Serialize a boxed value into a JSON encoded `RustString` that Swift can decode.
+///
+/// # Safety
+///
+/// The pointer must have come from `Box::into_raw`, and it must not be used again.
+pub unsafe fn to_json<T: serde::Serialize>(value: *mut T) -> ResultPtrAndPtr {
+    let value = unsafe { Box::from_raw(value) };
+
+    match serde_json::to_string(&value) {
+        Ok(json) => ResultPtrAndPtr {
+            is_ok: true,
+            ok_or_err: Box::into_raw(Box::new(RustString(json))) as *mut std::ffi::c_void,
+        },
+        Err(err) => serde_error(err),
+    }
+}
+
+/// Deserialize a JSON encoded `RustString` that was encoded by Swift into a boxed value.
+///
+/// # Safety
+///
+/// The pointer must have come from `Box::into_raw`, and it must not be used again.
+pub unsafe fn from_json<T: serde::de::DeserializeOwned>(json: *mut RustString) -> ResultPtrAndPtr {
+    let json = unsafe { Box::from_raw(json) };
+
+    match serde_json::from_str::<T>(&json.0) {
+        Ok(value) => ResultPtrAndPtr {
+            is_ok: true,
+            ok_or_err: Box::into_raw(Box::new(value)) as *mut std::ffi::c_void,
+        },
+        Err(err) => serde_error(err),
+    }
+}
+
+/// Pass the error's message to Swift as a `RustString`.
+fn serde_error(err: serde_json::Error) -> ResultPtrAndPtr {
+    ResultPtrAndPtr {
+        is_ok: false,
+        ok_or_err: Box::into_raw(Box::new(RustString(err.to_string()))) as *mut std::ffi::c_void,
+    }
+}
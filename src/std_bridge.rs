@@ -2,7 +2,10 @@
 //! crates/swift-bridge-build/src/generate_core/*
 #![allow(missing_docs)]
 
+pub mod int128;
 pub mod option;
 pub mod result;
+pub mod rust_hash_map;
+mod rust_set;
 mod rust_vec;
 pub mod string;
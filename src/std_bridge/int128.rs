@@ -0,0 +1,47 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/int128.swift
+
+/// A `u128` split into two `u64`s, since C and Swift don't have a portable 128 bit integer type.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RustU128 {
+    pub low: u64,
+    pub high: u64,
+}
+
+/// An `i128` split into two 64 bit integers, since C and Swift don't have a portable 128 bit
+/// integer type.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RustI128 {
+    pub low: u64,
+    pub high: i64,
+}
+
+impl RustU128 {
+    pub fn from_u128(val: u128) -> Self {
+        RustU128 {
+            low: val as u64,
+            high: (val >> 64) as u64,
+        }
+    }
+
+    pub fn to_u128(self) -> u128 {
+        ((self.high as u128) << 64) | self.low as u128
+    }
+}
+
+impl RustI128 {
+    pub fn from_i128(val: i128) -> Self {
+        RustI128 {
+            low: val as u64,
+            high: (val >> 64) as i64,
+        }
+    }
+
+    pub fn to_i128(self) -> i128 {
+        ((self.high as i128) << 64) | self.low as i128
+    }
+}
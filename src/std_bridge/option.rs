@@ -68,6 +68,20 @@ pub struct OptionIsize {
     pub is_some: bool,
 }
 
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionU128 {
+    pub val: crate::int128::RustU128,
+    pub is_some: bool,
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionI128 {
+    pub val: crate::int128::RustI128,
+    pub is_some: bool,
+}
+
 #[repr(C)]
 #[doc(hidden)]
 pub struct OptionF32 {
@@ -88,3 +102,11 @@ pub struct OptionBool {
     pub val: bool,
     pub is_some: bool,
 }
+
+/// A `char` is passed over FFI as a `u32`.
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionChar {
+    pub val: u32,
+    pub is_some: bool,
+}
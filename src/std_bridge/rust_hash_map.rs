@@ -0,0 +1,66 @@
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/rust_hash_map.swift
+//!
+//! The `extern "C"` functions for each `HashMap<K, V>` are generated by the `bridge` macro, since
+//! they depend on the key and value types that are being bridged.
+
+use std::collections::HashMap;
+
+/// Used by Swift's `RustHashMapIterator` to walk over the entries of a `HashMap<K, V>`.
+///
+/// The entries are cloned up front so that mutating the map on the Swift side while an iterator
+/// is alive can't invalidate the iterator.
+#[doc(hidden)]
+pub struct HashMapIter<K, V> {
+    entries: std::vec::IntoIter<(K, V)>,
+    key: Option<K>,
+    value: Option<V>,
+}
+
+impl<K: Clone, V: Clone> HashMapIter<K, V> {
+    pub fn new(map: &HashMap<K, V>) -> Self {
+        let entries: Vec<(K, V)> = map
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        HashMapIter {
+            entries: entries.into_iter(),
+            key: None,
+            value: None,
+        }
+    }
+}
+
+impl<K, V> HashMapIter<K, V> {
+    /// Move to the next entry, returning false if there are no entries left.
+    pub fn advance(&mut self) -> bool {
+        if let Some((key, value)) = self.entries.next() {
+            self.key = Some(key);
+            self.value = Some(value);
+            true
+        } else {
+            self.key = None;
+            self.value = None;
+            false
+        }
+    }
+
+    /// Take the key of the current entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `advance` has not returned true since the key was last taken.
+    pub fn take_key(&mut self) -> K {
+        self.key.take().expect("No current HashMap entry")
+    }
+
+    /// Take the value of the current entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `advance` has not returned true since the value was last taken.
+    pub fn take_value(&mut self) -> V {
+        self.value.take().expect("No current HashMap entry")
+    }
+}
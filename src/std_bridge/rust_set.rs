@@ -0,0 +1,235 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/rust_set.swift
+
+use crate::string::{RustStr, RustString};
+use macro_::{set_externs, set_iter_externs, string_set_externs};
+use std::collections::{BTreeSet, HashSet};
+
+set_externs!(HashSet, u8, OptionU8, 123);
+set_externs!(HashSet, u16, OptionU16, 123);
+set_externs!(HashSet, u32, OptionU32, 123);
+set_externs!(HashSet, u64, OptionU64, 123);
+set_externs!(HashSet, usize, OptionUsize, 123);
+
+set_externs!(HashSet, i8, OptionI8, 123);
+set_externs!(HashSet, i16, OptionI16, 123);
+set_externs!(HashSet, i32, OptionI32, 123);
+set_externs!(HashSet, i64, OptionI64, 123);
+set_externs!(HashSet, isize, OptionIsize, 123);
+
+set_externs!(HashSet, bool, OptionBool, false);
+
+set_externs!(BTreeSet, u8, OptionU8, 123);
+set_externs!(BTreeSet, u16, OptionU16, 123);
+set_externs!(BTreeSet, u32, OptionU32, 123);
+set_externs!(BTreeSet, u64, OptionU64, 123);
+set_externs!(BTreeSet, usize, OptionUsize, 123);
+
+set_externs!(BTreeSet, i8, OptionI8, 123);
+set_externs!(BTreeSet, i16, OptionI16, 123);
+set_externs!(BTreeSet, i32, OptionI32, 123);
+set_externs!(BTreeSet, i64, OptionI64, 123);
+set_externs!(BTreeSet, isize, OptionIsize, 123);
+
+set_externs!(BTreeSet, bool, OptionBool, false);
+
+set_iter_externs!(u8, OptionU8, 123);
+set_iter_externs!(u16, OptionU16, 123);
+set_iter_externs!(u32, OptionU32, 123);
+set_iter_externs!(u64, OptionU64, 123);
+set_iter_externs!(usize, OptionUsize, 123);
+
+set_iter_externs!(i8, OptionI8, 123);
+set_iter_externs!(i16, OptionI16, 123);
+set_iter_externs!(i32, OptionI32, 123);
+set_iter_externs!(i64, OptionI64, 123);
+set_iter_externs!(isize, OptionIsize, 123);
+
+set_iter_externs!(bool, OptionBool, false);
+
+string_set_externs!(HashSet);
+string_set_externs!(BTreeSet);
+
+#[export_name = "__swift_bridge__$SetIter_RustString$next"]
+#[doc(hidden)]
+pub extern "C" fn _set_iter_rust_string_next(
+    iter: *mut std::vec::IntoIter<String>,
+) -> *mut RustString {
+    let iter = unsafe { &mut *iter };
+    if let Some(val) = iter.next() {
+        RustString(val).box_into_raw()
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+#[export_name = "__swift_bridge__$SetIter_RustString$_free"]
+#[doc(hidden)]
+pub extern "C" fn _set_iter_rust_string_drop(iter: *mut std::vec::IntoIter<String>) {
+    let iter = unsafe { Box::from_raw(iter) };
+    drop(iter)
+}
+
+mod macro_ {
+    // Strings are passed to Swift as a `RustString`, and lookups borrow the Swift string as a
+    // `RustStr`, so they don't fit the `set_externs!` macro.
+    macro_rules! string_set_externs {
+        ($set:ident) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_RustString$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut $set<String> {
+                    Box::into_raw(Box::new($set::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_RustString$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(set: *mut $set<String>) {
+                    let set = unsafe { Box::from_raw(set) };
+                    drop(set)
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_RustString$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(set: *mut $set<String>) -> usize {
+                    let set = unsafe { &*set };
+                    set.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_RustString$contains")]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(set: *mut $set<String>, val: RustStr) -> bool {
+                    let set = unsafe { &*set };
+                    set.contains(val.to_str())
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_RustString$insert")]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(set: *mut $set<String>, val: *mut RustString) -> bool {
+                    let set = unsafe { &mut *set };
+                    let val = unsafe { Box::from_raw(val) };
+                    set.insert(val.0)
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_RustString$remove")]
+                #[doc(hidden)]
+                pub extern "C" fn _remove(set: *mut $set<String>, val: RustStr) -> *mut RustString {
+                    let set = unsafe { &mut *set };
+                    if let Some(val) = set.take(val.to_str()) {
+                        RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_RustString$iter")]
+                #[doc(hidden)]
+                pub extern "C" fn _iter(set: *mut $set<String>) -> *mut std::vec::IntoIter<String> {
+                    let set = unsafe { &*set };
+                    let vals: Vec<String> = set.iter().cloned().collect();
+                    Box::into_raw(Box::new(vals.into_iter()))
+                }
+            };
+        };
+    }
+
+    macro_rules! set_externs {
+        ($set:ident, $ty:ty, $option_ty:ident, $unused_none:expr) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut $set<$ty> {
+                    Box::into_raw(Box::new($set::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(set: *mut $set<$ty>) {
+                    let set = unsafe { Box::from_raw(set) };
+                    drop(set)
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(set: *mut $set<$ty>) -> usize {
+                    let set = unsafe { &*set };
+                    set.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_", stringify!($ty), "$contains")]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(set: *mut $set<$ty>, val: $ty) -> bool {
+                    let set = unsafe { &*set };
+                    set.contains(&val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_", stringify!($ty), "$insert")]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(set: *mut $set<$ty>, val: $ty) -> bool {
+                    let set = unsafe { &mut *set };
+                    set.insert(val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_", stringify!($ty), "$remove")]
+                #[doc(hidden)]
+                pub extern "C" fn _remove(
+                    set: *mut $set<$ty>,
+                    val: $ty,
+                ) -> crate::option::$option_ty {
+                    let set = unsafe { &mut *set };
+                    if let Some(val) = set.take(&val) {
+                        crate::option::$option_ty { val, is_some: true }
+                    } else {
+                        crate::option::$option_ty {
+                            val: $unused_none,
+                            is_some: false,
+                        }
+                    }
+                }
+
+                // The values are copied out up front so that mutating the set on the Swift side
+                // while an iterator is alive can't invalidate the iterator.
+                #[export_name = concat!("__swift_bridge__$", stringify!($set), "_", stringify!($ty), "$iter")]
+                #[doc(hidden)]
+                pub extern "C" fn _iter(set: *mut $set<$ty>) -> *mut std::vec::IntoIter<$ty> {
+                    let set = unsafe { &*set };
+                    let vals: Vec<$ty> = set.iter().copied().collect();
+                    Box::into_raw(Box::new(vals.into_iter()))
+                }
+            };
+        };
+    }
+
+    macro_rules! set_iter_externs {
+        ($ty:ty, $option_ty:ident, $unused_none:expr) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$SetIter_", stringify!($ty), "$next")]
+                #[doc(hidden)]
+                pub extern "C" fn _next(
+                    iter: *mut std::vec::IntoIter<$ty>,
+                ) -> crate::option::$option_ty {
+                    let iter = unsafe { &mut *iter };
+                    if let Some(val) = iter.next() {
+                        crate::option::$option_ty { val, is_some: true }
+                    } else {
+                        crate::option::$option_ty {
+                            val: $unused_none,
+                            is_some: false,
+                        }
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$SetIter_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(iter: *mut std::vec::IntoIter<$ty>) {
+                    let iter = unsafe { Box::from_raw(iter) };
+                    drop(iter)
+                }
+            };
+        };
+    }
+
+    pub(super) use set_externs;
+    pub(super) use set_iter_externs;
+    pub(super) use string_set_externs;
+}
@@ -1,4 +1,5 @@
-use macro_::vec_externs;
+use crate::int128::{RustI128, RustU128};
+use macro_::{vec_externs, vec_externs_with_ffi_repr};
 
 vec_externs!(u8, OptionU8, 123);
 vec_externs!(u16, OptionU16, 123);
@@ -17,6 +18,100 @@ vec_externs!(f64, OptionF64, 0.123);
 
 vec_externs!(bool, OptionBool, false);
 
+vec_externs_with_ffi_repr!(
+    u128,
+    RustU128,
+    OptionU128,
+    RustU128 { low: 123, high: 0 },
+    RustU128::from_u128,
+    RustU128::to_u128
+);
+vec_externs_with_ffi_repr!(
+    i128,
+    RustI128,
+    OptionI128,
+    RustI128 { low: 123, high: 0 },
+    RustI128::from_i128,
+    RustI128::to_i128
+);
+vec_externs_with_ffi_repr!(char, u32, OptionChar, 123, u32::from, char_from_u32);
+
+fn char_from_u32(val: u32) -> char {
+    char::from_u32(val).unwrap()
+}
+
+// `Vec<String>` and `Vec<&str>` are both passed to Swift as a `Vec<String>`. Swift sees them as a
+// `RustVec<RustString>`, and borrows the elements as `RustStr`s.
+const _: () = {
+    use crate::string::{RustStr, RustString};
+
+    #[export_name = "__swift_bridge__$Vec_String$new"]
+    #[doc(hidden)]
+    pub extern "C" fn _new() -> *mut Vec<String> {
+        Box::into_raw(Box::new(Vec::new()))
+    }
+
+    #[export_name = "__swift_bridge__$Vec_String$drop"]
+    #[doc(hidden)]
+    pub extern "C" fn _drop(vec: *mut Vec<String>) {
+        let vec = unsafe { Box::from_raw(vec) };
+        drop(vec)
+    }
+
+    #[export_name = "__swift_bridge__$Vec_String$len"]
+    #[doc(hidden)]
+    pub extern "C" fn _len(vec: *mut Vec<String>) -> usize {
+        let vec = unsafe { &*vec };
+        vec.len()
+    }
+
+    #[export_name = "__swift_bridge__$Vec_String$push"]
+    #[doc(hidden)]
+    pub extern "C" fn _push(vec: *mut Vec<String>, val: *mut RustString) {
+        let vec = unsafe { &mut *vec };
+        let val = unsafe { Box::from_raw(val) };
+        vec.push(val.0);
+    }
+
+    #[export_name = "__swift_bridge__$Vec_String$pop"]
+    #[doc(hidden)]
+    pub extern "C" fn _pop(vec: *mut Vec<String>) -> *mut RustString {
+        let vec = unsafe { &mut *vec };
+        if let Some(val) = vec.pop() {
+            RustString(val).box_into_raw()
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    // A `RustStr` with a null `start` pointer represents `None`.
+    #[export_name = "__swift_bridge__$Vec_String$get"]
+    #[doc(hidden)]
+    pub extern "C" fn _get(vec: *mut Vec<String>, index: usize) -> RustStr {
+        let vec = unsafe { &*vec };
+        if let Some(val) = vec.get(index) {
+            RustStr::from_str(val)
+        } else {
+            RustStr {
+                start: std::ptr::null(),
+                len: 0,
+            }
+        }
+    }
+
+    // `RustString` is `#[repr(transparent)]`, so Swift can use the pointer as a `RustStringRefMut`.
+    #[export_name = "__swift_bridge__$Vec_String$get_mut"]
+    #[doc(hidden)]
+    pub extern "C" fn _get_mut(vec: *mut Vec<String>, index: usize) -> *mut RustString {
+        let vec = unsafe { &mut *vec };
+        if let Some(val) = vec.get_mut(index) {
+            val as *mut String as *mut RustString
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+};
+
 mod macro_ {
     macro_rules! vec_externs {
         ($ty:ty, $option_ty:ident, $unused_none:expr) => {
@@ -116,5 +211,88 @@ mod macro_ {
         };
     }
 
+    // For primitives such as `u128` and `char` that are passed over FFI using a different type.
+    macro_rules! vec_externs_with_ffi_repr {
+        ($ty:ty, $ffi_ty:ty, $option_ty:ident, $unused_none:expr, $to_ffi:expr, $from_ffi:expr) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut Vec<$ty> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(vec: *mut Vec<$ty>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(vec: *mut Vec<$ty>) -> usize {
+                    let vec = unsafe { &*vec };
+                    vec.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$push")]
+                #[doc(hidden)]
+                pub extern "C" fn _push(vec: *mut Vec<$ty>, val: $ffi_ty) {
+                    let vec = unsafe { &mut *vec };
+                    vec.push(($from_ffi)(val));
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$pop")]
+                #[doc(hidden)]
+                pub extern "C" fn _pop(vec: *mut Vec<$ty>) -> crate::option::$option_ty {
+                    let vec = unsafe { &mut *vec };
+                    to_ffi_option(vec.pop())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$get")]
+                #[doc(hidden)]
+                pub extern "C" fn _get(
+                    vec: *mut Vec<$ty>,
+                    index: usize,
+                ) -> crate::option::$option_ty {
+                    let vec = unsafe { &*vec };
+                    to_ffi_option(vec.get(index).copied())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$get_mut")]
+                #[doc(hidden)]
+                pub extern "C" fn _get_mut(
+                    vec: *mut Vec<$ty>,
+                    index: usize,
+                ) -> crate::option::$option_ty {
+                    let vec = unsafe { &mut *vec };
+                    to_ffi_option(vec.get(index).copied())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$as_ptr")]
+                #[doc(hidden)]
+                pub extern "C" fn _as_ptr(vec: *mut Vec<$ty>) -> *const $ty {
+                    let vec = unsafe { &*vec };
+                    vec.as_ptr()
+                }
+
+                fn to_ffi_option(val: Option<$ty>) -> crate::option::$option_ty {
+                    if let Some(val) = val {
+                        crate::option::$option_ty {
+                            val: ($to_ffi)(val),
+                            is_some: true,
+                        }
+                    } else {
+                        crate::option::$option_ty {
+                            val: $unused_none,
+                            is_some: false,
+                        }
+                    }
+                }
+            };
+        };
+    }
+
     pub(super) use vec_externs;
+    pub(super) use vec_externs_with_ffi_repr;
 }
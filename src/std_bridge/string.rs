@@ -22,6 +22,7 @@ mod ffi {
 }
 
 #[doc(hidden)]
+#[repr(transparent)]
 pub struct RustString(pub String);
 
 #[doc(hidden)]
@@ -97,3 +98,93 @@ impl PartialEq for RustStr {
 pub extern "C" fn __swift_bridge__RustStr_partial_eq(lhs: RustStr, rhs: RustStr) -> bool {
     lhs == rhs
 }
+
+/// A `&str` that points into a buffer that Swift owns.
+///
+/// An `extern "Swift"` function such as `fn make_string() -> &str` has no arguments for the
+/// returned `&str` to borrow from, so Rust receives a `SwiftStr` instead.
+/// Swift keeps the buffer alive until the `SwiftStr` is dropped.
+pub struct SwiftStr {
+    ffi: SwiftStrFfi,
+}
+
+/// The FFI representation of a `SwiftStr`.
+///
+/// `owner` is a retained Swift object that owns the bytes that `str` points to, and `release`
+/// releases it.
+/// A null `str.start` means `None` when Swift returns an `Option<&str>`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct SwiftStrFfi {
+    pub str: RustStr,
+    pub owner: *mut std::ffi::c_void,
+    pub release: Option<extern "C" fn(*mut std::ffi::c_void)>,
+}
+
+impl SwiftStrFfi {
+    pub fn into_swift_str(self) -> SwiftStr {
+        SwiftStr { ffi: self }
+    }
+
+    pub fn into_option_swift_str(self) -> Option<SwiftStr> {
+        if self.str.start.is_null() {
+            None
+        } else {
+            Some(self.into_swift_str())
+        }
+    }
+}
+
+impl SwiftStr {
+    /// Get the `&str` that points into the Swift owned buffer.
+    pub fn as_str(&self) -> &str {
+        let bytes = unsafe { std::slice::from_raw_parts(self.ffi.str.start, self.ffi.str.len) };
+        std::str::from_utf8(bytes).expect("Failed to convert SwiftStr to &str")
+    }
+}
+
+impl Drop for SwiftStr {
+    fn drop(&mut self) {
+        if let Some(release) = self.ffi.release {
+            release(self.ffi.owner)
+        }
+    }
+}
+
+impl std::ops::Deref for SwiftStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SwiftStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for SwiftStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl std::fmt::Display for SwiftStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq<str> for SwiftStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SwiftStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}